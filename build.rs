@@ -0,0 +1,30 @@
+// This file is part of the uutils coreutils package.
+//
+// For the full copyright and license information, please view the LICENSE file
+// that was distributed with this source code.
+
+// spell-checker:ignore (rust) rustc
+
+// build script ~ exports the version of the compiler used to build this crate (as `PLATFORM_INFO_RUSTC_VERSION`; eg,
+// "1.75.0 (82e1608df 2023-12-21)"), for inclusion in bug reports
+
+use std::env;
+use std::process::Command;
+
+fn main() {
+    println!("cargo:rerun-if-env-changed=RUSTC");
+    let rustc = env::var_os("RUSTC").unwrap_or_else(|| "rustc".into());
+    // * any failure simply leaves the version unset (ie, unavailable)
+    let version = Command::new(rustc)
+        .arg("--version")
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok());
+    if let Some(version) = version {
+        // * `rustc --version` output has the form "rustc <version>"
+        let version = version.trim();
+        let version = version.strip_prefix("rustc ").unwrap_or(version);
+        println!("cargo:rustc-env=PLATFORM_INFO_RUSTC_VERSION={}", version);
+    }
+}
@@ -55,7 +55,9 @@ of this crate and in the [uutils/coreutils](https://github.com/uutils/coreutils)
 
 #![warn(unused_results)] // enable warnings for unused results
 
-use std::ffi::OsStr;
+use std::borrow::Cow;
+use std::convert::TryFrom;
+use std::ffi::{OsStr, OsString};
 
 mod lib_impl;
 
@@ -66,13 +68,77 @@ mod lib_impl;
 pub use lib_impl::PlatformInfo;
 #[cfg(unix)]
 pub use lib_impl::UTSName;
+#[cfg(all(windows, not(feature = "no-file-fallback")))]
+pub use lib_impl::WinApiFileVersionInfo;
 #[cfg(windows)]
-pub use lib_impl::{WinApiSystemInfo, WinOsVersionInfo};
+pub use lib_impl::{ProcessorArchitecture, WinApiSystemInfo, WinOsVersionInfo, WindowsVersion};
+
+// host_os_name
+// The canonical name of the host OS (as used as the prefix of `UNameAPI::osname()`).
+pub use lib_impl::host_os_name;
+
+// UnameData
+// An owned copy of the `uname`-style platform information fields.
+pub use lib_impl::UnameData;
+
+// PlatformInfoRef
+// A borrowed (`Copy`) view of the `uname`-style platform information fields.
+pub use lib_impl::PlatformInfoRef;
+
+// BuildTarget
+// The compile-time target description (see `PlatformInfo::build_target()`).
+pub use lib_impl::BuildTarget;
+
+// UnameFlags
+// Field selection flags for `UNameAPI::format()`.
+pub use lib_impl::UnameFlags;
+
+// FieldCapabilities
+// Flags indicating which platform information fields are natively determined (see `PlatformInfo::field_capabilities()`).
+pub use lib_impl::FieldCapabilities;
+
+// ChassisType
+// The form factor of the system (as reported by `PlatformInfo::chassis_type()`).
+pub use lib_impl::ChassisType;
+
+// CpuSignature
+// The x86 processor signature (as reported by `PlatformInfo::cpu_signature()`).
+pub use lib_impl::CpuSignature;
+
+// MitigationSummary
+// The overall CPU vulnerability mitigation state (as reported by `PlatformInfo::mitigation_summary()`).
+pub use lib_impl::MitigationSummary;
+
+// Architecture
+// The processor architecture (as reported by `PlatformInfo::architecture()`).
+pub use lib_impl::Architecture;
+
+// KernelKind
+// The kind of OS kernel (as reported by `PlatformInfo::kernel_kind()`).
+pub use lib_impl::KernelKind;
+
+// SocketBufferLimits
+// The configured socket buffer size limits (as reported by `PlatformInfo::socket_buffer_limits()`).
+pub use lib_impl::SocketBufferLimits;
 
 // PlatformInfoError
 /// The common error type for [`PlatformInfoAPI`].
+///
+/// Any thread-safe error (eg, an [`std::io::Error`] from a failed OS query or a [`std::num::TryFromIntError`] from a
+/// failed conversion of an OS-reported value) converts into a `PlatformInfoError` (eg, via `?`), retaining its
+/// original type; see [`PlatformInfoErrorAPI`] to classify the error.
 pub use lib_impl::BoxedThreadSafeStdError as PlatformInfoError;
 
+// UnsupportedPlatformError
+// The error returned by `PlatformInfo::new_strict()` for platforms without a native implementation.
+pub use lib_impl::UnsupportedPlatformError;
+
+// BoxedPlatformInfo
+/// A boxed, thread-safe, [`UNameAPI`] trait object.
+/// <br> Allows code to accept any [`UNameAPI`] implementation (eg, [`PlatformInfo`] or a fake for testing).
+#[cfg(feature = "mockable")]
+pub type BoxedPlatformInfo = Box<dyn UNameAPI + Send + Sync>;
+
 // PlatformInfoAPI
 /// Defines the full API for [`PlatformInfo`].
 // * includes `UNameAPI`
@@ -84,6 +150,36 @@ pub trait PlatformInfoAPI: UNameAPI {
         Self: Sized;
 }
 
+// PlatformInfoErrorAPI
+/// Defines predicates classifying a [`PlatformInfoError`].
+pub trait PlatformInfoErrorAPI {
+    /// *Returns* whether the error reports that platform information is genuinely unavailable for the current platform
+    /// (ie, an [`UnsupportedPlatformError`]), as opposed to a failure to read information from the OS.
+    fn is_unsupported(&self) -> bool;
+
+    /// *Returns* whether the error reports a failed OS query (ie, an [`std::io::Error`]; eg, from a failed system
+    /// call).
+    fn is_syscall(&self) -> bool;
+
+    /// *Returns* whether the error reports a failed numeric conversion of an OS-reported value (ie, a
+    /// [`std::num::TryFromIntError`]).
+    fn is_conversion(&self) -> bool;
+}
+
+impl PlatformInfoErrorAPI for PlatformInfoError {
+    fn is_unsupported(&self) -> bool {
+        self.downcast_ref::<UnsupportedPlatformError>().is_some()
+    }
+
+    fn is_syscall(&self) -> bool {
+        self.downcast_ref::<std::io::Error>().is_some()
+    }
+
+    fn is_conversion(&self) -> bool {
+        self.downcast_ref::<std::num::TryFromIntError>().is_some()
+    }
+}
+
 // UNameAPI
 /// Defines a trait API providing `uname` (aka "Unix name") style platform information.
 // ref: <https://www.gnu.org/software/libc/manual/html_node/Platform-Type.html> @@ <https://archive.is/YjjWJ>
@@ -105,4 +201,184 @@ pub trait UNameAPI {
 
     /// The name of the current OS.
     fn osname(&self) -> &OsStr;
+
+    /// *Returns* [`sysname()`](UNameAPI::sysname) as a string (with any non-Unicode sequences replaced by U+FFFD).
+    fn sysname_lossy(&self) -> Cow<'_, str> {
+        self.sysname().to_string_lossy()
+    }
+
+    /// *Returns* [`nodename()`](UNameAPI::nodename) as a string (with any non-Unicode sequences replaced by U+FFFD).
+    fn nodename_lossy(&self) -> Cow<'_, str> {
+        self.nodename().to_string_lossy()
+    }
+
+    /// *Returns* [`release()`](UNameAPI::release) as a string (with any non-Unicode sequences replaced by U+FFFD).
+    fn release_lossy(&self) -> Cow<'_, str> {
+        self.release().to_string_lossy()
+    }
+
+    /// *Returns* [`version()`](UNameAPI::version) as a string (with any non-Unicode sequences replaced by U+FFFD).
+    fn version_lossy(&self) -> Cow<'_, str> {
+        self.version().to_string_lossy()
+    }
+
+    /// *Returns* [`machine()`](UNameAPI::machine) as a string (with any non-Unicode sequences replaced by U+FFFD).
+    fn machine_lossy(&self) -> Cow<'_, str> {
+        self.machine().to_string_lossy()
+    }
+
+    /// *Returns* [`osname()`](UNameAPI::osname) as a string (with any non-Unicode sequences replaced by U+FFFD).
+    fn osname_lossy(&self) -> Cow<'_, str> {
+        self.osname().to_string_lossy()
+    }
+
+    /// *Returns* [`sysname()`](UNameAPI::sysname) as a string slice (without allocation), or `None` if it's not valid Unicode.
+    fn sysname_str(&self) -> Option<&str> {
+        self.sysname().to_str()
+    }
+
+    /// *Returns* [`nodename()`](UNameAPI::nodename) as a string slice (without allocation), or `None` if it's not valid Unicode.
+    fn nodename_str(&self) -> Option<&str> {
+        self.nodename().to_str()
+    }
+
+    /// *Returns* [`release()`](UNameAPI::release) as a string slice (without allocation), or `None` if it's not valid Unicode.
+    fn release_str(&self) -> Option<&str> {
+        self.release().to_str()
+    }
+
+    /// *Returns* [`version()`](UNameAPI::version) as a string slice (without allocation), or `None` if it's not valid Unicode.
+    fn version_str(&self) -> Option<&str> {
+        self.version().to_str()
+    }
+
+    /// *Returns* [`machine()`](UNameAPI::machine) as a string slice (without allocation), or `None` if it's not valid Unicode.
+    fn machine_str(&self) -> Option<&str> {
+        self.machine().to_str()
+    }
+
+    /// *Returns* [`osname()`](UNameAPI::osname) as a string slice (without allocation), or `None` if it's not valid Unicode.
+    fn osname_str(&self) -> Option<&str> {
+        self.osname().to_str()
+    }
+
+    /// *Returns* the `uname`-style fields encoded in a compact binary format, suitable for embedding or transmission.
+    ///
+    /// Each field (in `sysname`, `nodename`, `release`, `version`, `machine`, `osname` order) is encoded as its byte
+    /// length (as a little-endian `u32`) followed by its bytes (raw bytes on Unix-like platforms; WTF-8 on WinOS). Use
+    /// [`UnameData::from_bytes()`] to decode.
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        for field in [
+            self.sysname(),
+            self.nodename(),
+            self.release(),
+            self.version(),
+            self.machine(),
+            self.osname(),
+        ]
+        .iter()
+        {
+            let field = lib_impl::os_str_to_bytes(field);
+            let length = u32::try_from(field.len()).unwrap_or(u32::MAX);
+            bytes.extend_from_slice(&length.to_le_bytes());
+            bytes.extend_from_slice(&field[..(length as usize)]);
+        }
+        bytes
+    }
+
+    /// *Returns* the (borrowed) `uname`-style fields as `(name, value)` pairs (eg, `("sysname", "Linux")`), in
+    /// `sysname`, `nodename`, `release`, `version`, `machine`, `osname` order.
+    fn fields(&self) -> [(&'static str, &OsStr); 6] {
+        [
+            ("sysname", self.sysname()),
+            ("nodename", self.nodename()),
+            ("release", self.release()),
+            ("version", self.version()),
+            ("machine", self.machine()),
+            ("osname", self.osname()),
+        ]
+    }
+
+    /// *Returns* a copy of the `uname`-style fields which is safe for terminal display, with each control (or invisible
+    /// formatting) character replaced by its escaped form (eg, ESC => `\u{1b}`).
+    ///
+    /// Fields are converted lossily (see [`sysname_lossy()`](UNameAPI::sysname_lossy)). Useful when displaying untrusted
+    /// values (eg, a remote node name), which might otherwise inject terminal escape sequences.
+    fn sanitized(&self) -> UnameData {
+        let sanitize = |field: Cow<'_, str>| OsString::from(lib_impl::sanitize_str(&field));
+        UnameData {
+            sysname: sanitize(self.sysname_lossy()),
+            nodename: sanitize(self.nodename_lossy()),
+            release: sanitize(self.release_lossy()),
+            version: sanitize(self.version_lossy()),
+            machine: sanitize(self.machine_lossy()),
+            osname: sanitize(self.osname_lossy()),
+        }
+    }
+
+    /// *Returns* the `uname`-style fields as a minimal JSON object (eg, `{"sysname":"Linux",...}`), without requiring
+    /// `serde`.
+    ///
+    /// Fields are emitted in `sysname`, `nodename`, `release`, `version`, `machine`, `osname` order, converted lossily
+    /// (see [`sysname_lossy()`](UNameAPI::sysname_lossy)) and escaped as JSON strings.
+    fn to_json(&self) -> String {
+        let mut json = String::from("{");
+        for (name, field) in [
+            ("sysname", self.sysname_lossy()),
+            ("nodename", self.nodename_lossy()),
+            ("release", self.release_lossy()),
+            ("version", self.version_lossy()),
+            ("machine", self.machine_lossy()),
+            ("osname", self.osname_lossy()),
+        ]
+        .iter()
+        {
+            if json.len() > 1 {
+                json.push(',');
+            }
+            lib_impl::push_json_string(&mut json, name);
+            json.push(':');
+            lib_impl::push_json_string(&mut json, field);
+        }
+        json.push('}');
+        json
+    }
+
+    /// *Returns* the fields selected by `flags` as a single space-separated string, in the same (GNU) order and format
+    /// as the `uname` command.
+    ///
+    /// An empty set of `flags` selects only the `sysname` (as for `uname` without options). The processor type
+    /// ([`UnameFlags::PROCESSOR`]) and hardware platform ([`UnameFlags::HARDWARE_PLATFORM`]) are not available and are
+    /// reported as "unknown" when specifically selected.
+    // ref: <https://www.gnu.org/software/coreutils/manual/html_node/uname-invocation.html>
+    fn format(&self, flags: UnameFlags) -> OsString {
+        let flags = if flags.is_empty() {
+            UnameFlags::SYSNAME
+        } else {
+            flags
+        };
+        let unknown = OsStr::new("unknown");
+        let fields = [
+            (UnameFlags::SYSNAME, self.sysname()),
+            (UnameFlags::NODENAME, self.nodename()),
+            (UnameFlags::RELEASE, self.release()),
+            (UnameFlags::VERSION, self.version()),
+            (UnameFlags::MACHINE, self.machine()),
+            (UnameFlags::PROCESSOR, unknown),
+            (UnameFlags::HARDWARE_PLATFORM, unknown),
+            (UnameFlags::OSNAME, self.osname()),
+        ];
+
+        let mut output = OsString::new();
+        for (flag, field) in fields.iter() {
+            if flags.contains(*flag) {
+                if !output.is_empty() {
+                    output.push(" ");
+                }
+                output.push(field);
+            }
+        }
+        output
+    }
 }
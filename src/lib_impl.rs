@@ -2,6 +2,10 @@
 
 #![warn(unused_results)] // enable warnings for unused results
 
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::ffi::{OsStr, OsString};
+
 #[cfg(target_os = "windows")]
 use std::path::Path;
 #[cfg(target_os = "windows")]
@@ -14,6 +18,831 @@ pub type ThreadSafeStdError = dyn std::error::Error + Send + Sync;
 /// Standard thread-safe error type (boxed to allow translation for any `std::error::Error` type)
 pub type BoxedThreadSafeStdError = Box<ThreadSafeStdError>;
 
+// UnsupportedPlatformError
+/// The error returned (by [`PlatformInfo::new_strict()`]) when platform information isn't available for the current
+/// platform (ie, for targets without a native implementation).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct UnsupportedPlatformError;
+
+impl std::fmt::Display for UnsupportedPlatformError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "platform information is not available for this platform")
+    }
+}
+
+impl std::error::Error for UnsupportedPlatformError {}
+
+// UnameFlags
+/// A set of `uname`-style field selection flags (mirroring the `uname` command-line switches), for use with
+/// [`UNameAPI::format()`](crate::UNameAPI::format).
+///
+/// Flags are combined with `|` (eg, `UnameFlags::SYSNAME | UnameFlags::RELEASE`, equivalent to `uname -sr`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct UnameFlags(u8);
+
+impl UnameFlags {
+    /// `-s` ~ the name of the OS kernel ([`sysname()`](crate::UNameAPI::sysname))
+    pub const SYSNAME: Self = Self(1 << 0);
+    /// `-n` ~ the network node hostname ([`nodename()`](crate::UNameAPI::nodename))
+    pub const NODENAME: Self = Self(1 << 1);
+    /// `-r` ~ the OS kernel release ([`release()`](crate::UNameAPI::release))
+    pub const RELEASE: Self = Self(1 << 2);
+    /// `-v` ~ the OS kernel version ([`version()`](crate::UNameAPI::version))
+    pub const VERSION: Self = Self(1 << 3);
+    /// `-m` ~ the machine hardware name ([`machine()`](crate::UNameAPI::machine))
+    pub const MACHINE: Self = Self(1 << 4);
+    /// `-p` ~ the processor type (not available; always "unknown")
+    pub const PROCESSOR: Self = Self(1 << 5);
+    /// `-i` ~ the hardware platform (not available; always "unknown")
+    pub const HARDWARE_PLATFORM: Self = Self(1 << 6);
+    /// `-o` ~ the operating system name ([`osname()`](crate::UNameAPI::osname))
+    pub const OSNAME: Self = Self(1 << 7);
+    /// `-a` ~ all information, omitting the (unknown) processor type and hardware platform
+    pub const ALL: Self = Self(
+        Self::SYSNAME.0
+            | Self::NODENAME.0
+            | Self::RELEASE.0
+            | Self::VERSION.0
+            | Self::MACHINE.0
+            | Self::OSNAME.0,
+    );
+
+    /// *Returns* an empty set of flags.
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    /// *Returns* whether no flags are set.
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// *Returns* whether all of the `other` flags are set.
+    pub const fn contains(self, other: Self) -> bool {
+        (self.0 & other.0) == other.0
+    }
+}
+
+impl std::ops::BitOr for UnameFlags {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl std::ops::BitOrAssign for UnameFlags {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+// FieldCapabilities
+/// A set of flags indicating which platform information fields are natively determined ("real") for the current
+/// platform, as reported by `PlatformInfo::field_capabilities()`.
+///
+/// Fields whose flag is clear are synthesized (eg, "unknown") or unavailable, and shouldn't be trusted.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct FieldCapabilities(u8);
+
+impl FieldCapabilities {
+    /// [`sysname()`](crate::UNameAPI::sysname) is natively determined
+    pub const SYSNAME: Self = Self(1 << 0);
+    /// [`nodename()`](crate::UNameAPI::nodename) is natively determined
+    pub const NODENAME: Self = Self(1 << 1);
+    /// [`release()`](crate::UNameAPI::release) is natively determined
+    pub const RELEASE: Self = Self(1 << 2);
+    /// [`version()`](crate::UNameAPI::version) is natively determined
+    pub const VERSION: Self = Self(1 << 3);
+    /// [`machine()`](crate::UNameAPI::machine) is natively determined
+    pub const MACHINE: Self = Self(1 << 4);
+    /// [`osname()`](crate::UNameAPI::osname) names a known OS (ie, isn't "unknown")
+    pub const OSNAME: Self = Self(1 << 5);
+    /// the NIS (aka YP) domain name (`utsname.domainname`) is available (Unix-like platforms only)
+    pub const DOMAINNAME: Self = Self(1 << 6);
+    /// all `uname`-style fields (excluding `DOMAINNAME`)
+    pub const UNAME: Self = Self(
+        Self::SYSNAME.0
+            | Self::NODENAME.0
+            | Self::RELEASE.0
+            | Self::VERSION.0
+            | Self::MACHINE.0
+            | Self::OSNAME.0,
+    );
+
+    /// *Returns* an empty set of flags.
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    /// *Returns* whether no flags are set.
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// *Returns* whether all of the `other` flags are set.
+    pub const fn contains(self, other: Self) -> bool {
+        (self.0 & other.0) == other.0
+    }
+}
+
+impl std::ops::BitOr for FieldCapabilities {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl std::ops::BitOrAssign for FieldCapabilities {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+// UnameData
+/// An owned, platform-independent copy of the `uname`-style platform information fields.
+///
+/// Supports a compact, dependency-free, binary encoding (see [`UNameAPI::to_bytes()`](crate::UNameAPI::to_bytes) and
+/// [`UnameData::from_bytes()`]) which round-trips the fields losslessly (including any non-Unicode content).
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct UnameData {
+    /// The name of this implementation of the operating system.
+    pub sysname: OsString,
+    /// The node name (network node hostname) of this machine.
+    pub nodename: OsString,
+    /// The current release level of the operating system.
+    pub release: OsString,
+    /// The current version level of the current release.
+    pub version: OsString,
+    /// The name of the current system's hardware.
+    pub machine: OsString,
+    /// The name of the current OS.
+    pub osname: OsString,
+}
+
+impl UnameData {
+    /// *Returns* the [`UnameData`] decoded from `bytes` (as encoded by [`UNameAPI::to_bytes()`](crate::UNameAPI::to_bytes)).
+    ///
+    /// Fails if `bytes` is truncated, has trailing content, or contains a field which isn't representable on the
+    /// current platform.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, BoxedThreadSafeStdError> {
+        let mut remaining = bytes;
+        let mut next_field = || -> Result<OsString, BoxedThreadSafeStdError> {
+            if remaining.len() < 4 {
+                return Err("truncated field length".into());
+            }
+            let (length, rest) = remaining.split_at(4);
+            let length = usize::try_from(u32::from_le_bytes([
+                length[0], length[1], length[2], length[3],
+            ]))?;
+            if rest.len() < length {
+                return Err("truncated field content".into());
+            }
+            let (field, rest) = rest.split_at(length);
+            remaining = rest;
+            os_string_from_bytes(field)
+        };
+        let data = Self {
+            sysname: next_field()?,
+            nodename: next_field()?,
+            release: next_field()?,
+            version: next_field()?,
+            machine: next_field()?,
+            osname: next_field()?,
+        };
+        if !remaining.is_empty() {
+            return Err("unexpected trailing content".into());
+        }
+        Ok(data)
+    }
+
+    /// *Returns* the [`UnameData`] parsed from `uname -a` style output (`s`; eg, as produced by the [`Display`]
+    /// implementation or by [`UNameAPI::format(UnameFlags::ALL)`](crate::UNameAPI::format)), or `None` if `s` doesn't
+    /// contain enough fields.
+    ///
+    /// Heuristics (the format is ambiguous, since `version` and `osname` may contain spaces):
+    /// * `sysname`, `nodename`, and `release` are the first three whitespace-separated tokens.
+    /// * `osname` is the final token; or, if `s` ends with a parenthetical (eg, "MS/Windows (Windows 10)"), the token
+    ///   preceding the parenthetical plus the parenthetical.
+    /// * `machine` is the token preceding `osname`. Up to two trailing processor/hardware platform tokens (as printed by
+    ///   GNU `uname -a`, eg, "x86_64 x86_64 x86_64") are skipped if "unknown" or identical to the preceding token.
+    /// * `version` is the (trimmed) remaining text between `release` and `machine`, with its spacing intact.
+    ///
+    /// Limits: a `nodename` or `release` containing spaces, or a processor/hardware platform token which differs from
+    /// `machine` (eg, "i686 i686 i386"), is misparsed. Any non-Unicode content is replaced with U+FFFD.
+    ///
+    /// [`Display`]: std::fmt::Display
+    pub fn parse_uname_a(s: &OsStr) -> Option<Self> {
+        let s = s.to_string_lossy();
+        let s = s.trim();
+        let mut tokens: Vec<(usize, &str)> = Vec::new();
+        let mut start = None;
+        for (index, c) in s.char_indices().chain(std::iter::once((s.len(), ' '))) {
+            match (c.is_whitespace(), start) {
+                (true, Some(token_start)) => {
+                    tokens.push((token_start, &s[token_start..index]));
+                    start = None;
+                }
+                (false, None) => start = Some(index),
+                _ => {}
+            }
+        }
+
+        let osname_start = match s.rfind('(') {
+            Some(open) if s.ends_with(')') => tokens.iter().rev().find(|(i, _)| *i < open)?.0,
+            _ => tokens.last()?.0,
+        };
+        let mut tokens: Vec<_> = tokens
+            .into_iter()
+            .filter(|(i, _)| *i < osname_start)
+            .collect();
+        for _ in 0..2 {
+            let n = tokens.len();
+            if n > 4 && (tokens[n - 1].1 == "unknown" || tokens[n - 1].1 == tokens[n - 2].1) {
+                let _ = tokens.pop();
+            }
+        }
+        if tokens.len() < 4 {
+            return None;
+        }
+        let (release_start, release) = tokens[2];
+        let (machine_start, machine) = tokens[tokens.len() - 1];
+        Some(Self {
+            sysname: OsString::from(tokens[0].1),
+            nodename: OsString::from(tokens[1].1),
+            release: OsString::from(release),
+            version: OsString::from(s[(release_start + release.len())..machine_start].trim()),
+            machine: OsString::from(machine),
+            osname: OsString::from(&s[osname_start..]),
+        })
+    }
+}
+
+impl std::fmt::Display for UnameData {
+    /// Formats the fields in `uname -a` style (see [`UNameAPI::format()`](crate::UNameAPI::format)), with any
+    /// non-Unicode content replaced by U+FFFD.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        use crate::UNameAPI;
+        write!(f, "{}", self.format(UnameFlags::ALL).to_string_lossy())
+    }
+}
+
+impl TryFrom<&OsStr> for UnameData {
+    type Error = BoxedThreadSafeStdError;
+
+    /// Parses `uname -a` style output (see [`UnameData::parse_uname_a()`]).
+    fn try_from(s: &OsStr) -> Result<Self, Self::Error> {
+        Self::parse_uname_a(s).ok_or_else(|| "unrecognized `uname -a` format".into())
+    }
+}
+
+impl UnameData {
+    /// Consumes the [`UnameData`], *returning* an iterator over the owned `uname`-style fields as `(name, value)` pairs,
+    /// in the same order as [`fields()`](crate::UNameAPI::fields).
+    pub fn into_fields(self) -> impl Iterator<Item = (&'static str, OsString)> {
+        IntoIterator::into_iter([
+            ("sysname", self.sysname),
+            ("nodename", self.nodename),
+            ("release", self.release),
+            ("version", self.version),
+            ("machine", self.machine),
+            ("osname", self.osname),
+        ])
+    }
+}
+
+impl From<PlatformInfo> for UnameData {
+    /// Converts a [`PlatformInfo`] into its (owned) `uname`-style fields, without cloning them.
+    fn from(info: PlatformInfo) -> Self {
+        info.into_uname_data()
+    }
+}
+
+impl crate::UNameAPI for UnameData {
+    fn sysname(&self) -> &OsStr {
+        &self.sysname
+    }
+
+    fn nodename(&self) -> &OsStr {
+        &self.nodename
+    }
+
+    fn release(&self) -> &OsStr {
+        &self.release
+    }
+
+    fn version(&self) -> &OsStr {
+        &self.version
+    }
+
+    fn machine(&self) -> &OsStr {
+        &self.machine
+    }
+
+    fn osname(&self) -> &OsStr {
+        &self.osname
+    }
+}
+
+// PlatformInfoRef
+/// A lightweight, borrowed view of the `uname`-style platform information fields (eg, of a [`PlatformInfo`]).
+///
+/// Being `Copy`, it can be forwarded to multiple consumers without cloning (and allocating) any of the fields.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PlatformInfoRef<'a> {
+    /// The name of this implementation of the operating system.
+    pub sysname: &'a OsStr,
+    /// The node name (network node hostname) of this machine.
+    pub nodename: &'a OsStr,
+    /// The current release level of the operating system.
+    pub release: &'a OsStr,
+    /// The current version level of the current release.
+    pub version: &'a OsStr,
+    /// The name of the current system's hardware.
+    pub machine: &'a OsStr,
+    /// The name of the current OS.
+    pub osname: &'a OsStr,
+}
+
+impl<'a> PlatformInfoRef<'a> {
+    /// *Returns* a borrowed view of the fields of any [`UNameAPI`](crate::UNameAPI) implementation (`info`).
+    pub fn new<T: crate::UNameAPI + ?Sized>(info: &'a T) -> Self {
+        Self {
+            sysname: info.sysname(),
+            nodename: info.nodename(),
+            release: info.release(),
+            version: info.version(),
+            machine: info.machine(),
+            osname: info.osname(),
+        }
+    }
+}
+
+impl<'a> From<&'a PlatformInfo> for PlatformInfoRef<'a> {
+    fn from(info: &'a PlatformInfo) -> Self {
+        Self::new(info)
+    }
+}
+
+impl<'a> From<&'a UnameData> for PlatformInfoRef<'a> {
+    fn from(data: &'a UnameData) -> Self {
+        Self::new(data)
+    }
+}
+
+impl crate::UNameAPI for PlatformInfoRef<'_> {
+    fn sysname(&self) -> &OsStr {
+        self.sysname
+    }
+
+    fn nodename(&self) -> &OsStr {
+        self.nodename
+    }
+
+    fn release(&self) -> &OsStr {
+        self.release
+    }
+
+    fn version(&self) -> &OsStr {
+        self.version
+    }
+
+    fn machine(&self) -> &OsStr {
+        self.machine
+    }
+
+    fn osname(&self) -> &OsStr {
+        self.osname
+    }
+}
+
+// ChassisType
+/// The form factor of the system (aka "chassis type").
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ChassisType {
+    /// A portable system (eg, laptop, notebook, or convertible).
+    Laptop,
+    /// A desktop system (eg, tower, mini PC, or all-in-one).
+    Desktop,
+    /// A server system (eg, rack mount or blade).
+    Server,
+    /// A virtual machine.
+    VirtualMachine,
+    /// Any other form factor (eg, tablet, docking station, or embedded system).
+    Other,
+}
+
+impl ChassisType {
+    /// *Returns* the [`ChassisType`] corresponding to an SMBIOS (System Management BIOS) system enclosure type `code`,
+    /// or `None` for the "Unknown" (or an invalid) code.
+    ///
+    /// Note: the "chassis lock present" flag (the high bit of the SMBIOS field) is ignored.
+    // ref: [SMBIOS (DSP0134 v3.7.0) ~ 7.4.1 System Enclosure Type](https://www.dmtf.org/sites/default/files/standards/documents/DSP0134_3.7.0.pdf)
+    pub fn from_smbios_code(code: u8) -> Option<Self> {
+        match code & 0x7f {
+            // Desktop, Low Profile Desktop, Pizza Box, Mini Tower, Tower, All in One, Space-saving, Lunch Box, Sealed-case PC, Mini PC, Stick PC
+            3 | 4 | 5 | 6 | 7 | 13 | 15 | 16 | 24 | 35 | 36 => Some(Self::Desktop),
+            // Portable, Laptop, Notebook, Sub Notebook, Convertible, Detachable
+            8 | 9 | 10 | 14 | 31 | 32 => Some(Self::Laptop),
+            // Main Server Chassis, Rack Mount Chassis, Multi-system chassis, Blade, Blade Enclosure
+            17 | 23 | 25 | 28 | 29 => Some(Self::Server),
+            // Other, Hand Held, Docking Station, Expansion/Sub/Bus Expansion/Peripheral/RAID Chassis, Compact PCI, AdvancedTCA, Tablet, IoT Gateway, Embedded PC
+            1 | 11 | 12 | 18..=22 | 26 | 27 | 30 | 33 | 34 => Some(Self::Other),
+            // Unknown (or invalid)
+            _ => None,
+        }
+    }
+}
+
+// MitigationSummary
+/// The overall CPU vulnerability mitigation state of the system (a rollup of the kernel-reported state of each known
+/// CPU vulnerability).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MitigationSummary {
+    /// Every known vulnerability is either fully mitigated or doesn't affect the CPU.
+    FullyMitigated,
+    /// At least one vulnerability is mitigated (or doesn't affect the CPU) while others are only partially mitigated
+    /// (eg, "SMT vulnerable"), unmitigated, or of unknown state.
+    PartiallyMitigated,
+    /// No vulnerability affecting the CPU is mitigated.
+    Vulnerable,
+}
+
+impl MitigationSummary {
+    /// *Returns* the [`MitigationSummary`] rollup of the kernel-reported vulnerability `states` (eg, "Not affected",
+    /// "Mitigation: PTI", or "Vulnerable"), or `None` if `states` is empty.
+    ///
+    /// A "Mitigation: ..." state which also reports a remaining vulnerability (eg, "Mitigation: Clear CPU buffers; SMT
+    /// vulnerable") or an "Unknown: ..." state counts as only partially mitigated.
+    // ref: <https://www.kernel.org/doc/Documentation/ABI/testing/sysfs-devices-system-cpu> (`/sys/devices/system/cpu/vulnerabilities`)
+    pub fn from_vulnerability_states<'a, I>(states: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        let (mut count, mut mitigated, mut vulnerable) = (0, 0, 0);
+        for state in states {
+            count += 1;
+            let state = state.trim();
+            if state.starts_with("Vulnerable") {
+                vulnerable += 1;
+            } else if state == "Not affected"
+                || (state.starts_with("Mitigation")
+                    && !state.to_ascii_lowercase().contains("vulnerable"))
+            {
+                mitigated += 1;
+            }
+        }
+        match count {
+            0 => None,
+            _ if mitigated == count => Some(Self::FullyMitigated),
+            _ if mitigated == 0 && vulnerable > 0 => Some(Self::Vulnerable),
+            _ => Some(Self::PartiallyMitigated),
+        }
+    }
+}
+
+// Architecture
+/// The processor architecture of the system, using Rust's architecture vocabulary (see [`std::env::consts::ARCH`]).
+///
+/// Parsing (via [`FromStr`](std::str::FromStr)) is infallible and case-insensitive, accepting the various OS-reported
+/// machine names (eg, "amd64", "i686", "arm64", "armv7l", or "ppc64le"), including the Windows spellings (eg, "AMD64",
+/// "x64", "ARM64", or "ARM64EC"); any unrecognized name is preserved as [`Architecture::Unknown`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Architecture {
+    /// 32-bit x86 (eg, "i386", "i686", or "x86").
+    X86,
+    /// 64-bit x86 (eg, "x86_64", "amd64", or "x64").
+    X86_64,
+    /// 32-bit ARM (eg, "arm", "armv7l", or "armv6l").
+    Arm,
+    /// 64-bit ARM (eg, "aarch64" or "arm64").
+    Aarch64,
+    /// 32-bit MIPS (eg, "mips" or "mipsel").
+    Mips,
+    /// 64-bit MIPS (eg, "mips64" or "mips64el").
+    Mips64,
+    /// 32-bit PowerPC (eg, "ppc" or "powerpc").
+    PowerPc,
+    /// 64-bit PowerPC (eg, "ppc64", "ppc64le", or "powerpc64").
+    PowerPc64,
+    /// 32-bit RISC-V ("riscv32").
+    RiscV32,
+    /// 64-bit RISC-V ("riscv64").
+    RiscV64,
+    /// 64-bit IBM Z ("s390x").
+    S390x,
+    /// 32-bit SPARC ("sparc").
+    Sparc,
+    /// 64-bit SPARC (eg, "sparc64", "sun4u", or "sun4v").
+    Sparc64,
+    /// 64-bit LoongArch ("loongarch64").
+    LoongArch64,
+    /// Motorola 68000 family ("m68k").
+    M68k,
+    /// Any other architecture, holding the machine name as given (eg, "ia64" or "alpha").
+    Unknown(String),
+}
+
+impl Architecture {
+    /// *Returns* the Rust architecture name (as in [`std::env::consts::ARCH`]; eg, "x86_64" or "aarch64"), or the
+    /// original machine name for an [`Architecture::Unknown`] architecture.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::X86 => "x86",
+            Self::X86_64 => "x86_64",
+            Self::Arm => "arm",
+            Self::Aarch64 => "aarch64",
+            Self::Mips => "mips",
+            Self::Mips64 => "mips64",
+            Self::PowerPc => "powerpc",
+            Self::PowerPc64 => "powerpc64",
+            Self::RiscV32 => "riscv32",
+            Self::RiscV64 => "riscv64",
+            Self::S390x => "s390x",
+            Self::Sparc => "sparc",
+            Self::Sparc64 => "sparc64",
+            Self::LoongArch64 => "loongarch64",
+            Self::M68k => "m68k",
+            Self::Unknown(name) => name,
+        }
+    }
+}
+
+impl std::str::FromStr for Architecture {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match rust_arch_from_machine(s) {
+            "x86" => Self::X86,
+            "x86_64" => Self::X86_64,
+            "arm" => Self::Arm,
+            "aarch64" => Self::Aarch64,
+            "mips" => Self::Mips,
+            "mips64" => Self::Mips64,
+            "powerpc" => Self::PowerPc,
+            "powerpc64" => Self::PowerPc64,
+            "riscv32" => Self::RiscV32,
+            "riscv64" => Self::RiscV64,
+            "s390x" => Self::S390x,
+            "sparc" => Self::Sparc,
+            "sparc64" => Self::Sparc64,
+            "loongarch64" => Self::LoongArch64,
+            "m68k" => Self::M68k,
+            _ => Self::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::fmt::Display for Architecture {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+// BuildTarget
+/// A description of the compilation target (ie, what the binary was compiled *for*), determined entirely at compile time.
+///
+/// Displays as "ARCH-OS\[-ENV\]" (eg, "x86_64-linux-gnu" or "aarch64-macos").
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BuildTarget {
+    /// The target architecture (as in [`std::env::consts::ARCH`]; eg, "x86_64" or "aarch64").
+    pub arch: &'static str,
+    /// The target OS (as in [`std::env::consts::OS`]; eg, "linux", "macos", or "windows").
+    pub os: &'static str,
+    /// The target environment/ABI (as in `cfg(target_env)`; eg, "gnu", "musl", or "msvc"), or "" if none.
+    pub env: &'static str,
+    /// The target endianness (as in `cfg(target_endian)`; ie, "little" or "big").
+    pub endian: &'static str,
+    /// The target pointer width, in bits (as in `cfg(target_pointer_width)`; eg, 64).
+    pub pointer_width: u32,
+}
+
+impl BuildTarget {
+    /// The compilation target of the current binary.
+    pub const CURRENT: Self = Self {
+        arch: std::env::consts::ARCH,
+        os: std::env::consts::OS,
+        env: if cfg!(target_env = "gnu") {
+            "gnu"
+        } else if cfg!(target_env = "musl") {
+            "musl"
+        } else if cfg!(target_env = "msvc") {
+            "msvc"
+        } else if cfg!(target_env = "uclibc") {
+            "uclibc"
+        } else if cfg!(target_env = "newlib") {
+            "newlib"
+        } else if cfg!(target_env = "ohos") {
+            "ohos"
+        } else if cfg!(target_env = "relibc") {
+            "relibc"
+        } else if cfg!(target_env = "sgx") {
+            "sgx"
+        } else if cfg!(target_env = "nto71") {
+            "nto71"
+        } else {
+            ""
+        },
+        endian: if cfg!(target_endian = "big") {
+            "big"
+        } else {
+            "little"
+        },
+        pointer_width: if cfg!(target_pointer_width = "16") {
+            16
+        } else if cfg!(target_pointer_width = "32") {
+            32
+        } else {
+            64
+        },
+    };
+}
+
+impl std::fmt::Display for BuildTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.arch, self.os)?;
+        if !self.env.is_empty() {
+            write!(f, "-{}", self.env)?;
+        }
+        Ok(())
+    }
+}
+
+// KernelKind
+/// The kind of OS kernel of the system, as a canonical classification of [`sysname()`](crate::UNameAPI::sysname) (eg,
+/// "Linux", "Darwin", or "Windows_NT"), allowing reliable matching without string comparisons.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum KernelKind {
+    /// Linux (including Android); sysname "Linux".
+    Linux,
+    /// Darwin (ie, MacOS, iOS, and other Apple platforms); sysname "Darwin".
+    Darwin,
+    /// Windows NT; sysname "Windows_NT" (or an MSYS/Cygwin-style name, eg, "MINGW64_NT-10.0-19045").
+    WindowsNt,
+    /// FreeBSD (including GNU/kFreeBSD); sysname "FreeBSD" (or "GNU/kFreeBSD").
+    FreeBsd,
+    /// NetBSD; sysname "NetBSD".
+    NetBsd,
+    /// OpenBSD; sysname "OpenBSD".
+    OpenBsd,
+    /// DragonFly BSD; sysname "DragonFly".
+    DragonFly,
+    /// SunOS (ie, illumos and Solaris); sysname "SunOS".
+    SunOs,
+    /// AIX; sysname "AIX".
+    Aix,
+    /// Haiku; sysname "Haiku".
+    Haiku,
+    /// GNU/Hurd; sysname "GNU".
+    Hurd,
+    /// Fuchsia; sysname "Fuchsia".
+    Fuchsia,
+    /// Redox; sysname "Redox".
+    Redox,
+    /// Any other kernel, holding the sysname as given.
+    Other(OsString),
+}
+
+impl KernelKind {
+    /// *Returns* the [`KernelKind`] corresponding to a `sysname` (matched case-insensitively); any unrecognized name is
+    /// preserved as [`KernelKind::Other`].
+    pub fn from_sysname(sysname: &OsStr) -> Self {
+        let name = sysname.to_string_lossy().to_ascii_lowercase();
+        match name.as_str() {
+            "linux" => Self::Linux,
+            "darwin" => Self::Darwin,
+            "windows_nt" => Self::WindowsNt,
+            n if [
+                "cygwin_nt",
+                "msys_nt",
+                "mingw32_nt",
+                "mingw64_nt",
+                "ucrt64_nt",
+                "clang64_nt",
+            ]
+            .iter()
+            .any(|prefix| n.starts_with(prefix)) =>
+            {
+                Self::WindowsNt
+            }
+            "freebsd" | "gnu/kfreebsd" => Self::FreeBsd,
+            "netbsd" => Self::NetBsd,
+            "openbsd" => Self::OpenBsd,
+            "dragonfly" => Self::DragonFly,
+            "sunos" => Self::SunOs,
+            "aix" => Self::Aix,
+            "haiku" => Self::Haiku,
+            "gnu" => Self::Hurd,
+            "fuchsia" => Self::Fuchsia,
+            "redox" => Self::Redox,
+            _ => Self::Other(sysname.to_os_string()),
+        }
+    }
+}
+
+// SocketBufferLimits
+/// The configured socket buffer size limits (in bytes), as reported by the kernel.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SocketBufferLimits {
+    /// The maximum receive buffer size which may be set (via `SO_RCVBUF`) for any socket (`net.core.rmem_max`).
+    pub rmem_max: u64,
+    /// The maximum send buffer size which may be set (via `SO_SNDBUF`) for any socket (`net.core.wmem_max`).
+    pub wmem_max: u64,
+    /// The TCP receive buffer sizes, as `[minimum, default, maximum]` (`net.ipv4.tcp_rmem`).
+    pub tcp_rmem: [u64; 3],
+    /// The TCP send buffer sizes, as `[minimum, default, maximum]` (`net.ipv4.tcp_wmem`).
+    pub tcp_wmem: [u64; 3],
+}
+
+// CpuSignature
+/// The x86 processor signature (ie, family, model, and stepping), as reported by CPUID (leaf 1).
+///
+/// `family` and `model` are the "display" values (ie, with the extended family/model fields already applied).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CpuSignature {
+    /// The processor family (eg, 6 for most Intel Core processors, or 25 for AMD Zen 3/4 processors).
+    pub family: u16,
+    /// The processor model (within the `family`).
+    pub model: u8,
+    /// The processor stepping (ie, revision) (within the `model`).
+    pub stepping: u8,
+}
+
+impl CpuSignature {
+    /// *Returns* the [`CpuSignature`] decoded from the `EAX` register value of CPUID leaf 1 (`eax`).
+    // ref: [Intel SDM (vol 2A) ~ CPUID, Figure 3-6](https://www.intel.com/content/www/us/en/developer/articles/technical/intel-sdm.html)
+    // ref: <https://en.wikipedia.org/wiki/CPUID#EAX=1:_Processor_Info_and_Feature_Bits>
+    pub fn from_cpuid_eax(eax: u32) -> Self {
+        let stepping = (eax & 0xf) as u8;
+        let base_model = ((eax >> 4) & 0xf) as u8;
+        let base_family = ((eax >> 8) & 0xf) as u16;
+        let extended_model = ((eax >> 16) & 0xf) as u8;
+        let extended_family = ((eax >> 20) & 0xff) as u16;
+
+        let family = if base_family == 0xf {
+            base_family + extended_family
+        } else {
+            base_family
+        };
+        let model = if base_family == 0x6 || base_family == 0xf {
+            (extended_model << 4) | base_model
+        } else {
+            base_model
+        };
+        Self {
+            family,
+            model,
+            stepping,
+        }
+    }
+}
+
+// is_virtual_machine_vendor
+/// *Returns* whether a system vendor/product description (`name`) identifies a well-known hypervisor.
+#[allow(dead_code)] // * fn is unused on some platforms
+pub(crate) fn is_virtual_machine_vendor(name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+    [
+        "bhyve",
+        "bochs",
+        "hvm domu",
+        "kvm",
+        "parallels",
+        "qemu",
+        "virtual machine",
+        "virtualbox",
+        "vmware",
+        "xen",
+    ]
+    .iter()
+    .any(|vendor| name.contains(vendor))
+}
+
+// virtualization_from_vendor
+/// *Returns* the normalized hypervisor name (eg, "kvm", "vmware", or "hyper-v") identified by a system vendor/product
+/// description (`name`).
+#[cfg(feature = "virtualization")]
+#[allow(dead_code)] // * fn is unused on some platforms
+pub(crate) fn virtualization_from_vendor(name: &str) -> Option<&'static str> {
+    let name = name.to_ascii_lowercase();
+    [
+        ("kvm", "kvm"),
+        ("qemu", "qemu"),
+        ("vmware", "vmware"),
+        ("virtualbox", "virtualbox"),
+        ("xen", "xen"),
+        ("parallels", "parallels"),
+        ("bhyve", "bhyve"),
+        ("bochs", "bochs"),
+        // * Hyper-V reports "Microsoft Corporation" (vendor) and "Virtual Machine" (product)
+        ("virtual machine", "hyper-v"),
+    ]
+    .iter()
+    .find(|(marker, _)| name.contains(marker))
+    .map(|(_, hypervisor)| *hypervisor)
+}
+
 /// A slice of a path string
 /// (akin to [`str`]; aka/equivalent to [`Path`]).
 #[cfg(target_os = "windows")]
@@ -31,6 +860,10 @@ const HOST_OS_NAME: &str = if cfg!(all(
     any(target_env = "gnu", target_env = "")
 )) {
     "GNU/Linux"
+} else if cfg!(all(target_os = "linux", target_env = "ohos")) {
+    // OpenHarmony (`*-linux-ohos`) is a (musl-derived) non-GNU Linux userland; "Linux" is kept (vs "OpenHarmony")
+    // to match coreutils (and other non-GNU Linux targets, eg musl), as the OS is still identified by its kernel
+    "Linux"
 } else if cfg!(all(
     target_os = "linux",
     not(any(target_env = "gnu", target_env = ""))
@@ -40,6 +873,10 @@ const HOST_OS_NAME: &str = if cfg!(all(
     "Android"
 } else if cfg!(target_os = "windows") {
     "MS/Windows" // prior art == `busybox`
+} else if cfg!(target_os = "cygwin") {
+    "Cygwin" // matches Cygwin coreutils `uname -o` (sysname is native; eg, "CYGWIN_NT-10.0-19045")
+} else if cfg!(all(target_os = "freebsd", target_env = "gnu")) {
+    "GNU/kFreeBSD" // Debian GNU/kFreeBSD (ie, a GNU userland on the FreeBSD kernel)
 } else if cfg!(target_os = "freebsd") {
     "FreeBSD"
 } else if cfg!(target_os = "netbsd") {
@@ -48,6 +885,8 @@ const HOST_OS_NAME: &str = if cfg!(all(
     "OpenBSD"
 } else if cfg!(target_vendor = "apple") {
     "Darwin"
+} else if cfg!(target_os = "hurd") {
+    "GNU" // GNU/Hurd
 } else if cfg!(target_os = "fuchsia") {
     "Fuchsia"
 } else if cfg!(target_os = "redox") {
@@ -60,6 +899,16 @@ const HOST_OS_NAME: &str = if cfg!(all(
     "unknown"
 };
 
+// host_os_name
+/// *Returns* the canonical name of the host OS (eg, "GNU/Linux", "Darwin", or "MS/Windows"), as determined at compile
+/// time, without requiring construction of a [`PlatformInfo`].
+///
+/// [`osname()`](crate::UNameAPI::osname) always begins with this name (though it may be followed by additional
+/// detail; eg, "MS/Windows (Windows 10)"). Usable in `const` contexts.
+pub const fn host_os_name() -> &'static str {
+    HOST_OS_NAME
+}
+
 //=== platform-specific module code
 
 #[cfg(unix)]
@@ -73,3 +922,843 @@ mod target;
 mod target;
 
 pub use target::*;
+
+//=== cross-platform information
+
+impl PlatformInfo {
+    /// Creates a new instance of [`PlatformInfo`], failing with an [`UnsupportedPlatformError`] (see
+    /// [`is_unsupported()`](crate::PlatformInfoErrorAPI::is_unsupported)) for targets without a native implementation.
+    ///
+    /// In contrast, [`new()`](crate::PlatformInfoAPI::new) succeeds for such targets, reporting "unknown" values.
+    pub fn new_strict() -> Result<Self, crate::PlatformInfoError> {
+        if cfg!(any(unix, windows)) {
+            <Self as crate::PlatformInfoAPI>::new()
+        } else {
+            Err(Box::new(UnsupportedPlatformError))
+        }
+    }
+
+    /// *Returns* whether native (lock-free) atomic operations of the given width (`width_bits`) are supported by the
+    /// current target and CPU.
+    ///
+    /// Widths up to 64 bits are determined by the compilation target. 128-bit support is detected at run-time, requiring
+    /// the `cmpxchg16b` (aka CX16) feature on x86_64 or the LSE (ARMv8.1 Large System Extensions) feature on AArch64.
+    pub fn has_native_atomic(&self, width_bits: u32) -> bool {
+        match width_bits {
+            8 => cfg!(target_has_atomic = "8"),
+            16 => cfg!(target_has_atomic = "16"),
+            32 => cfg!(target_has_atomic = "32"),
+            64 => cfg!(target_has_atomic = "64"),
+            128 => has_native_atomic_128(),
+            _ => false,
+        }
+    }
+
+    /// *Returns* the processor signature (ie, family, model, and stepping), as reported by CPUID, or `None` for non-x86
+    /// processors.
+    pub fn cpu_signature(&self) -> Option<CpuSignature> {
+        cpuid_leaf_1_eax().map(CpuSignature::from_cpuid_eax)
+    }
+
+    /// *Returns* the Rust target architecture name (as in [`std::env::consts::ARCH`]; eg, "x86_64", "x86", "aarch64",
+    /// or "arm") corresponding to [`machine()`](crate::UNameAPI::machine), or "unknown" if there's no equivalent.
+    ///
+    /// Maps the various OS-reported machine names (eg, "amd64", "i686", "arm64", "armv7l", or "ppc64le") to Rust's
+    /// naming, allowing matching on a known vocabulary. For illumos/Solaris, the "i86pc" hardware platform name is
+    /// first resolved to the kernel instruction set architecture (eg, "amd64").
+    pub fn arch_triple(&self) -> &'static str {
+        rust_arch_from_machine(&self.isa_machine())
+    }
+
+    /// *Returns* [`machine()`](crate::UNameAPI::machine), with an illumos/Solaris hardware platform name (ie, "i86pc",
+    /// which names no specific instruction set architecture) resolved to the kernel instruction set architecture (ie,
+    /// "amd64" or "i386", via `sysinfo(SI_ARCHITECTURE_K)`).
+    fn isa_machine(&self) -> std::borrow::Cow<'_, str> {
+        use crate::UNameAPI;
+        let machine = self.machine_lossy();
+        #[cfg(any(target_os = "illumos", target_os = "solaris"))]
+        if machine == "i86pc" {
+            if let Some(isa) = target::kernel_isa() {
+                return std::borrow::Cow::Owned(isa.to_string_lossy().into_owned());
+            }
+        }
+        machine
+    }
+
+    /// *Returns* the Debian (ie, `dpkg`) architecture name (eg, "amd64", "arm64", "armhf", or "i386") corresponding to
+    /// [`machine()`](crate::UNameAPI::machine), or `None` if there's no known equivalent.
+    ///
+    /// Note: the ARM float ABI ("armhf" vs "armel") is a property of the userland, not visible in the machine name; so,
+    /// it's inferred on a best-effort basis: ARMv4/ARMv5 machines (eg, "armv5tel") map to "armel", and all other 32-bit
+    /// ARM machines (eg, "armv7l" or "armv6l") map to "armhf". The illumos/Solaris "i86pc" hardware platform name is
+    /// resolved to the kernel instruction set architecture (see [`arch_triple()`](PlatformInfo::arch_triple)).
+    // ref: <https://wiki.debian.org/SupportedArchitectures>
+    pub fn dpkg_architecture(&self) -> Option<&'static str> {
+        dpkg_arch_from_machine(&self.isa_machine())
+    }
+
+    /// *Returns* the RPM architecture name (eg, "x86_64", "aarch64", "armv7hl", or "i686") corresponding to
+    /// [`machine()`](crate::UNameAPI::machine), or `None` if there's no known equivalent.
+    ///
+    /// RPM names mostly match the Linux machine names, but diverge for:
+    /// - non-Linux synonyms (eg, "amd64" => "x86_64" and "arm64" => "aarch64")
+    /// - 32-bit ARM, where RPM names encode the (hard) float ABI (eg, "armv7l" => "armv7hl" and "armv6l" => "armv6hl");
+    ///   as for [`dpkg_architecture()`](PlatformInfo::dpkg_architecture), the float ABI is inferred on a best-effort
+    ///   basis
+    /// - 64-bit SPARC (eg, "sun4v" => "sparc64")
+    ///
+    /// The illumos/Solaris "i86pc" hardware platform name is resolved to the kernel instruction set architecture (see
+    /// [`arch_triple()`](PlatformInfo::arch_triple)).
+    pub fn rpm_architecture(&self) -> Option<&'static str> {
+        rpm_arch_from_machine(&self.isa_machine())
+    }
+
+    /// *Returns* [`machine()`](crate::UNameAPI::machine) sanitized for safe, unquoted embedding within a shell command or
+    /// file name (eg, "downloads/tool-x86_64.tar.gz").
+    ///
+    /// Escaping rules:
+    /// - any character other than an ASCII letter, digit, `.`, `_`, `+`, or `-` (eg, whitespace, `/`, quotes, `$`, or
+    ///   any non-ASCII or non-Unicode content) is replaced by `_`
+    /// - a leading `-` or `.` is replaced by `_` (preventing interpretation as a command option, a hidden file, or `..`)
+    /// - an empty machine name is replaced by "unknown"
+    ///
+    /// Typical machine names (eg, "x86_64", "aarch64", or "armv7l") are returned unchanged.
+    pub fn machine_shell_safe(&self) -> String {
+        use crate::UNameAPI;
+        shell_safe_name(&self.machine_lossy())
+    }
+
+    /// *Returns* the [`BuildTarget`] for which this binary was compiled (ie, [`BuildTarget::CURRENT`]).
+    ///
+    /// This may differ from the runtime platform (eg, an "x86" binary running on "x86_64" WinOS, via WOW64); compare
+    /// with [`arch_triple()`](PlatformInfo::arch_triple).
+    pub fn build_target(&self) -> BuildTarget {
+        BuildTarget::CURRENT
+    }
+
+    /// *Returns* the processor [`Architecture`] corresponding to [`machine()`](crate::UNameAPI::machine).
+    pub fn architecture(&self) -> Architecture {
+        match self.isa_machine().parse() {
+            Ok(architecture) => architecture,
+            Err(never) => match never {},
+        }
+    }
+
+    /// *Returns* the canonical OS name, as output by GNU coreutils `uname -o` (eg, "GNU/Linux", "Darwin", or
+    /// "MS/Windows").
+    ///
+    /// Unlike [`osname()`](crate::UNameAPI::osname), this never includes additional detail (eg, the "(Windows 10)" of
+    /// "MS/Windows (Windows 10)"); it's always equal to [`host_os_name()`].
+    pub fn os_kind(&self) -> &OsStr {
+        OsStr::new(HOST_OS_NAME)
+    }
+
+    /// *Returns* the [`KernelKind`] classification of [`sysname()`](crate::UNameAPI::sysname).
+    ///
+    /// For an unrecognized sysname, the kernel kind of the compilation target (if known) is used as a tiebreaker.
+    pub fn kernel_kind(&self) -> KernelKind {
+        use crate::UNameAPI;
+        match KernelKind::from_sysname(self.sysname()) {
+            KernelKind::Other(name) => target_kernel_kind().unwrap_or(KernelKind::Other(name)),
+            kind => kind,
+        }
+    }
+
+    /// *Returns* `true` if `other` describes the same platform (ie, the same OS, at the same version, on the same
+    /// machine hardware), ignoring volatile fields.
+    ///
+    /// Only the stable identity fields are compared: [`sysname()`](crate::UNameAPI::sysname),
+    /// [`release()`](crate::UNameAPI::release), [`version()`](crate::UNameAPI::version),
+    /// [`machine()`](crate::UNameAPI::machine), and [`osname()`](crate::UNameAPI::osname). The node name (and, for
+    /// GNU/Linux, the domain name) may change at any time and is ignored. Useful for change detection (eg, "did the
+    /// OS get upgraded?"), where the full comparison of `==` is too strict.
+    pub fn same_platform(&self, other: &Self) -> bool {
+        use crate::UNameAPI;
+        self.sysname() == other.sysname()
+            && self.release() == other.release()
+            && self.version() == other.version()
+            && self.machine() == other.machine()
+            && self.osname() == other.osname()
+    }
+
+    /// Consumes the [`PlatformInfo`], *returning* an iterator over the owned `uname`-style fields as `(name, value)`
+    /// pairs (eg, `("sysname", "Linux")`), in the same order as [`fields()`](crate::UNameAPI::fields).
+    ///
+    /// The move-semantics counterpart of `fields()`; the cached values are moved (rather than cloned) out.
+    pub fn into_fields(self) -> impl Iterator<Item = (&'static str, OsString)> {
+        UnameData::from(self).into_fields()
+    }
+
+    /// *Returns* the platform information with the cached [`machine()`](crate::UNameAPI::machine) name remapped via
+    /// `overrides`, a table of machine names to their replacements (eg, "armv7ml" => "armv7l").
+    ///
+    /// Allows nonstandard machine names (eg, as reported by some embedded distributions) to be normalized. Machine names
+    /// without an exact matching entry are left unchanged; so, an empty table is a no-op. The OS-reported name remains
+    /// available via `machine_raw()`.
+    pub fn with_machine_overrides(mut self, overrides: HashMap<String, String>) -> Self {
+        use crate::UNameAPI;
+        let replacement = self
+            .machine()
+            .to_str()
+            .and_then(|machine| overrides.get(machine));
+        if let Some(replacement) = replacement {
+            self.set_machine(OsString::from(replacement));
+        }
+        self
+    }
+
+    /// *Returns* the platform information formatted as a Markdown block, suitable for pasting into a (GitHub) issue or
+    /// bug report.
+    ///
+    /// The format is stable: a "### Platform" heading followed by a `text` fenced code block of `key: value` lines, in
+    /// order, for `osname`, `release`, `version`, and `machine`, followed by any available enrichments (`distro` and
+    /// `distro id`, the os-release pretty name and `ID`, for Linux; `arch`, the Rust architecture name; `rustc`, the
+    /// version of the compiler which built this crate; and `build target`, the compilation target as
+    /// "ARCH-OS\[-ENV\]").
+    ///
+    /// ````text
+    /// ### Platform
+    ///
+    /// ```text
+    /// osname: GNU/Linux
+    /// release: 6.1.0-18-amd64
+    /// version: #1 SMP PREEMPT_DYNAMIC Debian 6.1.76-1 (2024-02-01)
+    /// machine: x86_64
+    /// distro: Debian GNU/Linux 12 (bookworm)
+    /// distro id: debian
+    /// arch: x86_64
+    /// rustc: 1.76.0 (07dca489a 2024-02-04)
+    /// build target: x86_64-linux-gnu
+    /// ```
+    /// ````
+    pub fn bug_report(&self) -> String {
+        use crate::UNameAPI;
+        let mut fields = vec![
+            ("osname", self.osname_lossy().into_owned()),
+            ("release", self.release_lossy().into_owned()),
+            ("version", self.version_lossy().into_owned()),
+            ("machine", self.machine_lossy().into_owned()),
+        ];
+        if let Some(id) = self.os_release_id() {
+            fields.push((
+                "distro",
+                self.os_pretty_name().to_string_lossy().into_owned(),
+            ));
+            fields.push(("distro id", id.to_string_lossy().into_owned()));
+        }
+        let arch = self.arch_triple();
+        if arch != "unknown" {
+            fields.push(("arch", arch.to_string()));
+        }
+        if let Some(rustc_version) = option_env!("PLATFORM_INFO_RUSTC_VERSION") {
+            fields.push(("rustc", rustc_version.to_string()));
+        }
+        fields.push(("build target", BuildTarget::CURRENT.to_string()));
+
+        let mut report = String::from("### Platform\n\n```text\n");
+        for (key, value) in fields {
+            // * keep each field on a single line (and prevent any premature closing of the code fence)
+            let value = value.replace(['\r', '\n'], " ").replace('`', "'");
+            report.push_str(&format!("{key}: {value}\n"));
+        }
+        report.push_str("```\n");
+        report
+    }
+
+    /// *Returns* the time of the last system boot, or `None` if it can't be determined.
+    ///
+    /// Derived from [`uptime()`](PlatformInfo::uptime) (and the current system time) on each call.
+    #[cfg(feature = "uptime")]
+    pub fn boot_time(&self) -> Option<std::time::SystemTime> {
+        std::time::SystemTime::now().checked_sub(self.uptime()?)
+    }
+
+    /// *Returns* the configured HTTP proxy (eg, `http://proxy.example.com:8080`), or `None` if no proxy is configured.
+    ///
+    /// Read from the environment (`http_proxy` or `HTTP_PROXY`); on WinOS, falls back to the WinINET (aka "Internet
+    /// Options") proxy settings.
+    pub fn http_proxy(&self) -> Option<OsString> {
+        let proxy = env_var_any_case("http_proxy");
+        #[cfg(windows)]
+        let proxy = proxy.or_else(|| target::winos_proxy("http"));
+        proxy
+    }
+
+    /// *Returns* the configured HTTPS proxy, or `None` if no proxy is configured.
+    ///
+    /// Read from the environment (`https_proxy` or `HTTPS_PROXY`); on WinOS, falls back to the WinINET (aka "Internet
+    /// Options") proxy settings.
+    pub fn https_proxy(&self) -> Option<OsString> {
+        let proxy = env_var_any_case("https_proxy");
+        #[cfg(windows)]
+        let proxy = proxy.or_else(|| target::winos_proxy("https"));
+        proxy
+    }
+
+    /// *Returns* the configured list of hosts/domains which bypass the proxy (eg, "localhost,.example.com"), or `None`
+    /// if unset.
+    ///
+    /// Read from the environment (`no_proxy` or `NO_PROXY`); on WinOS, falls back to the WinINET (aka "Internet
+    /// Options") proxy bypass list (converted to comma-separated form).
+    pub fn no_proxy(&self) -> Option<OsString> {
+        let no_proxy = env_var_any_case("no_proxy");
+        #[cfg(windows)]
+        let no_proxy = no_proxy.or_else(target::winos_no_proxy);
+        no_proxy
+    }
+
+    /// *Returns* the user's preferred text editor, from the environment (`VISUAL`, or, alternatively, `EDITOR`).
+    ///
+    /// If neither is set (or both are empty), *returns* `None`; except on WinOS, where "notepad" is returned.
+    pub fn default_editor(&self) -> Option<OsString> {
+        let editor = env_var_non_empty("VISUAL").or_else(|| env_var_non_empty("EDITOR"));
+        if cfg!(windows) {
+            editor.or_else(|| Some(OsString::from("notepad")))
+        } else {
+            editor
+        }
+    }
+
+    /// *Returns* the user's preferred pager, from the environment (`PAGER`), or `None` if unset (or empty).
+    pub fn default_pager(&self) -> Option<OsString> {
+        env_var_non_empty("PAGER")
+    }
+}
+
+// env_var_any_case
+/// *Returns* the value of the environment variable `name` (preferring the lowercase form, as is conventional for proxy
+/// variables; then the uppercase form), or `None` if unset or empty.
+fn env_var_any_case(name: &str) -> Option<OsString> {
+    env_var_non_empty(&name.to_ascii_lowercase())
+        .or_else(|| env_var_non_empty(&name.to_ascii_uppercase()))
+}
+
+// is_invisible_char
+/// *Returns* `true` if `c` is a control character (`Cc`), a format character (`Cf`; eg, a zero width space, a soft
+/// hyphen, a bidirectional override, or a tag character), a line/paragraph separator, or a private-use character;
+/// printable Unicode (including symbols and emojis) is allowed.
+///
+/// Note: the zero width joiner (U+200D) is allowed, as it's used within (printable) emoji sequences.
+// ref: <https://www.unicode.org/Public/UCD/latest/ucd/extracted/DerivedGeneralCategory.txt>
+pub(crate) fn is_invisible_char(c: char) -> bool {
+    c.is_control()
+        || matches!(
+            c,
+            // format characters (`Cf`), except the zero width joiner (U+200D)
+            '\u{00AD}' // soft hyphen
+            | '\u{0600}'..='\u{0605}' | '\u{061C}' | '\u{06DD}' | '\u{070F}' // Arabic/Syriac signs, Arabic letter mark
+            | '\u{0890}'..='\u{0891}' | '\u{08E2}' // Arabic pound/piastre marks, Arabic disputed end of ayah
+            | '\u{180E}' // Mongolian vowel separator
+            | '\u{200B}' | '\u{200C}' // zero width space, zero width non-joiner
+            | '\u{200E}' | '\u{200F}' // directional marks
+            | '\u{202A}'..='\u{202E}' | '\u{2066}'..='\u{2069}' // bidirectional embeddings/overrides/isolates
+            | '\u{2060}'..='\u{2064}' | '\u{206A}'..='\u{206F}' // word joiner, invisible operators, deprecated formats
+            | '\u{FEFF}' // zero width no-break space (aka BOM)
+            | '\u{FFF9}'..='\u{FFFB}' // interlinear annotation controls
+            | '\u{110BD}' | '\u{110CD}' // Kaithi number signs
+            | '\u{13430}'..='\u{1343F}' // Egyptian hieroglyph format controls
+            | '\u{1BCA0}'..='\u{1BCA3}' // shorthand format controls
+            | '\u{1D173}'..='\u{1D17A}' // musical symbol format controls
+            | '\u{E0000}'..='\u{E007F}' // language tag and tag characters
+            // line/paragraph separators
+            | '\u{2028}' | '\u{2029}'
+            // private-use characters
+            | '\u{E000}'..='\u{F8FF}' | '\u{F0000}'..='\u{FFFFD}' | '\u{100000}'..='\u{10FFFD}'
+        )
+}
+
+// contains_control_chars
+/// *Returns* `true` if `s` contains any control or invisible formatting characters (see [`is_invisible_char()`]).
+pub(crate) fn contains_control_chars(s: &str) -> bool {
+    s.chars().any(is_invisible_char)
+}
+
+// has_control_chars
+/// *Returns* `true` if any `uname`-style field of `info` contains control (or invisible formatting or private-use)
+/// characters, which may disrupt (or spoof) terminal display of the field (or hide text within it).
+#[cfg(test)]
+pub(crate) fn has_control_chars(info: &(impl crate::UNameAPI + ?Sized)) -> bool {
+    info.fields()
+        .iter()
+        .any(|(_, field)| contains_control_chars(&field.to_string_lossy()))
+}
+
+// sanitize_str
+/// *Returns* `s` with each control or invisible formatting character (see [`is_invisible_char()`]) replaced by its
+/// Unicode escape (eg, "\u{1b}").
+pub(crate) fn sanitize_str(s: &str) -> String {
+    if !contains_control_chars(s) {
+        return String::from(s);
+    }
+    let mut sanitized = String::with_capacity(s.len());
+    for c in s.chars() {
+        if is_invisible_char(c) {
+            sanitized.extend(c.escape_unicode());
+        } else {
+            sanitized.push(c);
+        }
+    }
+    sanitized
+}
+
+// shell_safe_name
+/// *Returns* `name` with all characters that are unsafe for unquoted shell or file name use replaced by `_` (see
+/// [`PlatformInfo::machine_shell_safe()`] for the escaping rules).
+fn shell_safe_name(name: &str) -> String {
+    if name.is_empty() {
+        return String::from("unknown");
+    }
+    name.chars()
+        .enumerate()
+        .map(|(i, c)| match c {
+            '-' | '.' if i == 0 => '_',
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '.' | '_' | '+' | '-' => c,
+            _ => '_',
+        })
+        .collect()
+}
+
+// is_foreign_machine
+/// *Returns* `true` if the OS-reported machine name (`machine`) names a known architecture which differs from the
+/// (Rust) target architecture (`target_arch`; eg, "x86_64" machine for an "x86" target).
+///
+/// Hardware platform names (eg, illumos/Solaris "i86pc", "sun4u", or "sun4v") don't identify the instruction set
+/// architecture in use, so are never considered foreign.
+#[allow(dead_code)] // * fn is unused on some platforms
+pub(crate) fn is_foreign_machine(machine: &str, target_arch: &str) -> bool {
+    if is_platform_machine_name(machine) {
+        return false;
+    }
+    let arch = rust_arch_from_machine(machine);
+    arch != "unknown" && arch != target_arch
+}
+
+// is_platform_machine_name
+/// *Returns* `true` if `machine` is an illumos/Solaris hardware platform name (eg, "i86pc" or "sun4v"), rather than an
+/// instruction set architecture name.
+pub(crate) fn is_platform_machine_name(machine: &str) -> bool {
+    matches!(machine, "i86pc" | "i86xpv" | "sun4u" | "sun4v")
+}
+
+// rust_arch_from_machine
+/// *Returns* the Rust target architecture name corresponding to an OS-reported machine name (`machine`).
+// ref: <https://doc.rust-lang.org/std/env/consts/constant.ARCH.html>
+pub(crate) fn rust_arch_from_machine(machine: &str) -> &'static str {
+    match machine.to_ascii_lowercase().as_str() {
+        "x86_64" | "amd64" | "x64" => "x86_64",
+        "x86" | "i386" | "i486" | "i586" | "i686" => "x86",
+        "aarch64" | "aarch64_be" | "arm64" | "arm64e" | "arm64ec" => "aarch64",
+        m if m.starts_with("arm") => "arm",
+        "riscv64" => "riscv64",
+        "riscv32" => "riscv32",
+        "ppc64" | "ppc64le" | "powerpc64" | "powerpc64le" => "powerpc64",
+        "ppc" | "ppcle" | "powerpc" => "powerpc",
+        "mips" | "mipsel" => "mips",
+        "mips64" | "mips64el" => "mips64",
+        "s390x" => "s390x",
+        "sparc64" | "sun4u" | "sun4v" => "sparc64",
+        "sparc" => "sparc",
+        "loongarch64" => "loongarch64",
+        "m68k" => "m68k",
+        // * no Rust equivalent (eg, "ia64", "alpha", or "superh"); or a hardware platform name which doesn't identify
+        //   an instruction set architecture (eg, illumos/Solaris "i86pc", used for both "amd64" and "i386" kernels)
+        _ => "unknown",
+    }
+}
+
+// dpkg_arch_from_machine
+/// *Returns* the Debian (`dpkg`) architecture name corresponding to an OS-reported machine name (`machine`).
+fn dpkg_arch_from_machine(machine: &str) -> Option<&'static str> {
+    let machine = machine.to_ascii_lowercase();
+    let arch = match machine.as_str() {
+        "x86_64" | "amd64" | "x64" => "amd64",
+        "x86" | "i386" | "i486" | "i586" | "i686" => "i386",
+        "aarch64" | "arm64" => "arm64",
+        m if m.starts_with("armv4") || m.starts_with("armv5") => "armel",
+        m if m.starts_with("arm") => "armhf",
+        "ppc64le" | "powerpc64le" => "ppc64el",
+        "ppc64" | "powerpc64" => "ppc64",
+        "ppc" | "powerpc" => "powerpc",
+        "riscv64" => "riscv64",
+        "s390x" => "s390x",
+        "mips64el" => "mips64el",
+        "mipsel" => "mipsel",
+        "mips" => "mips",
+        "loongarch64" => "loong64",
+        "sparc64" | "sun4u" | "sun4v" => "sparc64",
+        "m68k" => "m68k",
+        "alpha" => "alpha",
+        "ia64" => "ia64",
+        "parisc" | "parisc64" | "hppa" => "hppa",
+        _ => return None,
+    };
+    Some(arch)
+}
+
+// rpm_arch_from_machine
+/// *Returns* the RPM architecture name corresponding to an OS-reported machine name (`machine`).
+fn rpm_arch_from_machine(machine: &str) -> Option<&'static str> {
+    let machine = machine.to_ascii_lowercase();
+    let arch = match machine.as_str() {
+        "x86_64" | "amd64" | "x64" => "x86_64",
+        "x86" | "i686" => "i686",
+        "i386" => "i386",
+        "i486" => "i486",
+        "i586" => "i586",
+        "aarch64" | "arm64" => "aarch64",
+        m if m.starts_with("armv7") || m == "armv8l" => "armv7hl",
+        m if m.starts_with("armv6") => "armv6hl",
+        m if m.starts_with("armv5") => "armv5tel",
+        "ppc64le" | "powerpc64le" => "ppc64le",
+        "ppc64" | "powerpc64" => "ppc64",
+        "ppc" | "powerpc" => "ppc",
+        "riscv64" => "riscv64",
+        "s390x" => "s390x",
+        "loongarch64" => "loongarch64",
+        "sparc64" | "sun4u" | "sun4v" => "sparc64",
+        "m68k" => "m68k",
+        "alpha" => "alpha",
+        "ia64" => "ia64",
+        _ => return None,
+    };
+    Some(arch)
+}
+
+// target_kernel_kind
+/// *Returns* the [`KernelKind`] of the compilation target, or `None` if unknown.
+fn target_kernel_kind() -> Option<KernelKind> {
+    if cfg!(any(target_os = "linux", target_os = "android")) {
+        Some(KernelKind::Linux)
+    } else if cfg!(target_vendor = "apple") {
+        Some(KernelKind::Darwin)
+    } else if cfg!(any(windows, target_os = "cygwin")) {
+        Some(KernelKind::WindowsNt)
+    } else if cfg!(target_os = "freebsd") {
+        Some(KernelKind::FreeBsd)
+    } else if cfg!(target_os = "netbsd") {
+        Some(KernelKind::NetBsd)
+    } else if cfg!(target_os = "openbsd") {
+        Some(KernelKind::OpenBsd)
+    } else if cfg!(target_os = "dragonfly") {
+        Some(KernelKind::DragonFly)
+    } else if cfg!(any(target_os = "illumos", target_os = "solaris")) {
+        Some(KernelKind::SunOs)
+    } else if cfg!(target_os = "aix") {
+        Some(KernelKind::Aix)
+    } else if cfg!(target_os = "haiku") {
+        Some(KernelKind::Haiku)
+    } else if cfg!(target_os = "hurd") {
+        Some(KernelKind::Hurd)
+    } else if cfg!(target_os = "fuchsia") {
+        Some(KernelKind::Fuchsia)
+    } else if cfg!(target_os = "redox") {
+        Some(KernelKind::Redox)
+    } else {
+        None
+    }
+}
+
+// env_var_non_empty
+/// *Returns* the value of the environment variable `name`, or `None` if it's unset or empty.
+fn env_var_non_empty(name: &str) -> Option<OsString> {
+    std::env::var_os(name).filter(|value| !value.is_empty())
+}
+
+// cpuid_leaf_1_eax
+/// *Returns* the `EAX` register value of CPUID leaf 1 (ie, the raw processor signature), or `None` for non-x86 processors.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+fn cpuid_leaf_1_eax() -> Option<u32> {
+    #[cfg(target_arch = "x86")]
+    use std::arch::x86::__cpuid;
+    #[cfg(target_arch = "x86_64")]
+    use std::arch::x86_64::__cpuid;
+
+    // SAFETY: CPUID is available on all x86 processors supported by Rust (ie, i586+) and all x86_64 processors
+    #[allow(unused_unsafe)] // * `__cpuid()` is a safe fn in newer versions of Rust
+    let result = unsafe { __cpuid(1) };
+    Some(result.eax)
+}
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+fn cpuid_leaf_1_eax() -> Option<u32> {
+    None
+}
+
+// has_native_atomic_128
+/// *Returns* whether the current CPU supports native 128-bit atomic operations.
+#[cfg(target_arch = "x86_64")]
+fn has_native_atomic_128() -> bool {
+    std::is_x86_feature_detected!("cmpxchg16b")
+}
+#[cfg(all(
+    target_arch = "aarch64",
+    any(target_os = "linux", target_os = "macos", target_os = "windows")
+))]
+fn has_native_atomic_128() -> bool {
+    std::arch::is_aarch64_feature_detected!("lse")
+}
+#[cfg(not(any(
+    target_arch = "x86_64",
+    all(
+        target_arch = "aarch64",
+        any(target_os = "linux", target_os = "macos", target_os = "windows")
+    )
+)))]
+fn has_native_atomic_128() -> bool {
+    false
+}
+
+//=== binary encoding
+
+// os_str_to_bytes
+/// *Returns* the platform-independent byte encoding of `os_str` (raw bytes on Unix-like platforms; WTF-8 on WinOS).
+#[cfg(unix)]
+pub(crate) fn os_str_to_bytes(os_str: &OsStr) -> Vec<u8> {
+    use std::os::unix::ffi::OsStrExt;
+    os_str.as_bytes().to_vec()
+}
+#[cfg(windows)]
+pub(crate) fn os_str_to_bytes(os_str: &OsStr) -> Vec<u8> {
+    use std::os::windows::ffi::OsStrExt;
+    // ref: [WTF-8](https://simonsapin.github.io/wtf-8/#encoding-ill-formed-utf-16)
+    let mut bytes = Vec::new();
+    for c in std::char::decode_utf16(os_str.encode_wide()) {
+        match c {
+            Ok(c) => bytes.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes()),
+            // * unpaired surrogates are encoded as (generalized) UTF-8 three-byte sequences
+            Err(e) => {
+                let surrogate = e.unpaired_surrogate();
+                bytes.extend_from_slice(&[
+                    0xe0 | (surrogate >> 12) as u8,
+                    0x80 | ((surrogate >> 6) & 0x3f) as u8,
+                    0x80 | (surrogate & 0x3f) as u8,
+                ]);
+            }
+        }
+    }
+    bytes
+}
+#[cfg(not(any(unix, windows)))]
+pub(crate) fn os_str_to_bytes(os_str: &OsStr) -> Vec<u8> {
+    os_str.to_string_lossy().into_owned().into_bytes()
+}
+
+// os_string_from_bytes
+/// *Returns* the `OsString` decoded from its platform-independent byte encoding (`bytes`; see `os_str_to_bytes()`).
+#[cfg(unix)]
+fn os_string_from_bytes(bytes: &[u8]) -> Result<OsString, BoxedThreadSafeStdError> {
+    use std::os::unix::ffi::OsStrExt;
+    Ok(OsStr::from_bytes(bytes).to_os_string())
+}
+#[cfg(windows)]
+fn os_string_from_bytes(bytes: &[u8]) -> Result<OsString, BoxedThreadSafeStdError> {
+    use std::os::windows::ffi::OsStringExt;
+    let mut wide = Vec::new();
+    let mut remaining = bytes;
+    while !remaining.is_empty() {
+        let valid_length = match std::str::from_utf8(remaining) {
+            Ok(s) => s.len(),
+            Err(e) => e.valid_up_to(),
+        };
+        let (valid, rest) = remaining.split_at(valid_length);
+        // * `valid` is known to be valid UTF-8
+        wide.extend(std::str::from_utf8(valid)?.encode_utf16());
+        remaining = rest;
+        // * any invalid UTF-8 must be an encoded (unpaired) surrogate (ie, 0xED 0xA0..=0xBF 0x80..=0xBF)
+        if let [0xed, b1 @ 0xa0..=0xbf, b2 @ 0x80..=0xbf, rest @ ..] = remaining {
+            wide.push(0xd000 | (u16::from(b1 & 0x3f) << 6) | u16::from(b2 & 0x3f));
+            remaining = rest;
+        } else if !remaining.is_empty() {
+            return Err("invalid WTF-8 field content".into());
+        }
+    }
+    Ok(OsString::from_wide(&wide))
+}
+#[cfg(not(any(unix, windows)))]
+fn os_string_from_bytes(bytes: &[u8]) -> Result<OsString, BoxedThreadSafeStdError> {
+    Ok(OsString::from(String::from_utf8(bytes.to_vec())?))
+}
+
+//=== JSON encoding
+
+// push_json_string
+/// Appends `s` to `json` as a JSON string literal (ie, quoted, with quotes, backslashes, and control characters
+/// escaped).
+// ref: [RFC 8259 ~ 7. Strings](https://www.rfc-editor.org/rfc/rfc8259#section-7)
+pub(crate) fn push_json_string(json: &mut String, s: &str) {
+    json.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            '\u{08}' => json.push_str("\\b"),
+            '\u{0C}' => json.push_str("\\f"),
+            c if c < '\u{20}' => {
+                json.push_str(&format!("\\u{:04x}", c as u32));
+            }
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+}
+
+//=== thread safety
+
+// * compile-time assertion that the public information types may be shared across threads (eg, within an `Arc`)
+// * note: `WinApiSystemInfo` wraps raw (address range) pointers, so it relies on an explicit `unsafe impl` of `Send`/`Sync`
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<PlatformInfo>();
+    assert_send_sync::<UnameData>();
+    assert_send_sync::<PlatformInfoRef<'static>>();
+    assert_send_sync::<BoxedThreadSafeStdError>();
+    #[cfg(unix)]
+    assert_send_sync::<UTSName>();
+    #[cfg(windows)]
+    {
+        assert_send_sync::<WinApiSystemInfo>();
+        assert_send_sync::<WinOsVersionInfo>();
+    }
+};
+
+//=== trait object boxing
+
+#[cfg(feature = "mockable")]
+impl PlatformInfo {
+    /// Converts this [`PlatformInfo`] into a boxed [`UNameAPI`](crate::UNameAPI) trait object (ie, a [`BoxedPlatformInfo`](crate::BoxedPlatformInfo)).
+    pub fn boxed(self) -> crate::BoxedPlatformInfo {
+        Box::new(self)
+    }
+}
+
+//=== async construction
+
+#[cfg(any(feature = "tokio", feature = "async-std"))]
+impl PlatformInfo {
+    /// Creates a new instance of [`PlatformInfo`], asynchronously.
+    /// <br> Information retrieval (which *may* block, eg, with file I/O and DLL loading on WinOS) is done on the blocking
+    /// thread pool of the async runtime (`tokio`, if enabled; o/w `async-std`).
+    pub async fn new_async() -> Result<Self, BoxedThreadSafeStdError> {
+        use crate::PlatformInfoAPI;
+
+        #[cfg(feature = "tokio")]
+        let result = tokio::task::spawn_blocking(<Self as PlatformInfoAPI>::new).await?;
+        #[cfg(all(feature = "async-std", not(feature = "tokio")))]
+        let result = async_std::task::spawn_blocking(<Self as PlatformInfoAPI>::new).await;
+
+        result
+    }
+}
+
+//=== tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contains_control_chars() {
+        let contains = contains_control_chars;
+        assert!(!contains(""));
+        assert!(!contains(
+            "Linux 6.1.0-13-amd64 #1 SMP PREEMPT_DYNAMIC x86_64 GNU/Linux"
+        ));
+        assert!(!contains("caf\u{e9}-\u{1F600}-\u{2122}")); // accented letters, emojis, and symbols are printable
+        assert!(!contains("\u{1F468}\u{200D}\u{1F469}")); // zero width joiner (within emoji sequences) is allowed
+        assert!(contains("host\x1b[2J"));
+        assert!(contains("host\n"));
+        assert!(contains("host\0"));
+        assert!(contains("\u{7f}"));
+        assert!(contains("\u{9b}")); // C1 control (CSI)
+        assert!(contains("ab\u{200B}cd"));
+        assert!(contains("\u{202E}txt.exe"));
+        assert!(contains("\u{FEFF}host"));
+        assert!(contains("pass\u{AD}word")); // soft hyphen
+        assert!(contains("ab\u{200C}cd")); // zero width non-joiner
+        assert!(contains("ab\u{180E}cd")); // Mongolian vowel separator
+        assert!(contains("\u{FFF9}host\u{FFFA}hidden\u{FFFB}")); // interlinear annotations
+        assert!(contains("\u{2064}")); // invisible plus
+        assert!(contains("\u{E000}")); // private-use (BMP)
+        assert!(contains("\u{F8FF}"));
+        assert!(contains("\u{F0000}")); // private-use (supplementary planes)
+        assert!(contains("\u{10FFFD}"));
+        assert!(contains("host\u{E0001}\u{E0068}\u{E0069}\u{E007F}")); // tag characters ("smuggled" hidden text)
+        assert!(contains("\u{E0000}"));
+        assert!(contains("\u{2028}")); // line separator
+        assert!(!contains("\u{2070}\u{F900}")); // superscript zero, CJK compatibility ideograph (ie, just past ranges)
+    }
+
+    #[test]
+    fn test_has_control_chars() {
+        use crate::{PlatformInfoAPI, UNameAPI};
+
+        let info = PlatformInfo::new().unwrap();
+        assert!(!has_control_chars(&info));
+
+        let data = UnameData {
+            nodename: OsString::from("host\x1b]0;title\x07"),
+            ..UnameData::default()
+        };
+        assert!(has_control_chars(&data));
+        assert!(!has_control_chars(&data.sanitized()));
+        assert!(!has_control_chars(&UnameData::default()));
+    }
+
+    #[test]
+    fn test_shell_safe_name() {
+        let shell_safe = shell_safe_name;
+        assert_eq!(shell_safe("x86_64"), "x86_64");
+        assert_eq!(shell_safe("armv7-a+neon"), "armv7-a+neon");
+        assert_eq!(shell_safe("Power Macintosh"), "Power_Macintosh");
+        assert_eq!(shell_safe("../../etc/passwd"), "_._.._etc_passwd");
+        assert_eq!(shell_safe("x86/64"), "x86_64");
+        assert_eq!(shell_safe("a'b\"c$(d)`e`;f"), "a_b_c__d__e__f");
+        assert_eq!(shell_safe("-rf"), "_rf");
+        assert_eq!(shell_safe(".hidden"), "_hidden");
+        assert_eq!(shell_safe("m\u{e4}chine"), "m_chine");
+        assert_eq!(shell_safe(""), "unknown");
+    }
+
+    #[test]
+    fn test_dpkg_arch_from_machine() {
+        let dpkg_arch = dpkg_arch_from_machine;
+        assert_eq!(dpkg_arch("x86_64"), Some("amd64"));
+        assert_eq!(dpkg_arch("amd64"), Some("amd64"));
+        assert_eq!(dpkg_arch("i686"), Some("i386"));
+        assert_eq!(dpkg_arch("i386"), Some("i386"));
+        assert_eq!(dpkg_arch("aarch64"), Some("arm64"));
+        assert_eq!(dpkg_arch("arm64"), Some("arm64"));
+        assert_eq!(dpkg_arch("armv7l"), Some("armhf"));
+        assert_eq!(dpkg_arch("armv6l"), Some("armhf"));
+        assert_eq!(dpkg_arch("armv5tel"), Some("armel"));
+        assert_eq!(dpkg_arch("ppc64le"), Some("ppc64el"));
+        assert_eq!(dpkg_arch("s390x"), Some("s390x"));
+        assert_eq!(dpkg_arch("riscv64"), Some("riscv64"));
+        assert_eq!(dpkg_arch("mips64el"), Some("mips64el"));
+        assert_eq!(dpkg_arch("loongarch64"), Some("loong64"));
+        assert_eq!(dpkg_arch("i86pc"), None); // illumos/Solaris hardware platform name (ie, not an ISA)
+        assert_eq!(dpkg_arch("vax"), None);
+        assert_eq!(dpkg_arch(""), None);
+    }
+
+    #[test]
+    fn test_rpm_arch_from_machine() {
+        let rpm_arch = rpm_arch_from_machine;
+        assert_eq!(rpm_arch("x86_64"), Some("x86_64"));
+        assert_eq!(rpm_arch("amd64"), Some("x86_64"));
+        assert_eq!(rpm_arch("i686"), Some("i686"));
+        assert_eq!(rpm_arch("aarch64"), Some("aarch64"));
+        assert_eq!(rpm_arch("arm64"), Some("aarch64"));
+        assert_eq!(rpm_arch("armv7l"), Some("armv7hl"));
+        assert_eq!(rpm_arch("armv6l"), Some("armv6hl"));
+        assert_eq!(rpm_arch("ppc64le"), Some("ppc64le"));
+        assert_eq!(rpm_arch("s390x"), Some("s390x"));
+        assert_eq!(rpm_arch("riscv64"), Some("riscv64"));
+        assert_eq!(rpm_arch("sun4v"), Some("sparc64"));
+        assert_eq!(rpm_arch("i86pc"), None); // illumos/Solaris hardware platform name (ie, not an ISA)
+        assert_eq!(rpm_arch("vax"), None);
+        assert_eq!(rpm_arch(""), None);
+    }
+}
@@ -19,11 +19,15 @@
 
 #![warn(unused_results)] // enable warnings for unused results
 
+use std::collections::BTreeMap;
 use std::ffi::{OsStr, OsString};
 use std::fmt;
 use std::fmt::{Debug, Formatter};
 
-use crate::{PlatformInfoAPI, PlatformInfoError, UNameAPI};
+use crate::{
+    ChassisType, MitigationSummary, PlatformInfoAPI, PlatformInfoError, SocketBufferLimits,
+    UNameAPI,
+};
 
 use unix_safe::{oss_from_cstr, utsname};
 
@@ -41,20 +45,103 @@ pub struct PlatformInfo {
     version: OsString,
     machine: OsString,
     osname: OsString,
+    #[cfg(target_os = "haiku")]
+    kernel_build_date: Option<OsString>,
+    #[cfg(target_os = "haiku")]
+    kernel_build_version: Option<i64>,
+    #[cfg(any(target_os = "illumos", target_os = "solaris"))]
+    platform: Option<OsString>,
+    #[cfg(any(target_os = "illumos", target_os = "solaris"))]
+    hw_provider: Option<OsString>,
+    #[cfg(target_os = "android")]
+    android_release: Option<OsString>,
+    #[cfg(target_os = "android")]
+    android_sdk_int: Option<u32>,
+    #[cfg(target_arch = "riscv64")]
+    isa_string: Option<OsString>,
 }
 
 impl PlatformInfoAPI for PlatformInfo {
     // * note: this function *should* never fail
     fn new() -> Result<Self, PlatformInfoError> {
-        let utsname = UTSName(utsname()?);
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("PlatformInfo::new").entered();
+        Self::from_source(SystemUname)
+    }
+}
+
+impl PlatformInfo {
+    /// Creates a new instance of [`PlatformInfo`], using `source` (in place of the `uname()` system call) to retrieve
+    /// the `utsname` information.
+    ///
+    /// All other (non-`utsname`) information is retrieved from the current system, as for
+    /// [`new()`](PlatformInfoAPI::new).
+    pub(crate) fn from_source(source: impl UnameSource) -> Result<Self, PlatformInfoError> {
+        let utsname = match source.utsname() {
+            Ok(utsname) => UTSName(utsname),
+            Err(error) => {
+                #[cfg(feature = "tracing")]
+                tracing::debug!(%error, "uname() failed");
+                return Err(error.into());
+            }
+        };
+        #[cfg(feature = "tracing")]
+        tracing::trace!(utsname = ?utsname, "uname() succeeded");
+        // Haiku-native kernel details (unavailable via `uname`)
+        #[cfg(target_os = "haiku")]
+        let (kernel_build_date, kernel_build_version) = match unix_safe::haiku_system_info() {
+            Ok(info) => {
+                let mut build_date = oss_from_cstr(&info.kernel_build_date);
+                build_date.push(" ");
+                build_date.push(oss_from_cstr(&info.kernel_build_time));
+                (Some(build_date), Some(info.kernel_version))
+            }
+            Err(_) => (None, None),
+        };
+        let version = oss_from_cstr(&utsname.0.version);
+        // enrich an empty/truncated kernel version from the (more complete) `/proc/version`
+        #[cfg(all(
+            feature = "proc-version",
+            any(target_os = "linux", target_os = "android")
+        ))]
+        let version = if version.is_empty() || cstr_field_is_full(&utsname.0.version) {
+            std::fs::read_to_string("/proc/version")
+                .ok()
+                .and_then(|content| {
+                    let full = version_from_proc_version(&content)?;
+                    let is_enriched =
+                        full.len() > version.len() && full.starts_with(&*version.to_string_lossy());
+                    is_enriched.then(|| OsString::from(full))
+                })
+                .unwrap_or(version)
+        } else {
+            version
+        };
         Ok(Self {
             utsname,
             sysname: oss_from_cstr(&utsname.0.sysname),
             nodename: oss_from_cstr(&utsname.0.nodename),
             release: oss_from_cstr(&utsname.0.release),
-            version: oss_from_cstr(&utsname.0.version),
+            version,
             machine: oss_from_cstr(&utsname.0.machine),
-            osname: OsString::from(crate::lib_impl::HOST_OS_NAME),
+            osname: OsString::from(crate::host_os_name()),
+            #[cfg(target_os = "haiku")]
+            kernel_build_date,
+            #[cfg(target_os = "haiku")]
+            kernel_build_version,
+            #[cfg(any(target_os = "illumos", target_os = "solaris"))]
+            platform: unix_safe::sysinfo_string(libc::SI_PLATFORM),
+            #[cfg(any(target_os = "illumos", target_os = "solaris"))]
+            hw_provider: unix_safe::sysinfo_string(libc::SI_HW_PROVIDER),
+            #[cfg(target_os = "android")]
+            android_release: unix_safe::system_property("ro.build.version.release"),
+            #[cfg(target_os = "android")]
+            android_sdk_int: unix_safe::system_property("ro.build.version.sdk")
+                .and_then(|sdk| sdk.to_str()?.parse().ok()),
+            #[cfg(target_arch = "riscv64")]
+            isa_string: std::fs::read_to_string("/proc/cpuinfo")
+                .ok()
+                .and_then(|cpuinfo| isa_from_cpuinfo(&cpuinfo).map(OsString::from)),
         })
     }
 }
@@ -85,6 +172,708 @@ impl UNameAPI for PlatformInfo {
     }
 }
 
+impl PlatformInfo {
+    /// Re-queries volatile information (ie, the node name), updating the cached values in place.
+    ///
+    /// Refreshes [`utsname`](PlatformInfo::utsname) and [`nodename()`](UNameAPI::nodename); all other cached values
+    /// are left untouched. On failure, all cached values are left unchanged.
+    pub fn refresh(&mut self) -> Result<(), PlatformInfoError> {
+        let utsname = UTSName(utsname()?);
+        self.nodename = oss_from_cstr(&utsname.0.nodename);
+        self.utsname = utsname;
+        Ok(())
+    }
+
+    /// *Returns* the kernel version, parsed from [`release()`](UNameAPI::release), as `(major, minor, patch, suffix)`.
+    ///
+    /// Supports the common "*X*.*Y*.*Z*-*suffix*" (eg, "6.5.0-21-generic" => `(6, 5, 0, Some("21-generic"))`) and
+    /// "*X*.*Y*" forms; missing `minor`/`patch` components are reported as zero, and any additional numeric components
+    /// are kept within the suffix (eg, "5.15.90.1-microsoft-standard-WSL2" => `(5, 15, 90,
+    /// Some("1-microsoft-standard-WSL2"))`). *Returns* `None` only if even the `major` version component can't be parsed.
+    pub fn kernel_version(&self) -> Option<(u32, u32, u32, Option<OsString>)> {
+        parse_kernel_version(&self.release)
+    }
+
+    /// *Returns* the OS-native name of the machine hardware, exactly as reported by `uname()` (`utsname.machine`).
+    ///
+    /// For Unix-like platforms, this is currently identical to [`machine()`](UNameAPI::machine).
+    pub fn machine_raw(&self) -> OsString {
+        oss_from_cstr(&self.utsname.0.machine)
+    }
+
+    /// *Returns* the machine hardware name as seen by the (userland) ABI of the current process (eg, "i686" for an "x86"
+    /// binary on an "x86_64" kernel).
+    ///
+    /// [`machine()`](UNameAPI::machine) reports the kernel's machine name, which differs from the userland ABI for a
+    /// 32-bit process on a 64-bit kernel (unless run under a 32-bit personality; eg, via `setarch i686`, which changes
+    /// the kernel-reported value itself). In that case, the corresponding 32-bit machine name is returned ("i686",
+    /// "armv8l", "ppc", "sparc", or "mips"); otherwise, this is identical to `machine()`.
+    pub fn machine_userland(&self) -> OsString {
+        match userland_machine(&self.machine.to_string_lossy(), std::env::consts::ARCH) {
+            Some(machine) => OsString::from(machine),
+            None => self.machine.clone(),
+        }
+    }
+
+    /// *Returns* `true` if the current process is running under emulation/translation (eg, Rosetta 2 on MacOS) or in a
+    /// compatibility mode (eg, an "x86" binary on an "x86_64" Linux kernel).
+    ///
+    /// Detected via `sysctl.proc_translated` for MacOS (queried on each call), otherwise by comparing the kernel-reported
+    /// machine name ([`machine_raw()`](PlatformInfo::machine_raw)) with the compiled target architecture
+    /// ([`BuildTarget::arch`](crate::BuildTarget::arch)).
+    pub fn running_under_emulation(&self) -> bool {
+        #[cfg(target_os = "macos")]
+        if unix_safe::sysctl_int("sysctl.proc_translated") == Some(1) {
+            return true;
+        }
+        crate::lib_impl::is_foreign_machine(
+            &self.machine_raw().to_string_lossy(),
+            std::env::consts::ARCH,
+        )
+    }
+
+    /// Replaces the cached machine name (see [`with_machine_overrides()`](PlatformInfo::with_machine_overrides)).
+    pub(crate) fn set_machine(&mut self, machine: OsString) {
+        self.machine = machine;
+    }
+
+    /// Converts into the (owned) `uname`-style fields, moving (rather than cloning) the cached values.
+    pub(crate) fn into_uname_data(self) -> crate::UnameData {
+        crate::UnameData {
+            sysname: self.sysname,
+            nodename: self.nodename,
+            release: self.release,
+            version: self.version,
+            machine: self.machine,
+            osname: self.osname,
+        }
+    }
+
+    /// *Returns* the bitness (ie, 32 or 64) of the operating system (independent of the bitness of the current
+    /// process), or `None` if it can't be determined.
+    ///
+    /// Derived from [`machine()`](UNameAPI::machine) (eg, "x86_64" or "aarch64" => 64; "i686" or "armv7l" => 32).
+    pub fn os_bitness(&self) -> Option<u8> {
+        bitness_from_machine(&self.machine.to_string_lossy())
+    }
+
+    /// *Returns* the kernel build date and time (eg, "Jan 23 2024 10:31:47"), as reported by `get_system_info()`.
+    ///
+    /// Haiku-only; determined once, during construction.
+    #[cfg(target_os = "haiku")]
+    pub fn kernel_build_date(&self) -> Option<&OsStr> {
+        self.kernel_build_date.as_deref()
+    }
+
+    /// *Returns* the (numeric) kernel build version, as reported by `get_system_info()`.
+    ///
+    /// Haiku-only; determined once, during construction.
+    #[cfg(target_os = "haiku")]
+    pub fn kernel_build_version(&self) -> Option<i64> {
+        self.kernel_build_version
+    }
+
+    /// *Returns* the hardware platform name (eg, "i86pc" or "SUNW,SPARC-Enterprise-T5220"), as reported by
+    /// `sysinfo(SI_PLATFORM)`.
+    ///
+    /// Illumos/Solaris-only; determined once, during construction.
+    #[cfg(any(target_os = "illumos", target_os = "solaris"))]
+    pub fn platform(&self) -> Option<&OsStr> {
+        self.platform.as_deref()
+    }
+
+    /// *Returns* the hardware manufacturer name (eg, "Oracle Corporation"), as reported by `sysinfo(SI_HW_PROVIDER)`.
+    ///
+    /// Illumos/Solaris-only; determined once, during construction.
+    #[cfg(any(target_os = "illumos", target_os = "solaris"))]
+    pub fn hw_provider(&self) -> Option<&OsStr> {
+        self.hw_provider.as_deref()
+    }
+
+    /// *Returns* the user-visible Android version (eg, "14"), as reported by the `ro.build.version.release` system
+    /// property.
+    ///
+    /// Note: [`release()`](UNameAPI::release) remains the (Linux) kernel release. Android-only; determined once, during
+    /// construction.
+    #[cfg(target_os = "android")]
+    pub fn android_release(&self) -> Option<&OsStr> {
+        self.android_release.as_deref()
+    }
+
+    /// *Returns* the Android SDK (API) level (eg, 34 for Android 14), as reported by the `ro.build.version.sdk` system
+    /// property.
+    ///
+    /// Android-only; determined once, during construction.
+    #[cfg(target_os = "android")]
+    pub fn android_sdk_int(&self) -> Option<u32> {
+        self.android_sdk_int
+    }
+
+    /// *Returns* the RISC-V ISA string (eg, "rv64imafdc_zicsr_zifencei"), as reported by the `isa` line of
+    /// `/proc/cpuinfo`, or `None` if it can't be determined.
+    ///
+    /// RISC-V-only (`riscv64`); determined once, during construction.
+    #[cfg(target_arch = "riscv64")]
+    pub fn isa_string(&self) -> Option<&OsStr> {
+        self.isa_string.as_deref()
+    }
+
+    /// *Returns* the form factor (aka chassis type) of the system, or `None` if it can't be determined.
+    ///
+    /// Queried on each call; determined from DMI information (via `sysfs`) for Linux, and inferred from the model
+    /// identifier (`hw.model`) for MacOS.
+    pub fn chassis_type(&self) -> Option<ChassisType> {
+        chassis_type()
+    }
+
+    /// *Returns* the kernel graphics (DRM) drivers in use, along with their versions, when available (eg, "amdgpu" or
+    /// "nvidia 535.104.05"), or `None` if no graphics device is found.
+    ///
+    /// Queried (via `sysfs`) on each call; currently only supported for Linux.
+    pub fn graphics_drivers(&self) -> Option<Vec<OsString>> {
+        // ref: <https://www.kernel.org/doc/html/latest/gpu/drm-uapi.html>
+        let mut cards: Vec<_> = std::fs::read_dir("/sys/class/drm")
+            .ok()?
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| {
+                // * only primary "cardN" nodes (excluding connectors, eg "card0-HDMI-A-1", and render nodes)
+                let name = entry.file_name();
+                let name = name.to_string_lossy();
+                name.len() > 4
+                    && name.starts_with("card")
+                    && name[4..].bytes().all(|b| b.is_ascii_digit())
+            })
+            .map(|entry| entry.path())
+            .collect();
+        cards.sort();
+
+        let mut drivers = Vec::new();
+        for card in cards {
+            let driver_path = match std::fs::read_link(card.join("device/driver")) {
+                Ok(path) => path,
+                Err(_) => continue,
+            };
+            let name = match driver_path.file_name() {
+                Some(name) => name,
+                None => continue,
+            };
+            let mut driver = name.to_os_string();
+            // * in-tree drivers are versioned with the kernel; out-of-tree modules (eg, "nvidia") report a version
+            let version_path = std::path::Path::new("/sys/module")
+                .join(name)
+                .join("version");
+            if let Some(version) = read_sys_value(version_path) {
+                driver.push(" ");
+                driver.push(version);
+            }
+            if !drivers.contains(&driver) {
+                drivers.push(driver);
+            }
+        }
+        if drivers.is_empty() {
+            None
+        } else {
+            Some(drivers)
+        }
+    }
+
+    /// *Returns* the fully qualified domain name (FQDN) of this machine (eg, "host.example.com"), or `None` if it can't
+    /// be resolved.
+    ///
+    /// Resolved (via `getaddrinfo()`) from [`nodename()`](UNameAPI::nodename) on each call. Note: resolution *may*
+    /// require a DNS query, so this call may block (eg, for several seconds if the DNS server is unreachable).
+    pub fn fqdn(&self) -> Option<OsString> {
+        unix_safe::canonical_name(&self.nodename).filter(|name| !name.is_empty())
+    }
+
+    /// *Returns* `true` if the cached node name fills its entire `utsname.nodename` field (eg, 64 bytes, plus the
+    /// terminating NUL, for Linux), strongly suggesting that the actual host name was truncated.
+    ///
+    /// For a truncated node name, the full host name may be available via `gethostname()` or
+    /// [`fqdn()`](PlatformInfo::fqdn).
+    pub fn nodename_is_truncated(&self) -> bool {
+        cstr_field_is_full(&self.utsname.0.nodename)
+    }
+
+    /// *Returns* the names of any attached FIDO2/U2F security keys (ie, HID devices using the FIDO Alliance usage page),
+    /// or `None` if none are present.
+    ///
+    /// Queried (via `sysfs`) on each call; currently only supported for Linux.
+    pub fn fido_authenticators(&self) -> Option<Vec<OsString>> {
+        let mut devices: Vec<_> = std::fs::read_dir("/sys/class/hidraw")
+            .ok()?
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .collect();
+        devices.sort();
+
+        let mut authenticators = Vec::new();
+        for device in devices {
+            let is_fido = std::fs::read(device.join("device/report_descriptor"))
+                .is_ok_and(|descriptor| hid_report_descriptor_is_fido(&descriptor));
+            if !is_fido {
+                continue;
+            }
+            // * `uevent` contains "KEY=value" lines (eg, "HID_NAME=Yubico YubiKey OTP+FIDO+CCID")
+            let name = std::fs::read_to_string(device.join("device/uevent"))
+                .ok()
+                .and_then(|uevent| {
+                    uevent
+                        .lines()
+                        .find_map(|line| line.strip_prefix("HID_NAME=").map(OsString::from))
+                })
+                .or_else(|| device.file_name().map(OsStr::to_os_string));
+            if let Some(name) = name {
+                authenticators.push(name);
+            }
+        }
+        if authenticators.is_empty() {
+            None
+        } else {
+            Some(authenticators)
+        }
+    }
+
+    /// *Returns* the set of natively determined ("real") fields for this platform.
+    ///
+    /// All `uname`-style fields are set from `uname()`, except for `osname` (a compile-time name; unset if "unknown").
+    /// `DOMAINNAME` is set only for platforms with a `utsname.domainname` field (see
+    /// [`UTSName::domainname()`](UTSName::domainname)).
+    pub fn field_capabilities(&self) -> crate::FieldCapabilities {
+        use crate::FieldCapabilities as F;
+        let mut capabilities = F::SYSNAME | F::NODENAME | F::RELEASE | F::VERSION | F::MACHINE;
+        if crate::host_os_name() != "unknown" {
+            capabilities |= F::OSNAME;
+        }
+        // * note: keep this target list in sync with the other `domainname` cfg gates (in this file)
+        if cfg!(not(any(
+            target_os = "aix",
+            target_os = "illumos",
+            target_os = "solaris",
+            target_vendor = "apple",
+            target_os = "dragonfly",
+            target_os = "freebsd",
+            target_os = "openbsd",
+            target_os = "netbsd",
+            target_os = "haiku",
+            target_os = "nto",
+            target_os = "vxworks"
+        ))) {
+            capabilities |= F::DOMAINNAME;
+        }
+        capabilities
+    }
+
+    /// *Returns* the size (in bytes) of a memory page, as reported by `sysconf(_SC_PAGESIZE)`, or `None` if it can't be
+    /// determined.
+    pub fn page_size(&self) -> Option<usize> {
+        unix_safe::page_size()
+    }
+
+    /// *Returns* the time elapsed since the last system boot, or `None` if it can't be determined.
+    ///
+    /// Queried on each call; read from `/proc/uptime` for Linux, and derived from the boot time (`kern.boottime`) for
+    /// MacOS/BSD (`None` for other platforms).
+    #[cfg(feature = "uptime")]
+    pub fn uptime(&self) -> Option<std::time::Duration> {
+        if cfg!(any(target_os = "linux", target_os = "android")) {
+            return parse_proc_uptime(&std::fs::read_to_string("/proc/uptime").ok()?);
+        }
+        std::time::SystemTime::now()
+            .duration_since(unix_safe::boot_time()?)
+            .ok()
+    }
+
+    /// *Returns* the configured time zone (eg, "Europe/Berlin"), or `None` if it can't be determined.
+    ///
+    /// Queried on each call; taken from the `TZ` environment variable (if set), otherwise derived from the target of the
+    /// `/etc/localtime` symlink, falling back to the contents of `/etc/timezone`.
+    #[cfg(feature = "timezone")]
+    pub fn timezone(&self) -> Option<OsString> {
+        use std::os::unix::ffi::OsStrExt;
+        if let Some(tz) = std::env::var_os("TZ") {
+            // ref: <https://pubs.opengroup.org/onlinepubs/9699919799/basedefs/V1_chap08.html> (TZ, with optional leading ':')
+            let tz = tz.as_bytes();
+            let tz = OsStr::from_bytes(tz.strip_prefix(b":").unwrap_or(tz));
+            if tz.as_bytes().starts_with(b"/") {
+                if let Some(zone) = timezone_from_localtime_target(std::path::Path::new(tz)) {
+                    return Some(zone);
+                }
+            } else if !tz.is_empty() {
+                return Some(tz.to_os_string());
+            }
+        }
+        std::fs::read_link("/etc/localtime")
+            .ok()
+            .and_then(|target| timezone_from_localtime_target(&target))
+            .or_else(|| {
+                read_sys_value("/etc/timezone")
+                    .filter(|zone| !zone.is_empty())
+                    .map(OsString::from)
+            })
+    }
+
+    /// *Returns* the maximum size (in bytes) of a file on the root file system, or `None` if it can't be determined.
+    ///
+    /// Derived from the file size bit width of the root file system (eg, 32 bits for FAT32 => 4 GiB - 1), capped at the
+    /// maximum file offset (ie, `i64::MAX`). Queried (via `pathconf()`) on each call.
+    pub fn max_file_size(&self) -> Option<u64> {
+        max_file_size_from_bits(unix_safe::root_file_size_bits()?)
+    }
+
+    /// *Returns* whether the kernel's cryptographically secure random number generator (CRNG) has been initialized
+    /// (ie, whether reads from `/dev/urandom` or `getrandom()` will return fully seeded random data without blocking).
+    ///
+    /// Services started early in the boot process (before enough entropy has been gathered) may use this to delay
+    /// cryptographic initialization. Queried (via a non-blocking `getrandom()`) on each call; Linux-only (`None` for
+    /// other platforms or for kernels without `getrandom()` support).
+    pub fn urandom_ready(&self) -> Option<bool> {
+        unix_safe::crng_ready()
+    }
+
+    /// *Returns* the overall CPU vulnerability mitigation state (see [`MitigationSummary`]), or `None` if the kernel
+    /// doesn't report CPU vulnerabilities.
+    ///
+    /// Rolled up from each `/sys/devices/system/cpu/vulnerabilities/*` entry on each call; Linux-only (`None` for other
+    /// platforms).
+    pub fn mitigation_summary(&self) -> Option<MitigationSummary> {
+        let states: Vec<String> = std::fs::read_dir("/sys/devices/system/cpu/vulnerabilities")
+            .ok()?
+            .filter_map(|entry| read_sys_value(entry.ok()?.path()))
+            .collect();
+        MitigationSummary::from_vulnerability_states(states.iter().map(String::as_str))
+    }
+
+    /// *Returns* the names (eg, "CAP_NET_BIND_SERVICE") of the capabilities in the effective capability set of the
+    /// current process, or `None` if they can't be determined.
+    ///
+    /// Capabilities unknown to this crate are named by number (eg, "CAP_41"). Read (from `CapEff` of
+    /// `/proc/self/status`) on each call; Linux-only (`None` for other platforms).
+    // ref: <https://man7.org/linux/man-pages/man7/capabilities.7.html>
+    pub fn capabilities(&self) -> Option<Vec<OsString>> {
+        let status = std::fs::read_to_string("/proc/self/status").ok()?;
+        Some(capability_names(parse_cap_eff(&status)?))
+    }
+
+    /// *Returns* the version of the system TPM (Trusted Platform Module) (eg, "2.0"), or `None` if no TPM is present.
+    ///
+    /// Queried (via `sysfs`) on each call; currently only supported for Linux.
+    pub fn tpm_version(&self) -> Option<OsString> {
+        // ref: <https://www.kernel.org/doc/Documentation/ABI/stable/sysfs-class-tpm>
+        let major = read_sys_value("/sys/class/tpm/tpm0/tpm_version_major");
+        match major.as_deref() {
+            Some("1") => return Some(OsString::from("1.2")),
+            Some("2") => return Some(OsString::from("2.0")),
+            _ => {}
+        }
+        // fallback (for older kernels) ~ description is similar to "TPM 2.0 Device"
+        let description = read_sys_value("/sys/class/tpm/tpm0/device/description")?;
+        description
+            .split_whitespace()
+            .find(|word| word.contains('.') && word.chars().all(|c| c.is_ascii_digit() || c == '.'))
+            .map(OsString::from)
+    }
+
+    /// *Returns* whether the hardware (aka real-time) clock is kept in UTC (`true`) or in local time (`false`), or
+    /// `None` if it can't be determined.
+    ///
+    /// Read (from `/etc/adjtime`) on each call; currently only supported for Linux.
+    pub fn hardware_clock_is_utc(&self) -> Option<bool> {
+        parse_adjtime(&std::fs::read_to_string("/etc/adjtime").ok()?)
+    }
+
+    /// *Returns* the system default file-creation mask (ie, the `UMASK` setting from `/etc/login.defs`), or `None` if
+    /// it isn't configured.
+    ///
+    /// Note: this is the configured default for new login sessions, which may differ from the active umask of the
+    /// current process.
+    ///
+    /// Read (from `/etc/login.defs`) on each call.
+    pub fn login_defs_umask(&self) -> Option<u32> {
+        parse_login_defs_umask(&std::fs::read_to_string("/etc/login.defs").ok()?)
+    }
+
+    /// *Returns* the application packaging sandbox in which the current process is running ("flatpak", "snap", or
+    /// "appimage"), or `None` if running natively.
+    ///
+    /// Determined from the environment (`SNAP` or `APPIMAGE`) and from the `/.flatpak-info` marker file on each call;
+    /// Linux-only (`None` for other platforms).
+    pub fn package_sandbox(&self) -> Option<OsString> {
+        if !cfg!(target_os = "linux") {
+            return None;
+        }
+        package_sandbox(
+            |name| std::env::var_os(name),
+            |path| std::path::Path::new(path).exists(),
+        )
+    }
+
+    /// *Returns* the active audio backend ("pipewire", "pulseaudio", "jack", or "alsa"), or `None` if no audio
+    /// server or sound card is found (eg, in a headless context).
+    ///
+    /// Determined from the audio server sockets (within `XDG_RUNTIME_DIR`, or `/dev/shm` for JACK) and the ALSA sound
+    /// card list (`/proc/asound/cards`) on each call; Linux-only (`None` for other platforms).
+    pub fn audio_backend(&self) -> Option<OsString> {
+        if !cfg!(target_os = "linux") {
+            return None;
+        }
+        let runtime_dir = std::env::var_os("XDG_RUNTIME_DIR").filter(|dir| !dir.is_empty());
+        let jack_uid = format!("_{}_", unix_safe::uid());
+        let has_jack_server = std::fs::read_dir("/dev/shm").is_ok_and(|entries| {
+            entries.filter_map(|entry| entry.ok()).any(|entry| {
+                let name = entry.file_name();
+                let name = name.to_string_lossy();
+                name.starts_with("jack_") && name.contains(&jack_uid)
+            })
+        });
+        let alsa_cards = std::fs::read_to_string("/proc/asound/cards").ok();
+        audio_backend(
+            runtime_dir.as_deref().map(std::path::Path::new),
+            has_jack_server,
+            alsa_cards.as_deref(),
+            |path| path.exists(),
+        )
+    }
+
+    /// *Returns* the normalized name of the container (eg, "docker", "podman", "lxc", or "kubernetes") or virtual
+    /// machine (eg, "kvm", "vmware", "virtualbox", or "hyper-v") environment, or `None` if running natively (or if
+    /// undetermined).
+    ///
+    /// Determined from container marker files (`/.dockerenv` and `/run/.containerenv`), the init process cgroup
+    /// (`/proc/1/cgroup`), and the DMI system vendor/product (via `sysfs`) on each call; currently only supported for
+    /// Linux.
+    #[cfg(feature = "virtualization")]
+    pub fn virtualization(&self) -> Option<OsString> {
+        let cgroup = std::fs::read_to_string("/proc/1/cgroup").ok();
+        let container = container_from_markers(
+            std::path::Path::new("/.dockerenv").exists(),
+            std::path::Path::new("/run/.containerenv").exists(),
+            cgroup.as_deref(),
+        );
+        let virtualization = container.or_else(|| {
+            ["sys_vendor", "product_name"].iter().find_map(|id| {
+                let name = read_sys_value(format!("/sys/class/dmi/id/{id}"))?;
+                crate::lib_impl::virtualization_from_vendor(&name)
+            })
+        });
+        virtualization.map(OsString::from)
+    }
+
+    /// *Returns* the normalized name of the init system (eg, "systemd", "openrc", "runit", or "sysvinit"), or `None` if
+    /// it can't be determined.
+    ///
+    /// Determined from the init process name (`/proc/1/comm`) and the presence of `/run/systemd/system` on each call;
+    /// Linux-only (`None` for other platforms).
+    // ref: <https://www.freedesktop.org/software/systemd/man/latest/sd_booted.html>
+    #[cfg(feature = "init-detect")]
+    pub fn init_system(&self) -> Option<OsString> {
+        if !cfg!(any(target_os = "linux", target_os = "android")) {
+            return None;
+        }
+        let comm = read_sys_value("/proc/1/comm").unwrap_or_default();
+        let has_systemd_marker = std::path::Path::new("/run/systemd/system").is_dir();
+        init_system_from_comm(&comm, has_systemd_marker).map(OsString::from)
+    }
+
+    /// *Returns* the machine-readable OS (distribution) identifier (the os-release `ID` field; eg, "ubuntu", "fedora",
+    /// or "arch"), or `None` if it can't be determined.
+    ///
+    /// Read from `/etc/os-release` (falling back to `/usr/lib/os-release`) on each call; Linux-only (`None` for other
+    /// platforms). Per the os-release specification, "linux" is returned for an os-release file without an `ID` field.
+    // ref: <https://www.freedesktop.org/software/systemd/man/latest/os-release.html>
+    pub fn os_release_id(&self) -> Option<OsString> {
+        let content = read_os_release()?;
+        let id = os_release_value(&content, "ID").unwrap_or_else(|| String::from("linux"));
+        Some(OsString::from(id))
+    }
+
+    /// *Returns* the identifiers of the OS (distributions) from which this OS is derived, closest first (the os-release
+    /// `ID_LIKE` field; eg, \["rhel", "fedora"\] for CentOS), or `None` if unavailable.
+    ///
+    /// Read on each call; Linux-only (`None` for other platforms). See [`os_release_id()`](PlatformInfo::os_release_id).
+    pub fn os_release_id_like(&self) -> Option<Vec<OsString>> {
+        let id_like = os_release_value(&read_os_release()?, "ID_LIKE")?;
+        Some(id_like.split_whitespace().map(OsString::from).collect())
+    }
+
+    /// *Returns* the "pretty" (ie, human-readable) OS name (the os-release `PRETTY_NAME` field; eg, "Ubuntu 22.04.3 LTS"),
+    /// falling back to [`osname()`](UNameAPI::osname) (eg, "GNU/Linux" or "Darwin") if it's unavailable.
+    ///
+    /// Read on each call; the os-release `PRETTY_NAME` is Linux-only. See [`os_release_id()`](PlatformInfo::os_release_id).
+    pub fn os_pretty_name(&self) -> OsString {
+        read_os_release()
+            .and_then(|content| os_release_value(&content, "PRETTY_NAME"))
+            .map_or_else(|| self.osname.clone(), OsString::from)
+    }
+
+    /// *Returns* `true` if running under WSL (Windows Subsystem for Linux); see [`wsl_version()`](PlatformInfo::wsl_version).
+    pub fn is_wsl(&self) -> bool {
+        self.wsl_version().is_some()
+    }
+
+    /// *Returns* the major version of WSL (Windows Subsystem for Linux) (ie, 1 or 2) under which this system is
+    /// running, or `None` if not running under WSL.
+    ///
+    /// Determined from the Microsoft marker within the kernel release (eg, "4.4.0-19041-Microsoft" for WSL1 and
+    /// "5.15.90.1-microsoft-standard-WSL2" for WSL2), falling back to `/proc/version` (queried on each call) for any
+    /// custom kernel release; Linux-only (`None` for other platforms).
+    pub fn wsl_version(&self) -> Option<u8> {
+        wsl_version_from_kernel_info(&self.release.to_string_lossy()).or_else(|| {
+            let proc_version = std::fs::read_to_string("/proc/version").ok()?;
+            wsl_version_from_kernel_info(&proc_version)
+        })
+    }
+
+    /// *Returns* the configured maximum number of tracked network connections (`nf_conntrack_max`), or `None` if the
+    /// netfilter connection tracking module isn't loaded.
+    ///
+    /// Queried (via `procfs`) on each call; Linux-only.
+    pub fn nf_conntrack_max(&self) -> Option<u32> {
+        read_sys_value("/proc/sys/net/netfilter/nf_conntrack_max")?
+            .parse()
+            .ok()
+    }
+
+    /// *Returns* the configured TCP connection tracking timeouts (in seconds), keyed by TCP state (eg, "established"
+    /// => 432000), or `None` if the netfilter connection tracking module isn't loaded.
+    ///
+    /// Queried (via `procfs`; `nf_conntrack_tcp_timeout_*`) on each call; Linux-only (`None` for other platforms).
+    pub fn conntrack_timeouts(&self) -> Option<BTreeMap<String, u32>> {
+        let prefix = "nf_conntrack_tcp_timeout_";
+        let timeouts: BTreeMap<_, _> = std::fs::read_dir("/proc/sys/net/netfilter")
+            .ok()?
+            .filter_map(|entry| entry.ok())
+            .filter_map(|entry| {
+                let name = entry.file_name();
+                let state = name.to_str()?.strip_prefix(prefix)?.to_string();
+                let timeout = read_sys_value(entry.path())?.parse().ok()?;
+                Some((state, timeout))
+            })
+            .collect();
+        if timeouts.is_empty() {
+            None
+        } else {
+            Some(timeouts)
+        }
+    }
+
+    /// *Returns* the configured socket buffer size limits (see [`SocketBufferLimits`]).
+    ///
+    /// High-throughput network services (eg, QUIC servers) commonly need larger than default buffers, so may use these
+    /// values to warn proactively. Queried (via `procfs`) on each call; Linux-only (`None` for other platforms).
+    // ref: <https://www.kernel.org/doc/Documentation/networking/ip-sysctl.txt>
+    pub fn socket_buffer_limits(&self) -> Option<SocketBufferLimits> {
+        Some(SocketBufferLimits {
+            rmem_max: read_sys_value("/proc/sys/net/core/rmem_max")?
+                .parse()
+                .ok()?,
+            wmem_max: read_sys_value("/proc/sys/net/core/wmem_max")?
+                .parse()
+                .ok()?,
+            tcp_rmem: parse_tcp_mem(&read_sys_value("/proc/sys/net/ipv4/tcp_rmem")?)?,
+            tcp_wmem: parse_tcp_mem(&read_sys_value("/proc/sys/net/ipv4/tcp_wmem")?)?,
+        })
+    }
+
+    /// *Returns* the configured maximum number of inotify watches per user (`max_user_watches`).
+    ///
+    /// File-watching tools (editors, bundlers, ...) commonly exhaust the (historically low, 8192) default for large
+    /// projects, so may use this value to warn proactively.
+    ///
+    /// Queried (via `procfs`) on each call; Linux-only (`None` for other platforms).
+    pub fn inotify_max_watches(&self) -> Option<u32> {
+        read_sys_value("/proc/sys/fs/inotify/max_user_watches")?
+            .parse()
+            .ok()
+    }
+
+    /// *Returns* the estimated maximum number of threads which the current process may create.
+    ///
+    /// Combines the system-wide thread limit (`threads-max`) with the per-user process/thread resource limit
+    /// (`RLIMIT_NPROC`; if not unlimited), reporting the lesser. Note: threads of *all* processes of the current user
+    /// count against `RLIMIT_NPROC`, so actual headroom may be lower.
+    ///
+    /// Queried (via `procfs` and `getrlimit()`) on each call; Linux-only (`None` for other platforms).
+    pub fn threads_max(&self) -> Option<u64> {
+        let threads_max = read_sys_value("/proc/sys/kernel/threads-max")?
+            .parse()
+            .ok()?;
+        Some(thread_limit(threads_max, unix_safe::nproc_limit()))
+    }
+
+    /// *Returns* the configured system-wide maximum number of concurrent asynchronous I/O requests (`aio-max-nr`).
+    ///
+    /// AIO-heavy databases (eg, MySQL/InnoDB) commonly exhaust the (low, 65536) default when running multiple
+    /// instances on a single host, so may use this value to warn proactively.
+    ///
+    /// Queried (via `procfs`) on each call; Linux-only (`None` for other platforms).
+    pub fn aio_max_nr(&self) -> Option<u64> {
+        read_sys_value("/proc/sys/fs/aio-max-nr")?.parse().ok()
+    }
+
+    /// *Returns* the configured dirty-page writeback thresholds, as `(dirty_ratio, dirty_background_ratio)` percentages
+    /// of available memory.
+    ///
+    /// Note: if a threshold is instead configured in bytes (`dirty_bytes`/`dirty_background_bytes`), the corresponding
+    /// ratio is reported as zero.
+    ///
+    /// Queried (via `procfs`) on each call; Linux-only (`None` for other platforms).
+    // ref: <https://www.kernel.org/doc/Documentation/sysctl/vm.txt>
+    pub fn dirty_ratios(&self) -> Option<(u8, u8)> {
+        let dirty_ratio = read_sys_value("/proc/sys/vm/dirty_ratio")?.parse().ok()?;
+        let dirty_background_ratio = read_sys_value("/proc/sys/vm/dirty_background_ratio")?
+            .parse()
+            .ok()?;
+        Some((dirty_ratio, dirty_background_ratio))
+    }
+
+    /// *Returns* the configured virtual memory overcommit policy (`overcommit_memory`).
+    ///
+    /// * 0 ~ heuristic overcommit (the default)
+    /// * 1 ~ always overcommit
+    /// * 2 ~ never overcommit (strict accounting; see [`overcommit_ratio()`](PlatformInfo::overcommit_ratio))
+    ///
+    /// Queried (via `procfs`) on each call; Linux-only (`None` for other platforms).
+    // ref: <https://www.kernel.org/doc/Documentation/vm/overcommit-accounting>
+    pub fn overcommit_memory(&self) -> Option<u8> {
+        read_sys_value("/proc/sys/vm/overcommit_memory")?
+            .parse()
+            .ok()
+    }
+
+    /// *Returns* the configured percentage of physical RAM which may be committed (in addition to swap) when using the
+    /// "never overcommit" policy (`overcommit_ratio`).
+    ///
+    /// Queried (via `procfs`) on each call; Linux-only (`None` for other platforms).
+    pub fn overcommit_ratio(&self) -> Option<u32> {
+        read_sys_value("/proc/sys/vm/overcommit_ratio")?
+            .parse()
+            .ok()
+    }
+}
+
+//===
+
+// UnameSource
+/// Supplies the `utsname` information used to create a [`PlatformInfo`] (see
+/// [`PlatformInfo::from_source()`](PlatformInfo::from_source)).
+///
+/// Allows the `uname()` system call to be replaced (eg, by a fixture), for deterministic testing.
+pub(crate) trait UnameSource {
+    /// *Returns* a `libc::utsname` structure containing `uname`-like OS system information.
+    fn utsname(&self) -> Result<libc::utsname, std::io::Error>;
+}
+
+// SystemUname
+/// The default [`UnameSource`], using the `uname()` system call.
+pub(crate) struct SystemUname;
+
+impl UnameSource for SystemUname {
+    fn utsname(&self) -> Result<libc::utsname, std::io::Error> {
+        utsname()
+    }
+}
+
 //===
 
 // UTSName
@@ -116,17 +905,21 @@ impl Debug for UTSName {
             .field("version", &oss_from_cstr(&self.0.version))
             .field("machine", &oss_from_cstr(&self.0.machine));
         // The domainname field is not part of the POSIX standard but a GNU extension. Therefor
-        // BSD-like platforms and solaris/illumos are missing the domainname field.
+        // BSD-like platforms (including all Apple platforms), AIX, Haiku, QNX Neutrino ("nto"), VxWorks, and
+        // solaris/illumos are missing the domainname field (see `libc::utsname`).
+        // * note: keep this target list in sync with the other `domainname` cfg gates (in this file)
         #[cfg(not(any(
             target_os = "aix",
             target_os = "illumos",
             target_os = "solaris",
-            target_os = "macos",
+            target_vendor = "apple",
             target_os = "dragonfly",
             target_os = "freebsd",
             target_os = "openbsd",
             target_os = "netbsd",
-            target_os = "haiku"
+            target_os = "haiku",
+            target_os = "nto",
+            target_os = "vxworks"
         )))]
         {
             debug_struct = debug_struct.field("domainname", &oss_from_cstr(&self.0.domainname));
@@ -153,17 +946,21 @@ impl PartialEq for UTSName {
                 other.0.machine,
             );
         // The domainname field is not part of the POSIX standard but a GNU extension. Therefor
-        // BSD-like platforms and solaris/illumos are missing the domainname field.
+        // BSD-like platforms (including all Apple platforms), AIX, Haiku, QNX Neutrino ("nto"), VxWorks, and
+        // solaris/illumos are missing the domainname field (see `libc::utsname`).
+        // * note: keep this target list in sync with the other `domainname` cfg gates (in this file)
         #[cfg(not(any(
             target_os = "aix",
             target_os = "illumos",
             target_os = "solaris",
-            target_os = "macos",
+            target_vendor = "apple",
             target_os = "dragonfly",
             target_os = "freebsd",
             target_os = "openbsd",
             target_os = "netbsd",
-            target_os = "haiku"
+            target_os = "haiku",
+            target_os = "nto",
+            target_os = "vxworks"
         )))]
         {
             equal = equal && (self.0.domainname == other.0.domainname);
@@ -174,6 +971,627 @@ impl PartialEq for UTSName {
 
 impl Eq for UTSName {}
 
+impl UTSName {
+    /// *Returns* the name of the OS kernel (`utsname.sysname`; eg, "Linux").
+    pub fn sysname(&self) -> OsString {
+        oss_from_cstr(&self.0.sysname)
+    }
+
+    /// *Returns* the network node name of the machine (`utsname.nodename`).
+    pub fn nodename(&self) -> OsString {
+        oss_from_cstr(&self.0.nodename)
+    }
+
+    /// *Returns* the OS kernel release (`utsname.release`; eg, "6.5.0-21-generic").
+    pub fn release(&self) -> OsString {
+        oss_from_cstr(&self.0.release)
+    }
+
+    /// *Returns* the OS kernel version (`utsname.version`).
+    pub fn version(&self) -> OsString {
+        oss_from_cstr(&self.0.version)
+    }
+
+    /// *Returns* the name of the machine hardware (`utsname.machine`; eg, "x86_64").
+    pub fn machine(&self) -> OsString {
+        oss_from_cstr(&self.0.machine)
+    }
+
+    /// *Returns* the NIS (aka YP) domain name of the machine (`utsname.domainname`; "(none)" if unset, for Linux).
+    ///
+    /// Not available for platforms missing the (non-POSIX, GNU extension) `domainname` field (ie, BSD-like platforms
+    /// [including all Apple platforms], AIX, Haiku, QNX Neutrino, VxWorks, and illumos/Solaris).
+    #[cfg(not(any(
+        target_os = "aix",
+        target_os = "illumos",
+        target_os = "solaris",
+        target_vendor = "apple",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "openbsd",
+        target_os = "netbsd",
+        target_os = "haiku",
+        target_os = "nto",
+        target_os = "vxworks"
+    )))]
+    pub fn domainname(&self) -> OsString {
+        oss_from_cstr(&self.0.domainname)
+    }
+}
+
+//===
+
+// read_sys_value
+/// *Returns* the (whitespace trimmed) contents of a small `procfs`/`sysfs` pseudo-file (`path`), or `None` if it's not readable.
+fn read_sys_value<P: AsRef<std::path::Path>>(path: P) -> Option<String> {
+    std::fs::read_to_string(path)
+        .ok()
+        .map(|s| s.trim().to_string())
+}
+
+// chassis_type
+/// *Returns* the system form factor, as determined by the DMI (aka SMBIOS) information exposed via `sysfs`.
+#[cfg(not(target_os = "macos"))]
+fn chassis_type() -> Option<ChassisType> {
+    // ref: <https://www.kernel.org/doc/Documentation/ABI/testing/sysfs-class-dmi-id>
+    let is_virtual_machine = ["sys_vendor", "product_name"].iter().any(|id| {
+        read_sys_value(format!("/sys/class/dmi/id/{id}"))
+            .is_some_and(|name| crate::lib_impl::is_virtual_machine_vendor(&name))
+    });
+    if is_virtual_machine {
+        return Some(ChassisType::VirtualMachine);
+    }
+    let code = read_sys_value("/sys/class/dmi/id/chassis_type")?
+        .parse()
+        .ok()?;
+    ChassisType::from_smbios_code(code)
+}
+
+// chassis_type
+/// *Returns* the system form factor, as inferred from the model identifier (eg, "MacBookPro18,1" or "Macmini9,1").
+#[cfg(target_os = "macos")]
+fn chassis_type() -> Option<ChassisType> {
+    let model = unix_safe::sysctl_string("hw.model")?;
+    chassis_type_from_mac_model(&model.to_string_lossy())
+}
+
+// chassis_type_from_mac_model
+/// *Returns* the system form factor inferred from a MacOS model identifier (`model`).
+///
+/// Note: newer generic identifiers (eg, "Mac14,2") don't identify the form factor and so yield `None`.
+#[allow(dead_code)] // * fn is used only by MacOS (and test(s))
+fn chassis_type_from_mac_model(model: &str) -> Option<ChassisType> {
+    if crate::lib_impl::is_virtual_machine_vendor(model) || model.starts_with("VirtualMac") {
+        Some(ChassisType::VirtualMachine)
+    } else if model.starts_with("MacBook") || model.starts_with("PowerBook") {
+        Some(ChassisType::Laptop)
+    } else if ["iMac", "Macmini", "MacPro", "PowerMac"]
+        .iter()
+        .any(|prefix| model.starts_with(prefix))
+    {
+        Some(ChassisType::Desktop)
+    } else if model.starts_with("Xserve") {
+        Some(ChassisType::Server)
+    } else {
+        None
+    }
+}
+
+// kernel_isa
+/// *Returns* the native instruction set architecture of the kernel (eg, "amd64" or "sparcv9"), as reported by
+/// `sysinfo(SI_ARCHITECTURE_K)`.
+#[cfg(any(target_os = "illumos", target_os = "solaris"))]
+pub(crate) fn kernel_isa() -> Option<OsString> {
+    unix_safe::sysinfo_string(libc::SI_ARCHITECTURE_K)
+}
+
+// bitness_from_machine
+/// *Returns* the OS bitness (ie, 32 or 64) implied by a `uname` machine name (`machine`), or `None` for an
+/// unrecognized machine name.
+///
+/// Note: illumos/Solaris hardware platform names (eg, "i86pc" or "sun4v") imply 64 bits, as current illumos and
+/// Solaris (11+) kernels are 64-bit only.
+fn bitness_from_machine(machine: &str) -> Option<u8> {
+    if crate::lib_impl::is_platform_machine_name(machine) {
+        return Some(64);
+    }
+    match crate::lib_impl::rust_arch_from_machine(machine) {
+        "x86_64" | "aarch64" | "powerpc64" | "riscv64" | "mips64" | "s390x" | "sparc64"
+        | "loongarch64" => Some(64),
+        "unknown" => match machine.to_ascii_lowercase().as_str() {
+            // * 64-bit architectures without a Rust equivalent
+            "alpha" | "ia64" | "parisc64" => Some(64),
+            _ => None,
+        },
+        _ => Some(32),
+    }
+}
+
+// cstr_field_is_full
+/// *Returns* `true` if the NUL-terminated content of the fixed-size `field` fills the entire field (ie, the only NUL is
+/// the final byte), or if `field` is missing a NUL terminator altogether.
+fn cstr_field_is_full(field: &[libc::c_char]) -> bool {
+    let content = &field[..field.len().saturating_sub(1)]; // * all but the final (terminating) byte
+    !content.contains(&0 /* NUL */)
+}
+
+// isa_from_cpuinfo
+/// *Returns* the RISC-V ISA string (eg, "rv64imafdc_zicsr_zifencei") from the first `isa` entry of `/proc/cpuinfo`
+/// content (`content`), or `None` if there is no (non-empty) `isa` entry.
+// ref: <https://www.kernel.org/doc/html/latest/arch/riscv/uabi.html>
+#[allow(dead_code)] // * fn is unused for non-RISC-V targets
+fn isa_from_cpuinfo(content: &str) -> Option<&str> {
+    content.lines().find_map(|line| {
+        let (key, value) = line.split_once(':')?;
+        let value = value.trim();
+        (key.trim() == "isa" && !value.is_empty()).then_some(value)
+    })
+}
+
+// version_from_proc_version
+/// *Returns* the kernel version (eg, "#1 SMP PREEMPT_DYNAMIC Debian 6.1.55-1 (2023-09-29)") parsed from `/proc/version`
+/// content (`content`), or `None` if it's not in the expected format.
+///
+/// `/proc/version` has the form "Linux version <release> (<user>@<host>) (<compiler>) <version>", where the
+/// parenthesized groups may themselves contain (nested) parentheses.
+// ref: <https://man7.org/linux/man-pages/man5/proc_version.5.html>
+#[cfg(feature = "proc-version")]
+#[allow(dead_code)] // * fn is unused on non-Linux platforms
+fn version_from_proc_version(content: &str) -> Option<&str> {
+    let rest = content.trim().strip_prefix("Linux version ")?;
+    let mut rest = rest.trim_start().split_once(' ')?.1.trim_start(); // * skip the release
+    while rest.starts_with('(') {
+        let mut depth = 0_usize;
+        let end = rest.char_indices().find_map(|(i, c)| {
+            match c {
+                '(' => depth += 1,
+                ')' => depth -= 1,
+                _ => {}
+            }
+            if depth == 0 {
+                Some(i)
+            } else {
+                None
+            }
+        })?;
+        rest = rest[end + 1..].trim_start();
+    }
+    if rest.is_empty() {
+        None
+    } else {
+        Some(rest)
+    }
+}
+
+// hid_report_descriptor_is_fido
+/// *Returns* whether a raw HID report `descriptor` declares the FIDO Alliance usage page (0xF1D0).
+// ref: [HID v1.11 ~ 6.2.2 Report Descriptor](https://www.usb.org/sites/default/files/hid1_11.pdf)
+// ref: [FIDO CTAP v2.1 ~ 11.2.8.1 HID Report Descriptor and Device Discovery](https://fidoalliance.org/specs/fido-v2.1-ps-20210615/fido-client-to-authenticator-protocol-v2.1-ps-20210615.html#usb-discovery)
+fn hid_report_descriptor_is_fido(descriptor: &[u8]) -> bool {
+    let mut offset = 0;
+    while let Some(&prefix) = descriptor.get(offset) {
+        if prefix == 0xfe {
+            // long item ~ prefix, data size, long item tag, data
+            let size = descriptor
+                .get(offset + 1)
+                .map_or(0, |&size| usize::from(size));
+            offset += 3 + size;
+            continue;
+        }
+        // short item ~ prefix (tag, type, and size code), data (0, 1, 2, or 4 bytes; little-endian)
+        let size = match prefix & 0x03 {
+            3 => 4,
+            size => usize::from(size),
+        };
+        let data = match descriptor.get(offset + 1..offset + 1 + size) {
+            Some(data) => data,
+            None => break,
+        };
+        // "Usage Page" global item (tag == 0, type == 1)
+        if prefix & 0xfc == 0x04 {
+            let usage_page = data
+                .iter()
+                .rev()
+                .fold(0u32, |value, &b| (value << 8) | u32::from(b));
+            if usage_page == 0xf1d0 {
+                return true;
+            }
+        }
+        offset += 1 + size;
+    }
+    false
+}
+
+// max_file_size_from_bits
+/// *Returns* the maximum file size (in bytes) representable with the file system file size bit width (`bits`).
+fn max_file_size_from_bits(bits: u32) -> Option<u64> {
+    match bits {
+        0 => None,
+        // * file offsets (`off_t`) are signed, so sizes are limited to `i64::MAX`, whatever the file system limits
+        1..=62 => Some((1u64 << bits) - 1),
+        _ => Some(i64::MAX as u64),
+    }
+}
+
+// audio_backend
+/// *Returns* the audio backend identified by the audio server sockets (within `runtime_dir`; checked with `exists`),
+/// JACK server presence (`has_jack_server`), and ALSA sound card list (`alsa_cards`; from `/proc/asound/cards`).
+fn audio_backend<F>(
+    runtime_dir: Option<&std::path::Path>,
+    has_jack_server: bool,
+    alsa_cards: Option<&str>,
+    exists: F,
+) -> Option<OsString>
+where
+    F: Fn(&std::path::Path) -> bool,
+{
+    // * PipeWire also provides a PulseAudio-compatible socket (via `pipewire-pulse`), so must be checked first
+    let has_socket = |name| runtime_dir.is_some_and(|dir| exists(&dir.join(name)));
+    let backend = if has_socket("pipewire-0") {
+        "pipewire"
+    } else if has_socket("pulse/native") {
+        "pulseaudio"
+    } else if has_jack_server {
+        "jack"
+    } else if alsa_cards
+        .is_some_and(|cards| !cards.trim().is_empty() && !cards.contains("no soundcards"))
+    {
+        "alsa"
+    } else {
+        return None;
+    };
+    Some(OsString::from(backend))
+}
+
+// CAPABILITY_NAMES
+/// Linux capability names, indexed by capability number.
+// ref: <https://github.com/torvalds/linux/blob/master/include/uapi/linux/capability.h>
+const CAPABILITY_NAMES: [&str; 41] = [
+    "CAP_CHOWN",
+    "CAP_DAC_OVERRIDE",
+    "CAP_DAC_READ_SEARCH",
+    "CAP_FOWNER",
+    "CAP_FSETID",
+    "CAP_KILL",
+    "CAP_SETGID",
+    "CAP_SETUID",
+    "CAP_SETPCAP",
+    "CAP_LINUX_IMMUTABLE",
+    "CAP_NET_BIND_SERVICE",
+    "CAP_NET_BROADCAST",
+    "CAP_NET_ADMIN",
+    "CAP_NET_RAW",
+    "CAP_IPC_LOCK",
+    "CAP_IPC_OWNER",
+    "CAP_SYS_MODULE",
+    "CAP_SYS_RAWIO",
+    "CAP_SYS_CHROOT",
+    "CAP_SYS_PTRACE",
+    "CAP_SYS_PACCT",
+    "CAP_SYS_ADMIN",
+    "CAP_SYS_BOOT",
+    "CAP_SYS_NICE",
+    "CAP_SYS_RESOURCE",
+    "CAP_SYS_TIME",
+    "CAP_SYS_TTY_CONFIG",
+    "CAP_MKNOD",
+    "CAP_LEASE",
+    "CAP_AUDIT_WRITE",
+    "CAP_AUDIT_CONTROL",
+    "CAP_SETFCAP",
+    "CAP_MAC_OVERRIDE",
+    "CAP_MAC_ADMIN",
+    "CAP_SYSLOG",
+    "CAP_WAKE_ALARM",
+    "CAP_BLOCK_SUSPEND",
+    "CAP_AUDIT_READ",
+    "CAP_PERFMON",
+    "CAP_BPF",
+    "CAP_CHECKPOINT_RESTORE",
+];
+
+// parse_cap_eff
+/// *Returns* the effective capability set bitmask, parsed from the `CapEff` line of `/proc/<pid>/status` content
+/// (`status`; eg, "CapEff:\t00000000a80425fb").
+fn parse_cap_eff(status: &str) -> Option<u64> {
+    status.lines().find_map(|line| {
+        let mask = line.strip_prefix("CapEff:")?;
+        u64::from_str_radix(mask.trim(), 16).ok()
+    })
+}
+
+// capability_names
+/// *Returns* the names of the capabilities contained in the capability set bitmask `mask`, in capability number order.
+fn capability_names(mask: u64) -> Vec<OsString> {
+    (0..64)
+        .filter(|bit| mask & (1 << bit) != 0)
+        .map(|bit| match CAPABILITY_NAMES.get(bit) {
+            Some(name) => OsString::from(name),
+            None => OsString::from(format!("CAP_{bit}")),
+        })
+        .collect()
+}
+
+// parse_tcp_mem
+/// *Returns* the `[minimum, default, maximum]` buffer sizes parsed from a `tcp_rmem`/`tcp_wmem` value (`value`; eg,
+/// "4096\t131072\t6291456").
+fn parse_tcp_mem(value: &str) -> Option<[u64; 3]> {
+    let mut sizes = value.split_whitespace().map(|size| size.parse().ok());
+    let parsed = [sizes.next()??, sizes.next()??, sizes.next()??];
+    if sizes.next().is_some() {
+        return None;
+    }
+    Some(parsed)
+}
+
+// parse_proc_uptime
+/// *Returns* the system uptime parsed from `/proc/uptime` content (`content`; eg, "350735.47 234388.90", as the uptime
+/// and the aggregate idle time, in seconds).
+#[cfg(feature = "uptime")]
+fn parse_proc_uptime(content: &str) -> Option<std::time::Duration> {
+    let uptime: f64 = content.split_whitespace().next()?.parse().ok()?;
+    if !uptime.is_finite() || uptime < 0.0 {
+        return None;
+    }
+    Some(std::time::Duration::from_secs_f64(uptime))
+}
+
+// timezone_from_localtime_target
+/// *Returns* the time zone name (eg, "Europe/Berlin") implied by the target (`target`) of a `/etc/localtime` symlink (eg,
+/// "/usr/share/zoneinfo/Europe/Berlin"), or `None` if the target isn't within a `zoneinfo` directory.
+#[cfg(feature = "timezone")]
+fn timezone_from_localtime_target(target: &std::path::Path) -> Option<OsString> {
+    use std::os::unix::ffi::OsStrExt;
+    let components: Vec<_> = target.components().collect();
+    let index = components
+        .iter()
+        .rposition(|c| c.as_os_str().as_bytes().starts_with(b"zoneinfo"))?;
+    let zone: std::path::PathBuf = components[index + 1..].iter().collect();
+    // "posix/" and "right/" are alternate (leap second handling) trees of the same zone names
+    let zone = zone
+        .strip_prefix("posix")
+        .or_else(|_| zone.strip_prefix("right"))
+        .unwrap_or(&zone);
+    if zone.as_os_str().is_empty() {
+        None
+    } else {
+        Some(zone.as_os_str().to_os_string())
+    }
+}
+
+// wsl_version_from_kernel_info
+/// *Returns* the WSL (Windows Subsystem for Linux) major version implied by a kernel release or `/proc/version`
+/// string (`info`), or `None` if it contains no Microsoft marker.
+fn wsl_version_from_kernel_info(info: &str) -> Option<u8> {
+    let info = info.to_ascii_lowercase();
+    if info.contains("microsoft-standard") || info.contains("wsl2") {
+        Some(2)
+    } else if info.contains("microsoft") {
+        Some(1)
+    } else {
+        None
+    }
+}
+
+// thread_limit
+/// *Returns* the effective per-process thread limit, given the system-wide thread limit (`threads_max`) and the soft
+/// `RLIMIT_NPROC` resource limit (`nproc_limit`; `None` if unlimited).
+fn thread_limit(threads_max: u64, nproc_limit: Option<u64>) -> u64 {
+    nproc_limit.map_or(threads_max, |limit| limit.min(threads_max))
+}
+
+// container_from_markers
+/// *Returns* the normalized container name identified by the presence of the Docker (`has_dockerenv`) and Podman
+/// (`has_containerenv`) marker files, or by the init process `cgroup` contents.
+#[cfg(feature = "virtualization")]
+fn container_from_markers(
+    has_dockerenv: bool,
+    has_containerenv: bool,
+    cgroup: Option<&str>,
+) -> Option<&'static str> {
+    if has_dockerenv {
+        return Some("docker");
+    }
+    if has_containerenv {
+        return Some("podman");
+    }
+    // * cgroup lines are "<hierarchy-ID>:<controllers>:<path>" (eg, "0::/kubepods/besteffort/pod1234/...")
+    let cgroup = cgroup?;
+    [
+        ("kubepods", "kubernetes"),
+        ("docker", "docker"),
+        ("libpod", "podman"),
+        ("lxc", "lxc"),
+        ("containerd", "containerd"),
+    ]
+    .iter()
+    .find(|(marker, _)| cgroup.lines().any(|line| line.contains(marker)))
+    .map(|(_, container)| *container)
+}
+
+// userland_machine
+/// *Returns* the 32-bit machine name corresponding to a 64-bit kernel machine name (`kernel_machine`; eg, "x86_64")
+/// for a process compiled for the 32-bit (Rust) target architecture `target_arch` (eg, "x86"), or `None` if the kernel
+/// machine matches the target architecture (or has no known 32-bit counterpart).
+// ref: <https://man7.org/linux/man-pages/man8/setarch.8.html>
+fn userland_machine(kernel_machine: &str, target_arch: &str) -> Option<&'static str> {
+    match (kernel_machine, target_arch) {
+        ("x86_64" | "amd64", "x86") => Some("i686"),
+        ("aarch64" | "arm64", "arm") => Some("armv8l"),
+        ("ppc64" | "ppc64le", "powerpc") => Some("ppc"),
+        ("sparc64", "sparc") => Some("sparc"),
+        ("mips64", "mips") => Some("mips"),
+        _ => None,
+    }
+}
+
+// read_os_release
+/// *Returns* the contents of the os-release file (`/etc/os-release`, falling back to `/usr/lib/os-release`), or `None`
+/// if it's not readable (or for non-Linux platforms).
+fn read_os_release() -> Option<String> {
+    if !cfg!(any(target_os = "linux", target_os = "android")) {
+        return None;
+    }
+    ["/etc/os-release", "/usr/lib/os-release"]
+        .iter()
+        .find_map(|path| std::fs::read_to_string(path).ok())
+}
+
+// os_release_value
+/// *Returns* the (unquoted and unescaped) value of the `key` variable assignment within os-release content
+/// (`content`), or `None` if missing or empty.
+///
+/// Values may be unquoted, or enclosed in double or single quotes; within double quotes, `\`-escaped `"`, `\`, `$`, and
+/// `` ` `` characters are unescaped.
+fn os_release_value(content: &str, key: &str) -> Option<String> {
+    let value = content.lines().find_map(|line| {
+        let (name, value) = line.trim().split_once('=')?;
+        (name.trim_end() == key).then_some(value.trim())
+    })?;
+    let value = if let Some(quoted) = value
+        .strip_prefix('"')
+        .and_then(|value| value.strip_suffix('"'))
+    {
+        let mut unescaped = String::with_capacity(quoted.len());
+        let mut chars = quoted.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' => unescaped.extend(chars.next()),
+                _ => unescaped.push(c),
+            }
+        }
+        unescaped
+    } else if let Some(quoted) = value
+        .strip_prefix('\'')
+        .and_then(|value| value.strip_suffix('\''))
+    {
+        quoted.to_string()
+    } else {
+        value.to_string()
+    };
+    if value.is_empty() {
+        None
+    } else {
+        Some(value)
+    }
+}
+
+// init_system_from_comm
+/// *Returns* the normalized init system name identified by the init process name (`comm`; eg, "systemd" or "init") and
+/// the presence of the systemd runtime marker directory (`has_systemd_marker`), or `None` if unrecognized.
+#[cfg(feature = "init-detect")]
+fn init_system_from_comm(comm: &str, has_systemd_marker: bool) -> Option<&'static str> {
+    if has_systemd_marker {
+        return Some("systemd");
+    }
+    match comm.trim() {
+        "systemd" => Some("systemd"),
+        "openrc-init" => Some("openrc"),
+        "runit" | "runit-init" => Some("runit"),
+        "s6-svscan" => Some("s6"),
+        "dinit" => Some("dinit"),
+        "shepherd" => Some("shepherd"),
+        // * generic "init" (SysVinit, possibly running OpenRC services, or BusyBox `init`)
+        "init" => Some("sysvinit"),
+        // * minimal container init processes
+        "tini" | "docker-init" => Some("tini"),
+        "dumb-init" => Some("dumb-init"),
+        _ => None,
+    }
+}
+
+// package_sandbox
+/// *Returns* the application packaging sandbox identified by the environment (`env_var`) and file system (`exists`)
+/// markers.
+fn package_sandbox<E, F>(env_var: E, exists: F) -> Option<OsString>
+where
+    E: Fn(&str) -> Option<OsString>,
+    F: Fn(&str) -> bool,
+{
+    // ref: <https://docs.flatpak.org/en/latest/flatpak-command-reference.html#flatpak-metadata>
+    // ref: <https://snapcraft.io/docs/environment-variables>
+    // ref: <https://docs.appimage.org/packaging-guide/environment-variables.html>
+    let is_set = |name| env_var(name).is_some_and(|value| !value.is_empty());
+    let sandbox = if exists("/.flatpak-info") {
+        "flatpak"
+    } else if is_set("SNAP") {
+        "snap"
+    } else if is_set("APPIMAGE") {
+        "appimage"
+    } else {
+        return None;
+    };
+    Some(OsString::from(sandbox))
+}
+
+// parse_adjtime
+/// *Returns* the hardware clock mode (`true` => UTC; `false` => local time) parsed from `/etc/adjtime` file `content`.
+fn parse_adjtime(content: &str) -> Option<bool> {
+    // ref: <https://man7.org/linux/man-pages/man5/adjtime_config.5.html>
+    // * third line is "UTC" or "LOCAL" (a missing line implies "UTC")
+    match content.lines().nth(2).map(str::trim) {
+        Some("UTC") | None => Some(true),
+        Some("LOCAL") => Some(false),
+        Some(_) => None,
+    }
+}
+
+// parse_login_defs_umask
+/// *Returns* the (octal) `UMASK` setting parsed from `/etc/login.defs` file `content`.
+fn parse_login_defs_umask(content: &str) -> Option<u32> {
+    // ref: <https://man7.org/linux/man-pages/man5/login.defs.5.html>
+    // * lines are "NAME VALUE" pairs; blank lines and "#" comments are ignored; the last setting wins
+    content.lines().rev().find_map(|line| {
+        let mut words = line.split_whitespace();
+        match (words.next(), words.next()) {
+            (Some("UMASK"), Some(value)) => u32::from_str_radix(value, 8).ok(),
+            _ => None,
+        }
+    })
+}
+
+// parse_kernel_version
+/// *Returns* the `(major, minor, patch, suffix)` kernel version parsed from a `release` string.
+///
+/// Any numeric components beyond `patch` are kept within the suffix (eg, "5.15.90.1-microsoft-standard-WSL2" =>
+/// `(5, 15, 90, Some("1-microsoft-standard-WSL2"))`).
+fn parse_kernel_version(release: &OsStr) -> Option<(u32, u32, u32, Option<OsString>)> {
+    use std::os::unix::ffi::OsStrExt;
+
+    let bytes = release.as_bytes();
+    // * numeric portion ends at the first non-numeric character or at the third '.' (ie, after the patch component)
+    let mut dots = 0;
+    let numeric_len = bytes
+        .iter()
+        .position(|&b| match b {
+            b'.' => {
+                dots += 1;
+                dots > 2
+            }
+            _ => !b.is_ascii_digit(),
+        })
+        .unwrap_or(bytes.len());
+    // * numeric portion is always ASCII, so is valid UTF-8
+    let numeric = std::str::from_utf8(&bytes[..numeric_len]).ok()?;
+    let mut components = numeric.split('.').map(|c| c.parse::<u32>().ok());
+
+    let major = components.next()??;
+    let minor = components.next().flatten().unwrap_or(0);
+    let patch = components.next().flatten().unwrap_or(0);
+
+    let mut suffix = &bytes[numeric_len..];
+    if let Some((b'-' | b'+' | b'_' | b'~' | b'.', rest)) = suffix.split_first() {
+        suffix = rest;
+    }
+    let suffix = if suffix.is_empty() {
+        None
+    } else {
+        Some(OsStr::from_bytes(suffix).to_os_string())
+    };
+
+    Some((major, minor, patch, suffix))
+}
+
 //===
 
 //#region unsafe code
@@ -186,12 +1604,18 @@ mod unix_safe {
 
     // oss_from_str()
     /// *Returns* an `OsString` created from a `libc::c_char` slice.
+    ///
+    /// Any trailing whitespace (which some kernels use to pad `utsname` fields) is trimmed; internal content is never
+    /// modified.
     pub fn oss_from_cstr(slice: &[libc::c_char]) -> OsString {
         assert!(slice.len() < usize::try_from(isize::MAX).unwrap());
         assert!(slice.iter().position(|&c| c == 0 /* NUL */).unwrap() < slice.len());
-        OsString::from(OsStr::from_bytes(
-            unsafe { CStr::from_ptr(slice.as_ptr()) }.to_bytes(),
-        ))
+        let bytes = unsafe { CStr::from_ptr(slice.as_ptr()) }.to_bytes(); // * content up to (not including) the first NUL
+        let trimmed_len = bytes
+            .iter()
+            .rposition(|&b| !b.is_ascii_whitespace())
+            .map_or(0, |i| i + 1);
+        OsString::from(OsStr::from_bytes(&bytes[..trimmed_len]))
     }
 
     // utsname()
@@ -208,6 +1632,303 @@ mod unix_safe {
             Err(io::Error::last_os_error())
         }
     }
+
+    // boot_time()
+    /// *Returns* the time of the last system boot (`kern.boottime`), or `None` if it's not available.
+    #[cfg(all(
+        feature = "uptime",
+        any(
+            target_vendor = "apple",
+            target_os = "freebsd",
+            target_os = "dragonfly",
+            target_os = "netbsd",
+            target_os = "openbsd",
+        )
+    ))]
+    pub fn boot_time() -> Option<std::time::SystemTime> {
+        // ref: <https://man.freebsd.org/cgi/man.cgi?query=sysctl&sektion=3>
+        let mut mib = [libc::CTL_KERN, libc::KERN_BOOTTIME];
+        let mut boot_time: libc::timeval = unsafe { std::mem::zeroed() };
+        let mut size = std::mem::size_of::<libc::timeval>();
+        let result = unsafe {
+            libc::sysctl(
+                mib.as_mut_ptr(),
+                mib.len() as libc::c_uint,
+                &mut boot_time as *mut libc::timeval as *mut libc::c_void,
+                &mut size,
+                std::ptr::null_mut(),
+                0,
+            )
+        };
+        if result != 0 || boot_time.tv_sec <= 0 {
+            return None;
+        }
+        let since_epoch = std::time::Duration::new(
+            u64::try_from(boot_time.tv_sec).ok()?,
+            u32::try_from(boot_time.tv_usec).ok()? * 1000,
+        );
+        std::time::UNIX_EPOCH.checked_add(since_epoch)
+    }
+    #[cfg(all(
+        feature = "uptime",
+        not(any(
+            target_vendor = "apple",
+            target_os = "freebsd",
+            target_os = "dragonfly",
+            target_os = "netbsd",
+            target_os = "openbsd",
+        ))
+    ))]
+    pub fn boot_time() -> Option<std::time::SystemTime> {
+        None
+    }
+
+    // page_size()
+    /// *Returns* the memory page size (`_SC_PAGESIZE`), or `None` if it's not available.
+    pub fn page_size() -> Option<usize> {
+        // ref: <https://pubs.opengroup.org/onlinepubs/9699919799/functions/sysconf.html>
+        let result = unsafe { libc::sysconf(libc::_SC_PAGESIZE) };
+        usize::try_from(result).ok().filter(|&size| size > 0)
+    }
+
+    // root_file_size_bits()
+    /// *Returns* the minimum number of bits needed to represent the maximum size of a regular file on the root file
+    /// system (`_PC_FILESIZEBITS`), or `None` if it's not available.
+    #[cfg(any(
+        target_os = "linux",
+        target_vendor = "apple",
+        target_os = "freebsd",
+        target_os = "dragonfly",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "haiku",
+        target_os = "illumos",
+        target_os = "solaris",
+    ))]
+    pub fn root_file_size_bits() -> Option<u32> {
+        // ref: <https://pubs.opengroup.org/onlinepubs/9699919799/functions/pathconf.html>
+        let root = b"/\0";
+        let result =
+            unsafe { libc::pathconf(root.as_ptr() as *const libc::c_char, libc::_PC_FILESIZEBITS) };
+        u32::try_from(result).ok()
+    }
+    #[cfg(not(any(
+        target_os = "linux",
+        target_vendor = "apple",
+        target_os = "freebsd",
+        target_os = "dragonfly",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "haiku",
+        target_os = "illumos",
+        target_os = "solaris",
+    )))]
+    pub fn root_file_size_bits() -> Option<u32> {
+        None
+    }
+
+    // nproc_limit()
+    /// *Returns* the soft `RLIMIT_NPROC` resource limit (ie, the maximum number of processes/threads for the real user
+    /// ID), or `None` if unlimited (or not available).
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    pub fn nproc_limit() -> Option<u64> {
+        // ref: <https://man7.org/linux/man-pages/man2/getrlimit.2.html>
+        let mut limit: libc::rlimit = unsafe { std::mem::zeroed() };
+        let result = unsafe { libc::getrlimit(libc::RLIMIT_NPROC, &mut limit) };
+        if result != 0 || limit.rlim_cur == libc::RLIM_INFINITY {
+            return None;
+        }
+        #[allow(clippy::useless_conversion)] // * `rlim_t` is narrower than `u64` for some targets
+        u64::try_from(limit.rlim_cur).ok()
+    }
+    #[cfg(not(any(target_os = "linux", target_os = "android")))]
+    pub fn nproc_limit() -> Option<u64> {
+        None
+    }
+
+    // uid()
+    /// *Returns* the real user ID of the current process.
+    pub fn uid() -> libc::uid_t {
+        // ref: <https://man7.org/linux/man-pages/man2/getuid.2.html>
+        // * `getuid()` is always successful
+        unsafe { libc::getuid() }
+    }
+
+    // crng_ready()
+    /// *Returns* whether the kernel CRNG has been initialized, as determined by a non-blocking `getrandom()` call, or
+    /// `None` if `getrandom()` isn't available.
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    pub fn crng_ready() -> Option<bool> {
+        // ref: <https://man7.org/linux/man-pages/man2/getrandom.2.html>
+        // * with `GRND_NONBLOCK` (and without `GRND_RANDOM`), fails with `EAGAIN` only if the CRNG is not yet initialized
+        let mut buffer = [0u8; 1];
+        let result = unsafe {
+            libc::getrandom(
+                buffer.as_mut_ptr() as *mut libc::c_void,
+                buffer.len(),
+                libc::GRND_NONBLOCK,
+            )
+        };
+        if result >= 0 {
+            return Some(true);
+        }
+        match io::Error::last_os_error().raw_os_error() {
+            Some(libc::EAGAIN) => Some(false),
+            _ => None, // eg, `ENOSYS` (kernel < v3.17)
+        }
+    }
+    #[cfg(not(any(target_os = "linux", target_os = "android")))]
+    pub fn crng_ready() -> Option<bool> {
+        None
+    }
+
+    // canonical_name()
+    /// *Returns* the canonical (ie, fully qualified) DNS name of `node_name`, as resolved by `getaddrinfo()` (using
+    /// `AI_CANONNAME`), or `None` if it can't be resolved.
+    pub fn canonical_name(node_name: &OsStr) -> Option<OsString> {
+        // ref: <https://pubs.opengroup.org/onlinepubs/9699919799/functions/getaddrinfo.html>
+        #[cfg(not(target_os = "haiku"))]
+        use libc::AI_CANONNAME;
+        #[cfg(target_os = "haiku")]
+        const AI_CANONNAME: libc::c_int = 0x0002; // * from Haiku `<netdb.h>` (missing from `libc`)
+
+        let node_name = std::ffi::CString::new(node_name.as_bytes()).ok()?;
+        let mut hints: libc::addrinfo = unsafe { std::mem::zeroed() };
+        hints.ai_flags = AI_CANONNAME;
+        hints.ai_family = libc::AF_UNSPEC;
+        let mut info: *mut libc::addrinfo = std::ptr::null_mut();
+        let result =
+            unsafe { libc::getaddrinfo(node_name.as_ptr(), std::ptr::null(), &hints, &mut info) };
+        if result != 0 || info.is_null() {
+            return None;
+        }
+        // SAFETY: `getaddrinfo()` succeeded => `info` points to a valid `addrinfo` (valid until `freeaddrinfo()`)
+        let canonical_name = unsafe { (*info).ai_canonname };
+        let name = if canonical_name.is_null() {
+            None
+        } else {
+            let bytes = unsafe { CStr::from_ptr(canonical_name) }.to_bytes();
+            Some(OsStr::from_bytes(bytes).to_os_string())
+        };
+        unsafe { libc::freeaddrinfo(info) };
+        name
+    }
+
+    // haiku_system_info()
+    /// *Returns* a `libc::system_info` structure containing Haiku-native OS system information.
+    #[cfg(target_os = "haiku")]
+    pub fn haiku_system_info() -> Result<libc::system_info, std::io::Error> {
+        // ref: <https://www.haiku-os.org/docs/api/OS_8h.html>
+        let mut info = MaybeUninit::<libc::system_info>::uninit();
+        let result = unsafe { libc::get_system_info(info.as_mut_ptr()) };
+        if result == libc::B_OK {
+            // SAFETY: `libc::get_system_info()` succeeded => `info` was initialized
+            Ok(unsafe { info.assume_init() })
+        } else {
+            Err(io::Error::from_raw_os_error(result))
+        }
+    }
+
+    // sysinfo_string()
+    /// *Returns* the value of the `sysinfo()` string information item `command` (eg, `SI_PLATFORM`), or `None` if it's
+    /// not available.
+    #[cfg(any(target_os = "illumos", target_os = "solaris"))]
+    pub fn sysinfo_string(command: libc::c_int) -> Option<OsString> {
+        // ref: <https://illumos.org/man/2/sysinfo>
+        // * returns the buffer size required to hold the complete value (including the terminating NUL), or -1 on error
+        let mut buffer = vec![0u8; 257];
+        loop {
+            let size = libc::c_long::try_from(buffer.len()).ok()?;
+            let result =
+                unsafe { libc::sysinfo(command, buffer.as_mut_ptr() as *mut libc::c_char, size) };
+            let required = usize::try_from(result).ok()?;
+            if required <= buffer.len() {
+                break;
+            }
+            buffer.resize(required, 0);
+        }
+        let length = buffer.iter().position(|&b| b == 0).unwrap_or(buffer.len());
+        Some(OsStr::from_bytes(&buffer[..length]).to_os_string())
+    }
+
+    // system_property()
+    /// *Returns* the value of the named (`name`) Android system property, or `None` if it's not set (or empty).
+    #[cfg(target_os = "android")]
+    pub fn system_property(name: &str) -> Option<OsString> {
+        // ref: <https://android.googlesource.com/platform/bionic/+/master/libc/include/sys/system_properties.h>
+        // * returns the length of the value (excluding the terminating NUL), which is at most `PROP_VALUE_MAX - 1`
+        let name = std::ffi::CString::new(name).ok()?;
+        let mut buffer = vec![0u8; libc::PROP_VALUE_MAX as usize];
+        let result = unsafe {
+            libc::__system_property_get(name.as_ptr(), buffer.as_mut_ptr() as *mut libc::c_char)
+        };
+        let length = usize::try_from(result).ok()?.min(buffer.len());
+        if length == 0 {
+            return None;
+        }
+        Some(OsStr::from_bytes(&buffer[..length]).to_os_string())
+    }
+
+    // sysctl_string()
+    /// *Returns* the value of the named (`name`) string-type `sysctl` variable, or `None` if it's not available.
+    #[cfg(target_os = "macos")]
+    pub fn sysctl_string(name: &str) -> Option<OsString> {
+        // ref: <https://developer.apple.com/documentation/kernel/1387446-sysctlbyname>
+        let name = std::ffi::CString::new(name).ok()?;
+        let mut size: libc::size_t = 0;
+        let result = unsafe {
+            libc::sysctlbyname(
+                name.as_ptr(),
+                std::ptr::null_mut(),
+                &mut size,
+                std::ptr::null_mut(),
+                0,
+            )
+        };
+        if result != 0 || size == 0 {
+            return None;
+        }
+        let mut buffer = vec![0u8; size];
+        let result = unsafe {
+            libc::sysctlbyname(
+                name.as_ptr(),
+                buffer.as_mut_ptr() as *mut libc::c_void,
+                &mut size,
+                std::ptr::null_mut(),
+                0,
+            )
+        };
+        if result != 0 {
+            return None;
+        }
+        buffer.truncate(size);
+        // * value is NUL-terminated
+        let length = buffer.iter().position(|&b| b == 0).unwrap_or(buffer.len());
+        Some(OsStr::from_bytes(&buffer[..length]).to_os_string())
+    }
+
+    // sysctl_int()
+    /// *Returns* the value of the named (`name`) integer-type `sysctl` variable, or `None` if it's not available.
+    #[cfg(target_os = "macos")]
+    pub fn sysctl_int(name: &str) -> Option<libc::c_int> {
+        // ref: <https://developer.apple.com/documentation/kernel/1387446-sysctlbyname>
+        let name = std::ffi::CString::new(name).ok()?;
+        let mut value: libc::c_int = 0;
+        let mut size = std::mem::size_of::<libc::c_int>();
+        let result = unsafe {
+            libc::sysctlbyname(
+                name.as_ptr(),
+                &mut value as *mut libc::c_int as *mut libc::c_void,
+                &mut size,
+                std::ptr::null_mut(),
+                0,
+            )
+        };
+        if result != 0 || size != std::mem::size_of::<libc::c_int>() {
+            return None;
+        }
+        Some(value)
+    }
 }
 //#endregion (unsafe code)
 
@@ -217,7 +1938,1106 @@ mod unix_safe {
 fn test_osname() {
     let info = PlatformInfo::new().unwrap();
     let osname = info.osname().to_string_lossy();
-    assert!(osname.starts_with(crate::lib_impl::HOST_OS_NAME));
+    assert!(osname.starts_with(crate::host_os_name()));
+}
+
+#[test]
+#[cfg(any(target_os = "hurd", all(target_os = "freebsd", target_env = "gnu")))]
+fn test_gnu_osname() {
+    // * matches coreutils `uname -o`
+    let expected = if cfg!(target_os = "hurd") {
+        "GNU"
+    } else {
+        "GNU/kFreeBSD"
+    };
+    let info = PlatformInfo::new().unwrap();
+    assert_eq!(info.osname(), expected);
+}
+
+#[test]
+#[cfg(target_env = "ohos")]
+fn test_ohos() {
+    // * OpenHarmony uses this (unix) backend as a Linux target; osname is kept as "Linux" (see `HOST_OS_NAME`)
+    let info = PlatformInfo::new().unwrap();
+    println!("{:?}", info);
+    assert_eq!(info.osname(), "Linux");
+    assert_eq!(info.sysname(), "Linux");
+    assert_eq!(info.kernel_kind(), crate::KernelKind::Linux);
+    assert_eq!(crate::BuildTarget::CURRENT.env, "ohos");
+    // * OHOS's (musl-derived) `utsname` includes `domainname`
+    let _ = info.utsname.domainname();
+}
+
+#[test]
+#[cfg(target_os = "cygwin")]
+fn test_cygwin() {
+    // * Cygwin uses this (unix) backend; native `uname` values are kept as reported (eg, "CYGWIN_NT-10.0-19045")
+    let info = PlatformInfo::new().unwrap();
+    println!("{:?}", info);
+    assert_eq!(info.osname(), "Cygwin"); // matches Cygwin coreutils `uname -o`
+    assert!(info.sysname().to_string_lossy().starts_with("CYGWIN_NT-"));
+    assert_eq!(info.kernel_kind(), crate::KernelKind::WindowsNt);
+    // * Cygwin's `utsname` includes `domainname`
+    let _ = info.utsname.domainname();
+
+    let data = crate::UnameData::from_bytes(&info.to_bytes()).unwrap();
+    assert_eq!(data.sysname(), info.sysname());
+    assert_eq!(data.machine(), info.machine());
+    assert_eq!(data.osname(), info.osname());
+}
+
+#[test]
+fn test_from_source() {
+    struct FixtureUname(libc::utsname);
+    impl UnameSource for FixtureUname {
+        fn utsname(&self) -> Result<libc::utsname, std::io::Error> {
+            Ok(self.0)
+        }
+    }
+    struct FailingUname;
+    impl UnameSource for FailingUname {
+        fn utsname(&self) -> Result<libc::utsname, std::io::Error> {
+            Err(std::io::Error::from_raw_os_error(libc::EFAULT))
+        }
+    }
+    fn fill(field: &mut [libc::c_char], value: &[u8]) {
+        for (dst, src) in field.iter_mut().zip(value) {
+            *dst = *src as libc::c_char;
+        }
+    }
+
+    // SAFETY: `libc::utsname` is a plain C struct (of `c_char` arrays); all-zero is a valid (empty) value
+    let mut uts: libc::utsname = unsafe { std::mem::zeroed() };
+    fill(&mut uts.sysname, b"Linux");
+    fill(&mut uts.release, b"6.5.0-21-generic");
+    fill(&mut uts.version, b"#21-Ubuntu SMP PREEMPT_DYNAMIC");
+    fill(&mut uts.machine, b"x86_64");
+    // * a node name filling the entire field (ie, only the final NUL terminator remains) ~ truncated
+    let capacity = uts.nodename.len();
+    fill(&mut uts.nodename, &vec![b'n'; capacity - 1]);
+
+    let info = PlatformInfo::from_source(FixtureUname(uts)).unwrap();
+    println!("{:?}", info);
+    assert_eq!(info.sysname(), "Linux");
+    assert_eq!(info.release(), "6.5.0-21-generic");
+    assert_eq!(info.machine(), "x86_64");
+    assert_eq!(info.osname(), crate::host_os_name());
+    assert_eq!(info.nodename().len(), capacity - 1);
+    assert!(info.nodename_is_truncated());
+    assert_eq!(
+        info.kernel_version(),
+        Some((6, 5, 0, Some(OsString::from("21-generic"))))
+    );
+
+    // * a short node name ~ not truncated
+    uts.nodename.iter_mut().for_each(|c| *c = 0);
+    fill(&mut uts.nodename, b"host");
+    let info = PlatformInfo::from_source(FixtureUname(uts)).unwrap();
+    assert_eq!(info.nodename(), "host");
+    assert!(!info.nodename_is_truncated());
+
+    assert!(PlatformInfo::from_source(FailingUname).is_err());
+}
+
+#[test]
+#[cfg(feature = "proc-version")]
+fn test_version_from_proc_version() {
+    let info = PlatformInfo::new().unwrap();
+    println!("version={:?}", info.version());
+    assert!(!info.version().is_empty());
+
+    assert_eq!(
+        version_from_proc_version("Linux version 6.1.0-13-amd64 (debian-kernel@lists.debian.org) (gcc-12 (Debian 12.2.0-14) 12.2.0, GNU ld (GNU Binutils for Debian) 2.40) #1 SMP PREEMPT_DYNAMIC Debian 6.1.55-1 (2023-09-29)\n"),
+        Some("#1 SMP PREEMPT_DYNAMIC Debian 6.1.55-1 (2023-09-29)")
+    );
+    assert_eq!(
+        version_from_proc_version("Linux version 5.15.0-1051-azure (buildd@lcy02-amd64-032) (gcc (Ubuntu 11.4.0-1ubuntu1~22.04) 11.4.0, GNU ld (GNU Binutils for Ubuntu) 2.38) #59-Ubuntu SMP Tue Oct 10 16:56:07 UTC 2023"),
+        Some("#59-Ubuntu SMP Tue Oct 10 16:56:07 UTC 2023")
+    );
+    assert_eq!(
+        version_from_proc_version("Linux version 6.6.8-0-lts (buildozer@build-3-19-x86_64) (gcc (Alpine 13.2.1_git20231014) 13.2.1 20231014, GNU ld (GNU Binutils) 2.41) #1-Alpine SMP PREEMPT_DYNAMIC Fri, 22 Dec 2023 06:33:55 +0000"),
+        Some("#1-Alpine SMP PREEMPT_DYNAMIC Fri, 22 Dec 2023 06:33:55 +0000")
+    );
+    assert_eq!(
+        version_from_proc_version("Linux version 4.4.0-19041-Microsoft (Microsoft@Microsoft.com) (gcc version 5.4.0 (GCC) ) #1237-Microsoft Sat Sep 11 14:32:00 PST 2021"),
+        Some("#1237-Microsoft Sat Sep 11 14:32:00 PST 2021")
+    );
+    assert_eq!(version_from_proc_version(""), None);
+    assert_eq!(version_from_proc_version("Linux version 6.1.0"), None);
+    assert_eq!(
+        version_from_proc_version("Linux version 6.1.0 (user@host) (gcc"),
+        None
+    );
+    assert_eq!(version_from_proc_version("FreeBSD 14.0-RELEASE"), None);
+}
+
+#[test]
+fn test_field_capabilities() {
+    use crate::FieldCapabilities as F;
+    let info = PlatformInfo::new().unwrap();
+    let capabilities = info.field_capabilities();
+    println!("{:?}", capabilities);
+    assert!(capabilities.contains(F::SYSNAME | F::NODENAME | F::RELEASE | F::VERSION | F::MACHINE));
+    if cfg!(target_os = "linux") {
+        assert!(capabilities.contains(F::UNAME | F::DOMAINNAME));
+    }
+    if cfg!(any(target_vendor = "apple", target_os = "freebsd")) {
+        assert!(!capabilities.contains(F::DOMAINNAME));
+    }
+}
+
+#[test]
+fn test_kernel_version() {
+    let info = PlatformInfo::new().unwrap();
+    let kernel_version = info.kernel_version();
+    println!("kernel_version={kernel_version:?}");
+    assert!(kernel_version.is_some());
+
+    let parse = |s: &str| parse_kernel_version(OsStr::new(s));
+    let suffix = |s: &str| Some(OsString::from(s));
+    assert_eq!(
+        parse("6.5.0-21-generic"),
+        Some((6, 5, 0, suffix("21-generic")))
+    );
+    assert_eq!(parse("5.10.0-8-amd64"), Some((5, 10, 0, suffix("8-amd64"))));
+    assert_eq!(parse("6.1.21+"), Some((6, 1, 21, None)));
+    assert_eq!(
+        parse("6.6.31+rpt-rpi-v8"),
+        Some((6, 6, 31, suffix("rpt-rpi-v8")))
+    );
+    assert_eq!(
+        parse("5.15.90.1-microsoft-standard-WSL2"),
+        Some((5, 15, 90, suffix("1-microsoft-standard-WSL2")))
+    );
+    assert_eq!(parse("4.4.0.19041"), Some((4, 4, 0, suffix("19041"))));
+    assert_eq!(parse("13.2-RELEASE"), Some((13, 2, 0, suffix("RELEASE"))));
+    assert_eq!(parse("4.19"), Some((4, 19, 0, None)));
+    assert_eq!(parse("22.6.0"), Some((22, 6, 0, None)));
+    assert_eq!(parse("5.11"), Some((5, 11, 0, None)));
+    assert_eq!(parse("7"), Some((7, 0, 0, None)));
+    assert_eq!(parse(""), None);
+    assert_eq!(parse("unknown"), None);
+    assert_eq!(parse("-1.2"), None);
+}
+
+#[test]
+fn test_os_bitness() {
+    let info = PlatformInfo::new().unwrap();
+    let os_bitness = info.os_bitness();
+    println!("os_bitness={os_bitness:?}");
+    if cfg!(target_pointer_width = "64") {
+        assert_eq!(os_bitness, Some(64));
+    } else {
+        assert!(matches!(os_bitness, Some(32 | 64)));
+    }
+
+    for machine in [
+        "x86_64", "aarch64", "amd64", "arm64", "ppc64le", "riscv64", "s390x", "alpha", "i86pc",
+        "sun4u", "sun4v",
+    ] {
+        assert_eq!(bitness_from_machine(machine), Some(64), "{machine}");
+    }
+    for machine in ["i386", "i686", "armv7l", "armv6l", "mips", "riscv32"] {
+        assert_eq!(bitness_from_machine(machine), Some(32), "{machine}");
+    }
+    assert_eq!(bitness_from_machine(""), None);
+    assert_eq!(bitness_from_machine("unknown"), None);
+    assert_eq!(bitness_from_machine("vax"), None);
+}
+
+#[test]
+fn test_arch_triple() {
+    let info = PlatformInfo::new().unwrap();
+    let arch_triple = info.arch_triple();
+    println!("machine={:?}; arch_triple={arch_triple}", info.machine());
+    if cfg!(target_arch = "x86_64") || cfg!(target_arch = "aarch64") {
+        assert_eq!(arch_triple, std::env::consts::ARCH);
+    }
+
+    let arch = crate::lib_impl::rust_arch_from_machine;
+    assert_eq!(arch("x86_64"), "x86_64");
+    assert_eq!(arch("amd64"), "x86_64"); // FreeBSD/OpenBSD
+    assert_eq!(arch("i686"), "x86");
+    assert_eq!(arch("i86pc"), "unknown"); // illumos/Solaris hardware platform name (ie, not an ISA)
+    assert_eq!(arch("arm64"), "aarch64"); // MacOS/FreeBSD
+    assert_eq!(arch("aarch64"), "aarch64");
+    assert_eq!(arch("armv7l"), "arm");
+    assert_eq!(arch("armv6l"), "arm");
+    assert_eq!(arch("ppc64le"), "powerpc64");
+    assert_eq!(arch("riscv64"), "riscv64");
+    assert_eq!(arch("s390x"), "s390x");
+    assert_eq!(arch("sun4v"), "sparc64");
+    assert_eq!(arch(""), "unknown");
+}
+
+#[test]
+fn test_graphics_drivers() {
+    let info = PlatformInfo::new().unwrap();
+    let graphics_drivers = info.graphics_drivers();
+    println!("graphics_drivers={graphics_drivers:?}");
+    if let Some(drivers) = graphics_drivers {
+        assert!(!drivers.is_empty());
+        assert!(drivers.iter().all(|d| !d.is_empty()));
+    }
+}
+
+#[test]
+fn test_chassis_type() {
+    let info = PlatformInfo::new().unwrap();
+    let chassis_type = info.chassis_type();
+    println!("chassis_type={chassis_type:?}");
+
+    assert_eq!(
+        chassis_type_from_mac_model("MacBookPro18,1"),
+        Some(ChassisType::Laptop)
+    );
+    assert_eq!(
+        chassis_type_from_mac_model("Macmini9,1"),
+        Some(ChassisType::Desktop)
+    );
+    assert_eq!(
+        chassis_type_from_mac_model("VMware7,1"),
+        Some(ChassisType::VirtualMachine)
+    );
+    assert_eq!(chassis_type_from_mac_model("Mac14,2"), None);
+}
+
+#[test]
+#[ignore = "performs live DNS lookups (which may block)"]
+fn test_fqdn() {
+    let info = PlatformInfo::new().unwrap();
+    let fqdn = info.fqdn();
+    println!("fqdn={fqdn:?}");
+    if let Some(fqdn) = fqdn {
+        assert!(!fqdn.is_empty());
+    }
+
+    let localhost = unix_safe::canonical_name(OsStr::new("localhost"));
+    println!("localhost={localhost:?}");
+}
+
+#[test]
+fn test_canonical_name_invalid() {
+    // * rejected before any name resolution (ie, no DNS lookup)
+    assert_eq!(unix_safe::canonical_name(OsStr::new("bad\0name")), None);
+}
+
+#[test]
+fn test_nodename_is_truncated() {
+    let info = PlatformInfo::new().unwrap();
+    let truncated = info.nodename_is_truncated();
+    println!("nodename={:?}; truncated={truncated}", info.nodename());
+    let capacity = info.utsname.0.nodename.len();
+    assert_eq!(truncated, info.nodename().len() + 1 >= capacity);
+
+    let field = |content: &[u8]| {
+        let mut field = [0 as libc::c_char; 65];
+        for (c, &b) in field.iter_mut().zip(content) {
+            *c = b as libc::c_char;
+        }
+        field
+    };
+    assert!(cstr_field_is_full(&field(&[b'a'; 64])));
+    assert!(cstr_field_is_full(&field(&[b'a'; 65]))); // missing NUL terminator
+    assert!(!cstr_field_is_full(&field(&[b'a'; 63])));
+    assert!(!cstr_field_is_full(&field(b"localhost")));
+    assert!(!cstr_field_is_full(&field(b"")));
+}
+
+#[test]
+fn test_fido_authenticators() {
+    let info = PlatformInfo::new().unwrap();
+    let fido_authenticators = info.fido_authenticators();
+    println!("fido_authenticators={fido_authenticators:?}");
+    if let Some(authenticators) = fido_authenticators {
+        assert!(!authenticators.is_empty());
+        assert!(authenticators.iter().all(|a| !a.is_empty()));
+    }
+
+    // FIDO U2F/CTAPHID interface (eg, from a YubiKey)
+    let fido_descriptor = [
+        0x06, 0xd0, 0xf1, 0x09, 0x01, 0xa1, 0x01, 0x09, 0x20, 0x15, 0x00, 0x26, 0xff, 0x00, 0x75,
+        0x08, 0x95, 0x40, 0x81, 0x02, 0x09, 0x21, 0x15, 0x00, 0x26, 0xff, 0x00, 0x75, 0x08, 0x95,
+        0x40, 0x91, 0x02, 0xc0,
+    ];
+    assert!(hid_report_descriptor_is_fido(&fido_descriptor));
+    // boot keyboard (Generic Desktop usage page); includes 0xd0 0xf1 bytes as (non-"Usage Page") item data
+    let keyboard_descriptor = [
+        0x05, 0x01, 0x09, 0x06, 0xa1, 0x01, 0x05, 0x07, 0x19, 0xe0, 0x29, 0xe7, 0x16, 0xd0, 0xf1,
+        0x25, 0x01, 0x75, 0x01, 0x95, 0x08, 0x81, 0x02, 0xc0,
+    ];
+    assert!(!hid_report_descriptor_is_fido(&keyboard_descriptor));
+    assert!(!hid_report_descriptor_is_fido(&[]));
+    assert!(!hid_report_descriptor_is_fido(&[0x06, 0xd0])); // truncated
+}
+
+#[test]
+fn test_page_size() {
+    let info = PlatformInfo::new().unwrap();
+    let page_size = info.page_size();
+    println!("page_size={page_size:?}");
+    let page_size = page_size.unwrap();
+    assert!(page_size.is_power_of_two());
+    assert!(page_size >= 4096);
+}
+
+#[test]
+#[cfg(feature = "uptime")]
+fn test_uptime() {
+    let info = PlatformInfo::new().unwrap();
+    let uptime = info.uptime();
+    let boot_time = info.boot_time();
+    println!("uptime={uptime:?}");
+    println!("boot_time={boot_time:?}");
+    if cfg!(any(
+        target_os = "linux",
+        target_vendor = "apple",
+        target_os = "freebsd"
+    )) {
+        assert!(uptime > Some(std::time::Duration::ZERO));
+        assert!(boot_time < Some(std::time::SystemTime::now()));
+    }
+
+    let uptime = |secs| Some(std::time::Duration::from_secs_f64(secs));
+    assert_eq!(
+        parse_proc_uptime("350735.47 234388.90\n"),
+        uptime(350_735.47)
+    );
+    assert_eq!(parse_proc_uptime("0.00 0.00"), uptime(0.0));
+    assert_eq!(parse_proc_uptime(""), None);
+    assert_eq!(parse_proc_uptime("-1.0 0.00"), None);
+    assert_eq!(parse_proc_uptime("up 0.00"), None);
+}
+
+#[test]
+#[cfg(feature = "timezone")]
+fn test_timezone() {
+    use std::path::Path;
+
+    let info = PlatformInfo::new().unwrap();
+    println!("timezone={:?}", info.timezone());
+
+    let zone = |target: &str| timezone_from_localtime_target(Path::new(target));
+    assert_eq!(
+        zone("/usr/share/zoneinfo/Europe/Berlin"),
+        Some(OsString::from("Europe/Berlin"))
+    );
+    assert_eq!(
+        zone("../usr/share/zoneinfo/America/Argentina/Buenos_Aires"),
+        Some(OsString::from("America/Argentina/Buenos_Aires"))
+    );
+    assert_eq!(
+        zone("/var/db/timezone/zoneinfo/Asia/Tokyo"),
+        Some(OsString::from("Asia/Tokyo"))
+    );
+    assert_eq!(
+        zone("/usr/share/zoneinfo/posix/UTC"),
+        Some(OsString::from("UTC"))
+    );
+    assert_eq!(
+        zone("/usr/share/zoneinfo.default/right/Etc/UTC"),
+        Some(OsString::from("Etc/UTC"))
+    );
+    assert_eq!(zone("/usr/share/zoneinfo/"), None);
+    assert_eq!(zone("/etc/localtime"), None);
+    assert_eq!(zone(""), None);
+}
+
+#[test]
+fn test_max_file_size() {
+    let info = PlatformInfo::new().unwrap();
+    let max_file_size = info.max_file_size();
+    println!("max_file_size={max_file_size:?}");
+    if cfg!(target_os = "linux") {
+        // * any common root file system (eg, ext4, btrfs, xfs, or overlayfs) supports files of at least 4 GiB - 1
+        assert!(max_file_size >= Some((1 << 32) - 1));
+    }
+
+    assert_eq!(max_file_size_from_bits(32), Some((1 << 32) - 1)); // eg, FAT32
+    assert_eq!(max_file_size_from_bits(64), Some(i64::MAX as u64)); // eg, ext4
+    assert_eq!(max_file_size_from_bits(0), None);
+}
+
+#[test]
+fn test_urandom_ready() {
+    let info = PlatformInfo::new().unwrap();
+    let urandom_ready = info.urandom_ready();
+    println!("urandom_ready={urandom_ready:?}");
+    if cfg!(target_os = "linux") {
+        // * CRNG is initialized well before any test process is started
+        assert_eq!(urandom_ready, Some(true));
+    } else {
+        assert_eq!(urandom_ready, None);
+    }
+}
+
+#[test]
+fn test_mitigation_summary() {
+    let info = PlatformInfo::new().unwrap();
+    let mitigation_summary = info.mitigation_summary();
+    println!("mitigation_summary={mitigation_summary:?}");
+    if !cfg!(target_os = "linux") {
+        assert_eq!(mitigation_summary, None);
+    }
+}
+
+#[test]
+fn test_threads_max() {
+    let info = PlatformInfo::new().unwrap();
+    let threads_max = info.threads_max();
+    println!("threads_max={threads_max:?}");
+    if cfg!(target_os = "linux") {
+        // * the current (test harness) process is already running multiple threads
+        assert!(threads_max > Some(1));
+    } else {
+        assert_eq!(threads_max, None);
+    }
+
+    assert_eq!(thread_limit(126_309, None), 126_309);
+    assert_eq!(thread_limit(126_309, Some(4096)), 4096);
+    assert_eq!(thread_limit(4096, Some(126_309)), 4096);
+}
+
+#[test]
+fn test_capabilities() {
+    let info = PlatformInfo::new().unwrap();
+    let capabilities = info.capabilities();
+    println!("capabilities={capabilities:?}");
+    if cfg!(target_os = "linux") {
+        assert!(capabilities.is_some());
+    } else {
+        assert_eq!(capabilities, None);
+    }
+
+    // Docker default capability set
+    let status = "Name:\tsh\nCapInh:\t0000000000000000\nCapPrm:\t00000000a80425fb\nCapEff:\t00000000a80425fb\n";
+    let mask = parse_cap_eff(status);
+    assert_eq!(mask, Some(0xa804_25fb));
+    let names = capability_names(mask.unwrap());
+    let expected = [
+        "CAP_CHOWN",
+        "CAP_DAC_OVERRIDE",
+        "CAP_FOWNER",
+        "CAP_FSETID",
+        "CAP_KILL",
+        "CAP_SETGID",
+        "CAP_SETUID",
+        "CAP_SETPCAP",
+        "CAP_NET_BIND_SERVICE",
+        "CAP_NET_RAW",
+        "CAP_SYS_CHROOT",
+        "CAP_MKNOD",
+        "CAP_AUDIT_WRITE",
+        "CAP_SETFCAP",
+    ];
+    assert_eq!(
+        names,
+        expected.iter().map(OsString::from).collect::<Vec<_>>()
+    );
+
+    assert!(capability_names(0).is_empty());
+    assert_eq!(
+        capability_names(1 << 40 | 1 << 41),
+        vec![
+            OsString::from("CAP_CHECKPOINT_RESTORE"),
+            OsString::from("CAP_41")
+        ]
+    );
+    assert_eq!(parse_cap_eff("CapPrm:\t0000000000000000\n"), None);
+}
+
+#[test]
+fn test_socket_buffer_limits() {
+    let info = PlatformInfo::new().unwrap();
+    let limits = info.socket_buffer_limits();
+    println!("socket_buffer_limits={limits:?}");
+    if let Some(limits) = limits {
+        assert!(limits.tcp_rmem[0] <= limits.tcp_rmem[1]);
+        assert!(limits.tcp_wmem[0] <= limits.tcp_wmem[1]);
+    }
+    if !cfg!(target_os = "linux") {
+        assert_eq!(limits, None);
+    }
+
+    assert_eq!(
+        parse_tcp_mem("4096\t131072\t6291456"),
+        Some([4096, 131_072, 6_291_456])
+    );
+    assert_eq!(
+        parse_tcp_mem("4096 16384 4194304\n"),
+        Some([4096, 16384, 4_194_304])
+    );
+    assert_eq!(parse_tcp_mem("4096\t131072"), None);
+    assert_eq!(parse_tcp_mem("4096\t131072\t6291456\t1"), None);
+    assert_eq!(parse_tcp_mem("4096\tlarge\t6291456"), None);
+}
+
+#[test]
+fn test_machine_raw() {
+    let info = PlatformInfo::new().unwrap();
+    let machine_raw = info.machine_raw();
+    println!("machine_raw={machine_raw:?}");
+    assert_eq!(machine_raw, info.machine());
+}
+
+#[test]
+fn test_tpm_version() {
+    let info = PlatformInfo::new().unwrap();
+    let tpm_version = info.tpm_version();
+    println!("tpm_version={tpm_version:?}");
+    if let Some(v) = tpm_version {
+        assert!(["1.2", "2.0"].contains(&&*v.to_string_lossy()));
+    }
+}
+
+#[test]
+fn test_hardware_clock_is_utc() {
+    let info = PlatformInfo::new().unwrap();
+    let hardware_clock_is_utc = info.hardware_clock_is_utc();
+    println!("hardware_clock_is_utc={hardware_clock_is_utc:?}");
+
+    assert_eq!(parse_adjtime("0.0 0 0.0\n0\nUTC\n"), Some(true));
+    assert_eq!(
+        parse_adjtime("-0.010138 1701356457 0.000000\n1701356457\nLOCAL\n"),
+        Some(false)
+    );
+    assert_eq!(parse_adjtime("0.0 0 0.0\n0\n"), Some(true));
+    assert_eq!(parse_adjtime("0.0 0 0.0\n0\nbogus\n"), None);
+}
+
+#[test]
+fn test_login_defs_umask() {
+    let info = PlatformInfo::new().unwrap();
+    let login_defs_umask = info.login_defs_umask();
+    println!("login_defs_umask={login_defs_umask:?}");
+    if let Some(umask) = login_defs_umask {
+        assert!(umask <= 0o777);
+    }
+
+    let sample =
+        "# UMASK is the default umask value\n#UMASK 077\n\nUMASK\t\t022\nUSERGROUPS_ENAB yes\n";
+    assert_eq!(parse_login_defs_umask(sample), Some(0o022));
+    assert_eq!(
+        parse_login_defs_umask("UMASK 077\nUMASK 027\n"),
+        Some(0o027)
+    );
+    assert_eq!(parse_login_defs_umask("USERGROUPS_ENAB yes\n"), None);
+    assert_eq!(parse_login_defs_umask("UMASK 089\n"), None);
+}
+
+#[test]
+fn test_audio_backend() {
+    let info = PlatformInfo::new().unwrap();
+    let backend = info.audio_backend();
+    println!("audio_backend={backend:?}");
+
+    let runtime_dir = Some(std::path::Path::new("/run/user/1000"));
+    let sockets = |names: &'static [&'static str]| {
+        move |path: &std::path::Path| {
+            names
+                .iter()
+                .any(|name| path == std::path::Path::new("/run/user/1000").join(name))
+        }
+    };
+    let cards = " 0 [PCH            ]: HDA-Intel - HDA Intel PCH\n                      HDA Intel PCH at 0xf7f10000 irq 32\n";
+    let no_cards = "--- no soundcards ---\n";
+    let backend = |names, jack, alsa| audio_backend(runtime_dir, jack, alsa, sockets(names));
+
+    assert_eq!(
+        backend(&["pipewire-0", "pulse/native"], false, Some(cards)),
+        Some(OsString::from("pipewire"))
+    );
+    assert_eq!(
+        backend(&["pulse/native"], false, Some(cards)),
+        Some(OsString::from("pulseaudio"))
+    );
+    assert_eq!(
+        backend(&[], true, Some(cards)),
+        Some(OsString::from("jack"))
+    );
+    assert_eq!(
+        backend(&[], false, Some(cards)),
+        Some(OsString::from("alsa"))
+    );
+    // headless
+    assert_eq!(backend(&[], false, Some(no_cards)), None);
+    assert_eq!(backend(&[], false, None), None);
+    assert_eq!(audio_backend(None, false, None, |_| true), None);
+}
+
+#[test]
+fn test_machine_userland() {
+    let info = PlatformInfo::new().unwrap();
+    let machine_userland = info.machine_userland();
+    println!(
+        "machine={:?}; machine_userland={:?}",
+        info.machine(),
+        machine_userland
+    );
+    if cfg!(target_pointer_width = "64") {
+        assert_eq!(machine_userland, info.machine());
+    }
+
+    // * 32-bit userland on a 64-bit kernel
+    assert_eq!(userland_machine("x86_64", "x86"), Some("i686"));
+    assert_eq!(userland_machine("aarch64", "arm"), Some("armv8l"));
+    assert_eq!(userland_machine("ppc64", "powerpc"), Some("ppc"));
+    // * matching userland and kernel
+    assert_eq!(userland_machine("x86_64", "x86_64"), None);
+    assert_eq!(userland_machine("i686", "x86"), None); // eg, under `setarch i686`
+    assert_eq!(userland_machine("armv7l", "arm"), None);
+    assert_eq!(userland_machine("aarch64", "aarch64"), None);
+}
+
+#[test]
+fn test_running_under_emulation() {
+    let info = PlatformInfo::new().unwrap();
+    let running_under_emulation = info.running_under_emulation();
+    println!("running_under_emulation={running_under_emulation}");
+    if cfg!(all(
+        target_os = "linux",
+        any(target_arch = "x86_64", target_arch = "aarch64")
+    )) {
+        assert!(!running_under_emulation);
+    }
+
+    let is_foreign = crate::lib_impl::is_foreign_machine;
+    assert!(!is_foreign("x86_64", "x86_64"));
+    assert!(!is_foreign("amd64", "x86_64"));
+    assert!(!is_foreign("arm64", "aarch64"));
+    assert!(!is_foreign("i686", "x86"));
+    assert!(is_foreign("x86_64", "x86")); // 32-bit userland on a 64-bit kernel
+    assert!(is_foreign("aarch64", "arm"));
+    assert!(is_foreign("aarch64", "x86_64"));
+    assert!(!is_foreign("ia64", "x86_64")); // * unknown machine => not determinable
+                                            // * illumos/Solaris hardware platform names (used by both 32- and 64-bit processes) => not determinable
+    assert!(!is_foreign("i86pc", "x86_64"));
+    assert!(!is_foreign("i86pc", "x86"));
+    assert!(!is_foreign("sun4v", "sparc"));
+    assert!(!is_foreign("sun4u", "sparc64"));
+}
+
+#[test]
+fn test_os_release_id() {
+    let info = PlatformInfo::new().unwrap();
+    let id = info.os_release_id();
+    println!(
+        "os_release_id={:?}; id_like={:?}",
+        id,
+        info.os_release_id_like()
+    );
+    if !cfg!(any(target_os = "linux", target_os = "android")) {
+        assert_eq!(id, None);
+    }
+
+    let ubuntu = r#"PRETTY_NAME="Ubuntu 22.04.3 LTS"
+NAME="Ubuntu"
+VERSION_ID="22.04"
+ID=ubuntu
+ID_LIKE=debian
+"#;
+    assert_eq!(os_release_value(ubuntu, "ID").as_deref(), Some("ubuntu"));
+    assert_eq!(
+        os_release_value(ubuntu, "ID_LIKE").as_deref(),
+        Some("debian")
+    );
+    assert_eq!(os_release_value(ubuntu, "NAME").as_deref(), Some("Ubuntu"));
+
+    assert_eq!(
+        os_release_value(ubuntu, "PRETTY_NAME").as_deref(),
+        Some("Ubuntu 22.04.3 LTS")
+    );
+
+    let centos = "# comment\nNAME=\"CentOS Stream\"\nID=\"centos\"\nID_LIKE=\"rhel fedora\"\n";
+    assert_eq!(os_release_value(centos, "ID").as_deref(), Some("centos"));
+    assert_eq!(
+        os_release_value(centos, "ID_LIKE").as_deref(),
+        Some("rhel fedora")
+    );
+    assert_eq!(
+        os_release_value("ID='arch'\n", "ID").as_deref(),
+        Some("arch")
+    );
+    assert_eq!(
+        os_release_value(r#"NAME="A \"quoted\" \$name""#, "NAME").as_deref(),
+        Some(r#"A "quoted" $name"#)
+    );
+    // * `VERSION_ID` must not match `ID`
+    assert_eq!(os_release_value("VERSION_ID=1\n", "ID"), None);
+    assert_eq!(os_release_value("ID=\n", "ID"), None);
+    assert_eq!(os_release_value("ID=\"\"\n", "ID"), None);
+    assert_eq!(os_release_value("", "ID"), None);
+}
+
+#[test]
+fn test_os_pretty_name() {
+    let info = PlatformInfo::new().unwrap();
+    let pretty_name = info.os_pretty_name();
+    println!("os_pretty_name={:?}", pretty_name);
+    assert!(!pretty_name.is_empty());
+    if read_os_release().is_none() {
+        assert_eq!(pretty_name, info.osname());
+    }
+}
+
+#[test]
+#[cfg(feature = "init-detect")]
+fn test_init_system() {
+    let info = PlatformInfo::new().unwrap();
+    println!("init_system={:?}", info.init_system());
+
+    assert_eq!(init_system_from_comm("systemd\n", true), Some("systemd"));
+    assert_eq!(init_system_from_comm("systemd", false), Some("systemd"));
+    // * systemd marker takes precedence (eg, for an unusual pid 1 name)
+    assert_eq!(init_system_from_comm("init", true), Some("systemd"));
+    assert_eq!(init_system_from_comm("init", false), Some("sysvinit"));
+    assert_eq!(init_system_from_comm("openrc-init", false), Some("openrc"));
+    assert_eq!(init_system_from_comm("runit", false), Some("runit"));
+    assert_eq!(init_system_from_comm("s6-svscan", false), Some("s6"));
+    assert_eq!(init_system_from_comm("dinit", false), Some("dinit"));
+    assert_eq!(init_system_from_comm("docker-init", false), Some("tini"));
+    assert_eq!(init_system_from_comm("bash", false), None);
+    assert_eq!(init_system_from_comm("", false), None);
+}
+
+#[test]
+#[cfg(feature = "virtualization")]
+fn test_virtualization() {
+    let info = PlatformInfo::new().unwrap();
+    let virtualization = info.virtualization();
+    println!("virtualization={virtualization:?}");
+
+    assert_eq!(container_from_markers(true, false, None), Some("docker"));
+    assert_eq!(container_from_markers(false, true, None), Some("podman"));
+    let cgroup = "0::/kubepods/besteffort/pod8a1b/0123456789abcdef\n";
+    assert_eq!(
+        container_from_markers(false, false, Some(cgroup)),
+        Some("kubernetes")
+    );
+    let cgroup = "12:devices:/docker/0123456789abcdef\n11:cpu:/docker/0123456789abcdef\n";
+    assert_eq!(
+        container_from_markers(false, false, Some(cgroup)),
+        Some("docker")
+    );
+    assert_eq!(
+        container_from_markers(false, false, Some("0::/lxc.payload.c1\n")),
+        Some("lxc")
+    );
+    // native (systemd init)
+    assert_eq!(
+        container_from_markers(false, false, Some("0::/init.scope\n")),
+        None
+    );
+
+    let vendor = crate::lib_impl::virtualization_from_vendor;
+    assert_eq!(vendor("QEMU"), Some("qemu"));
+    assert_eq!(vendor("KVM"), Some("kvm"));
+    assert_eq!(vendor("VMware, Inc."), Some("vmware"));
+    assert_eq!(vendor("VirtualBox"), Some("virtualbox"));
+    assert_eq!(vendor("Virtual Machine"), Some("hyper-v"));
+    assert_eq!(vendor("Dell Inc."), None);
+}
+
+#[test]
+fn test_package_sandbox() {
+    let info = PlatformInfo::new().unwrap();
+    let sandbox = info.package_sandbox();
+    println!("package_sandbox={sandbox:?}");
+
+    let env = |vars: &'static [(&'static str, &'static str)]| {
+        move |name: &str| {
+            vars.iter()
+                .find(|(n, _)| *n == name)
+                .map(|(_, v)| OsString::from(v))
+        }
+    };
+    let no_files = |_: &str| false;
+    let flatpak_files = |path: &str| path == "/.flatpak-info";
+
+    assert_eq!(
+        package_sandbox(env(&[]), flatpak_files),
+        Some(OsString::from("flatpak"))
+    );
+    assert_eq!(
+        package_sandbox(env(&[("SNAP", "/snap/hello/42")]), no_files),
+        Some(OsString::from("snap"))
+    );
+    assert_eq!(
+        package_sandbox(env(&[("APPIMAGE", "/home/user/App.AppImage")]), no_files),
+        Some(OsString::from("appimage"))
+    );
+    // native
+    assert_eq!(package_sandbox(env(&[]), no_files), None);
+    assert_eq!(package_sandbox(env(&[("SNAP", "")]), no_files), None);
+}
+
+#[test]
+fn test_nf_conntrack_max() {
+    let info = PlatformInfo::new().unwrap();
+    let nf_conntrack_max = info.nf_conntrack_max();
+    println!("nf_conntrack_max={nf_conntrack_max:?}");
+    if let Some(n) = nf_conntrack_max {
+        assert!(n > 0);
+    }
+}
+
+#[test]
+fn test_conntrack_timeouts() {
+    let info = PlatformInfo::new().unwrap();
+    let conntrack_timeouts = info.conntrack_timeouts();
+    println!("conntrack_timeouts={conntrack_timeouts:?}");
+    if let Some(timeouts) = conntrack_timeouts {
+        assert!(!timeouts.is_empty());
+        // * kernel default is 432000 (5 days)
+        if let Some(&established) = timeouts.get("established") {
+            assert!(established > 0);
+        }
+        assert!(timeouts.keys().all(|state| !state.is_empty()));
+    }
+}
+
+#[test]
+fn test_inotify_max_watches() {
+    let info = PlatformInfo::new().unwrap();
+    let inotify_max_watches = info.inotify_max_watches();
+    println!("inotify_max_watches={inotify_max_watches:?}");
+    if cfg!(target_os = "linux") {
+        // * kernel default is 8192 (older kernels) or scaled with available RAM, with a floor of 8192 (kernel v5.11+)
+        // * note: admins *may* lower the value, but that would be highly unusual
+        assert!(inotify_max_watches >= Some(8192));
+    } else {
+        assert_eq!(inotify_max_watches, None);
+    }
+}
+
+#[test]
+fn test_aio_max_nr() {
+    let info = PlatformInfo::new().unwrap();
+    let aio_max_nr = info.aio_max_nr();
+    println!("aio_max_nr={aio_max_nr:?}");
+    if cfg!(target_os = "linux") {
+        // * kernel default is 65536; admins commonly raise it (eg, to 1048576) for database hosts
+        assert!(aio_max_nr > Some(0));
+    } else {
+        assert_eq!(aio_max_nr, None);
+    }
+}
+
+#[test]
+fn test_dirty_ratios() {
+    let info = PlatformInfo::new().unwrap();
+    let dirty_ratios = info.dirty_ratios();
+    println!("dirty_ratios={dirty_ratios:?}");
+    if cfg!(target_os = "linux") {
+        let (dirty_ratio, dirty_background_ratio) = dirty_ratios.unwrap();
+        assert!(dirty_ratio <= 100);
+        assert!(dirty_background_ratio <= 100);
+    } else {
+        assert_eq!(dirty_ratios, None);
+    }
+}
+
+#[test]
+fn test_overcommit() {
+    let info = PlatformInfo::new().unwrap();
+    let overcommit_memory = info.overcommit_memory();
+    let overcommit_ratio = info.overcommit_ratio();
+    println!("overcommit_memory={overcommit_memory:?}; overcommit_ratio={overcommit_ratio:?}");
+    if cfg!(target_os = "linux") {
+        assert!(matches!(overcommit_memory, Some(0..=2)));
+        assert!(overcommit_ratio.is_some());
+    } else {
+        assert_eq!(overcommit_memory, None);
+        assert_eq!(overcommit_ratio, None);
+    }
+}
+
+#[test]
+#[cfg(target_os = "haiku")]
+fn test_haiku_kernel_info() {
+    let info = PlatformInfo::new().unwrap();
+    let kernel_build_date = info.kernel_build_date();
+    let kernel_build_version = info.kernel_build_version();
+    println!(
+        "kernel_build_date={kernel_build_date:?}; kernel_build_version={kernel_build_version:?}"
+    );
+    assert!(!kernel_build_date.unwrap().is_empty());
+    assert!(kernel_build_version.is_some());
+    assert_eq!(info.sysname(), "Haiku");
+}
+
+#[test]
+fn test_isa_from_cpuinfo() {
+    #[cfg(target_arch = "riscv64")]
+    {
+        let info = PlatformInfo::new().unwrap();
+        println!("isa_string={:?}", info.isa_string());
+        if cfg!(target_os = "linux") {
+            assert!(info.isa_string().is_some());
+        }
+    }
+
+    let cpuinfo = "processor\t: 0
+hart\t\t: 1
+isa\t\t: rv64imafdc_zicntr_zicsr_zifencei_zihpm
+mmu\t\t: sv39
+uarch\t\t: sifive,u74-mc
+mvendorid\t: 0x489
+marchid\t\t: 0x8000000000000007
+mimpid\t\t: 0x4210427
+
+processor\t: 1
+hart\t\t: 2
+isa\t\t: rv64imafdc_zicntr_zicsr_zifencei_zihpm
+mmu\t\t: sv39
+";
+    assert_eq!(
+        isa_from_cpuinfo(cpuinfo),
+        Some("rv64imafdc_zicntr_zicsr_zifencei_zihpm")
+    );
+    assert_eq!(isa_from_cpuinfo("isa\t\t: rv32imac\n"), Some("rv32imac"));
+    assert_eq!(isa_from_cpuinfo("isa :\nisa : rv64gc"), Some("rv64gc"));
+    assert_eq!(
+        isa_from_cpuinfo(
+            "processor\t: 0\nmodel name\t: Intel(R) Core(TM) i7\nflags\t\t: fpu vme\n"
+        ),
+        None
+    );
+    assert_eq!(isa_from_cpuinfo("isa_ext\t: zba\n"), None);
+    assert_eq!(isa_from_cpuinfo(""), None);
+}
+
+#[test]
+#[cfg(target_os = "android")]
+fn test_android_properties() {
+    let info = PlatformInfo::new().unwrap();
+    let android_release = info.android_release();
+    let android_sdk_int = info.android_sdk_int();
+    println!("android_release={android_release:?}");
+    println!("android_sdk_int={android_sdk_int:?}");
+    assert!(android_release.is_some_and(|release| !release.is_empty()));
+    // * API level 1 == Android 1.0
+    assert!(android_sdk_int >= Some(1));
+    // * `release()` remains the kernel release
+    assert_ne!(info.release(), android_release.unwrap());
+}
+
+#[test]
+#[cfg(any(target_os = "illumos", target_os = "solaris"))]
+fn test_solarish_sysinfo() {
+    let info = PlatformInfo::new().unwrap();
+    let platform = info.platform();
+    let hw_provider = info.hw_provider();
+    println!("platform={platform:?}; hw_provider={hw_provider:?}");
+    assert!(!platform.unwrap().is_empty());
+    assert!(hw_provider.is_some());
+}
+
+#[test]
+fn test_no_trailing_padding() {
+    let info = PlatformInfo::new().unwrap();
+    for (name, field) in [
+        ("sysname", info.sysname()),
+        ("nodename", info.nodename()),
+        ("release", info.release()),
+        ("version", info.version()),
+        ("machine", info.machine()),
+        ("osname", info.osname()),
+    ] {
+        let s = field.to_string_lossy();
+        println!("{name}=[{}]'{s}'", s.len());
+        assert!(!s.ends_with(|c: char| c.is_whitespace() || c.is_control()));
+    }
+
+    // only *trailing* padding is trimmed
+    let padded: Vec<libc::c_char> = b"  #1 SMP  Debian \t\n\0\0"
+        .iter()
+        .map(|&b| b as libc::c_char)
+        .collect();
+    assert_eq!(oss_from_cstr(&padded), "  #1 SMP  Debian");
+    let blank: Vec<libc::c_char> = b" \0".iter().map(|&b| b as libc::c_char).collect();
+    assert_eq!(oss_from_cstr(&blank), "");
+}
+
+#[test]
+fn test_utsname_fields() {
+    let info = PlatformInfo::new().unwrap();
+    let utsname = info.utsname;
+    assert_eq!(utsname.sysname(), info.sysname());
+    assert_eq!(utsname.nodename(), info.nodename());
+    assert_eq!(utsname.release(), info.release());
+    assert_eq!(utsname.version(), info.version());
+    assert_eq!(utsname.machine(), info.machine_raw());
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_utsname_domainname() {
+    let info = PlatformInfo::new().unwrap();
+    let domainname = info.utsname.domainname();
+    println!("domainname={domainname:?}");
+    // * Linux reports "(none)" for an unset domain name
+    assert!(domainname.len() < info.utsname.0.domainname.len());
+}
+
+#[test]
+fn test_wsl_version() {
+    let info = PlatformInfo::new().unwrap();
+    let wsl_version = info.wsl_version();
+    println!("wsl_version={wsl_version:?}");
+    assert_eq!(info.is_wsl(), wsl_version.is_some());
+    if !cfg!(target_os = "linux") {
+        assert_eq!(wsl_version, None);
+    }
+
+    assert_eq!(
+        wsl_version_from_kernel_info("4.4.0-19041-Microsoft"),
+        Some(1)
+    );
+    assert_eq!(
+        wsl_version_from_kernel_info("4.19.104-microsoft-standard"),
+        Some(2)
+    );
+    assert_eq!(
+        wsl_version_from_kernel_info("5.15.90.1-microsoft-standard-WSL2"),
+        Some(2)
+    );
+    assert_eq!(
+        wsl_version_from_kernel_info("6.6.36.3-WSL2-custom"),
+        Some(2)
+    );
+    assert_eq!(
+        wsl_version_from_kernel_info(
+            "Linux version 4.4.0-19041-Microsoft (Microsoft@Microsoft.com) (gcc version 5.4.0 (GCC) ) #1237-Microsoft"
+        ),
+        Some(1)
+    );
+    assert_eq!(wsl_version_from_kernel_info("6.1.0-18-amd64"), None);
+    assert_eq!(wsl_version_from_kernel_info("6.5.0-21-generic"), None);
+    assert_eq!(wsl_version_from_kernel_info(""), None);
+}
+
+#[test]
+fn test_same_platform() {
+    let info = PlatformInfo::new().unwrap();
+    let mut renamed = info.clone();
+    renamed.nodename = OsString::from("renamed-nodename");
+    assert!(info.same_platform(&renamed));
+    assert_ne!(info, renamed);
+
+    let mut upgraded = info.clone();
+    upgraded.release = OsString::from("0.0.0");
+    assert!(!info.same_platform(&upgraded));
+}
+
+#[test]
+fn test_refresh() {
+    let mut info = PlatformInfo::new().unwrap();
+    let original = info.clone();
+    info.nodename = OsString::from("stale-nodename");
+    info.refresh().unwrap();
+    assert_eq!(info.nodename(), original.nodename());
+    assert_eq!(info, original);
 }
 
 #[test]
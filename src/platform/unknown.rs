@@ -13,19 +13,25 @@
 
 use std::ffi::{OsStr, OsString};
 
-use crate::{PlatformInfoAPI, PlatformInfoError, UNameAPI};
+use crate::{
+    ChassisType, MitigationSummary, PlatformInfoAPI, PlatformInfoError, SocketBufferLimits,
+    UNameAPI,
+};
 
 // PlatformInfo
 /// Handles initial retrieval and holds cached information for the current platform ("unknown" in this case).
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct PlatformInfo {
     unknown: OsString,
+    machine: OsString,
 }
 
 impl PlatformInfoAPI for PlatformInfo {
     fn new() -> Result<Self, PlatformInfoError> {
+        let unknown = OsString::from(crate::host_os_name());
         Ok(Self {
-            unknown: OsString::from(crate::lib_impl::HOST_OS_NAME),
+            machine: unknown.clone(),
+            unknown,
         })
     }
 }
@@ -48,7 +54,7 @@ impl UNameAPI for PlatformInfo {
     }
 
     fn machine(&self) -> &OsStr {
-        &self.unknown
+        &self.machine
     }
 
     fn osname(&self) -> &OsStr {
@@ -56,6 +62,218 @@ impl UNameAPI for PlatformInfo {
     }
 }
 
+impl PlatformInfo {
+    /// Re-queries volatile information, updating the cached values in place; a no-op for this platform.
+    pub fn refresh(&mut self) -> Result<(), PlatformInfoError> {
+        Ok(())
+    }
+
+    /// *Returns* the OS-native name of the machine hardware; always "unknown" for this platform.
+    pub fn machine_raw(&self) -> OsString {
+        self.unknown.clone()
+    }
+
+    /// *Returns* `true` if the current process is running under emulation; always `false` for this platform.
+    pub fn running_under_emulation(&self) -> bool {
+        false
+    }
+
+    /// *Returns* the "pretty" (ie, human-readable) OS name; always "unknown" (ie, [`osname()`](UNameAPI::osname)) for
+    /// this platform.
+    pub fn os_pretty_name(&self) -> OsString {
+        self.unknown.clone()
+    }
+
+    /// Replaces the cached machine name (see [`with_machine_overrides()`](PlatformInfo::with_machine_overrides)).
+    pub(crate) fn set_machine(&mut self, machine: OsString) {
+        self.machine = machine;
+    }
+
+    /// Converts into the (owned) `uname`-style fields.
+    pub(crate) fn into_uname_data(self) -> crate::UnameData {
+        crate::UnameData {
+            sysname: self.unknown.clone(),
+            nodename: self.unknown.clone(),
+            release: self.unknown.clone(),
+            version: self.unknown.clone(),
+            machine: self.machine,
+            osname: self.unknown,
+        }
+    }
+
+    /// *Returns* the time elapsed since the last system boot; always `None` for this platform.
+    #[cfg(feature = "uptime")]
+    pub fn uptime(&self) -> Option<std::time::Duration> {
+        None
+    }
+
+    /// *Returns* the configured time zone; always `None` for this platform.
+    #[cfg(feature = "timezone")]
+    pub fn timezone(&self) -> Option<OsString> {
+        None
+    }
+
+    /// *Returns* the set of natively determined ("real") fields; always empty for this platform (all fields are
+    /// synthesized as "unknown").
+    pub fn field_capabilities(&self) -> crate::FieldCapabilities {
+        crate::FieldCapabilities::empty()
+    }
+
+    /// *Returns* the size (in bytes) of a memory page; always `None` for this platform.
+    pub fn page_size(&self) -> Option<usize> {
+        None
+    }
+
+    /// *Returns* the bitness (ie, 32 or 64) of the operating system; always `None` for this platform.
+    pub fn os_bitness(&self) -> Option<u8> {
+        None
+    }
+
+    /// *Returns* the form factor (aka chassis type) of the system; always `None` for this platform.
+    pub fn chassis_type(&self) -> Option<ChassisType> {
+        None
+    }
+
+    /// *Returns* the normalized name of the container or virtual machine environment; always `None` for this platform.
+    #[cfg(feature = "virtualization")]
+    pub fn virtualization(&self) -> Option<OsString> {
+        None
+    }
+
+    /// *Returns* the normalized name of the init system; always `None` for this platform.
+    #[cfg(feature = "init-detect")]
+    pub fn init_system(&self) -> Option<OsString> {
+        None
+    }
+
+    /// *Returns* the graphics drivers in use; always `None` for this platform.
+    pub fn graphics_drivers(&self) -> Option<Vec<OsString>> {
+        None
+    }
+
+    /// *Returns* the fully qualified domain name (FQDN) of this machine; always `None` for this platform.
+    pub fn fqdn(&self) -> Option<OsString> {
+        None
+    }
+
+    /// *Returns* the descriptions of any attached FIDO2/U2F security keys; always `None` for this platform.
+    pub fn fido_authenticators(&self) -> Option<Vec<OsString>> {
+        None
+    }
+
+    /// *Returns* whether the hardware clock is kept in UTC; always `None` for this platform.
+    pub fn hardware_clock_is_utc(&self) -> Option<bool> {
+        None
+    }
+
+    /// *Returns* the maximum size (in bytes) of a file on the root file system; always `None` for this platform.
+    pub fn max_file_size(&self) -> Option<u64> {
+        None
+    }
+
+    /// *Returns* the version of the system TPM (Trusted Platform Module); always `None` for this platform.
+    pub fn tpm_version(&self) -> Option<OsString> {
+        None
+    }
+
+    /// *Returns* the configured maximum number of inotify watches per user; always `None` for this platform.
+    pub fn inotify_max_watches(&self) -> Option<u32> {
+        None
+    }
+
+    /// *Returns* the configured virtual memory overcommit policy; always `None` for this platform.
+    pub fn overcommit_memory(&self) -> Option<u8> {
+        None
+    }
+
+    /// *Returns* the configured percentage of physical RAM which may be committed when using the "never overcommit"
+    /// policy; always `None` for this platform.
+    pub fn overcommit_ratio(&self) -> Option<u32> {
+        None
+    }
+
+    /// *Returns* the configured system-wide maximum number of concurrent asynchronous I/O requests; always `None` for
+    /// this platform.
+    pub fn aio_max_nr(&self) -> Option<u64> {
+        None
+    }
+
+    /// *Returns* the configured dirty-page writeback thresholds; always `None` for this platform.
+    pub fn dirty_ratios(&self) -> Option<(u8, u8)> {
+        None
+    }
+
+    /// *Returns* whether the kernel CRNG (cryptographically secure random number generator) has been initialized; always
+    /// `None` for this platform.
+    pub fn urandom_ready(&self) -> Option<bool> {
+        None
+    }
+
+    /// *Returns* the overall CPU vulnerability mitigation state; always `None` for this platform.
+    pub fn mitigation_summary(&self) -> Option<MitigationSummary> {
+        None
+    }
+
+    /// *Returns* the estimated maximum number of threads which the current process may create; always `None` for this
+    /// platform.
+    pub fn threads_max(&self) -> Option<u64> {
+        None
+    }
+
+    /// *Returns* the names of the capabilities in the effective capability set of the current process; always `None` for
+    /// this platform.
+    pub fn capabilities(&self) -> Option<Vec<OsString>> {
+        None
+    }
+
+    /// *Returns* the application packaging sandbox in which the current process is running; always `None` for this
+    /// platform.
+    pub fn package_sandbox(&self) -> Option<OsString> {
+        None
+    }
+
+    /// *Returns* the configured maximum number of tracked network connections; always `None` for this platform.
+    pub fn nf_conntrack_max(&self) -> Option<u32> {
+        None
+    }
+
+    /// *Returns* the configured TCP connection tracking timeouts; always `None` for this platform.
+    pub fn conntrack_timeouts(&self) -> Option<std::collections::BTreeMap<String, u32>> {
+        None
+    }
+
+    /// *Returns* the system default file-creation mask; always `None` for this platform.
+    pub fn login_defs_umask(&self) -> Option<u32> {
+        None
+    }
+
+    /// *Returns* the configured socket buffer size limits; always `None` for this platform.
+    pub fn socket_buffer_limits(&self) -> Option<SocketBufferLimits> {
+        None
+    }
+
+    /// *Returns* `true` if running under WSL (Windows Subsystem for Linux); always `false` for this platform.
+    pub fn is_wsl(&self) -> bool {
+        false
+    }
+
+    /// *Returns* the major version of WSL (Windows Subsystem for Linux); always `None` for this platform.
+    pub fn wsl_version(&self) -> Option<u8> {
+        None
+    }
+
+    /// *Returns* the machine-readable OS (distribution) identifier; always `None` for this platform.
+    pub fn os_release_id(&self) -> Option<OsString> {
+        None
+    }
+
+    /// *Returns* the identifiers of the OS (distributions) from which this OS is derived; always `None` for this
+    /// platform.
+    pub fn os_release_id_like(&self) -> Option<Vec<OsString>> {
+        None
+    }
+}
+
 #[test]
 fn test_unknown() {
     let platform_info = PlatformInfo::new().unwrap();
@@ -66,6 +284,13 @@ fn test_unknown() {
     assert_eq!(platform_info.version().to_string_lossy(), "unknown");
     assert_eq!(platform_info.machine().to_string_lossy(), "unknown");
     assert_eq!(platform_info.osname().to_string_lossy(), "unknown");
+    assert_eq!(platform_info.os_pretty_name().to_string_lossy(), "unknown");
+}
+
+#[test]
+fn test_field_capabilities() {
+    let info = PlatformInfo::new().unwrap();
+    assert!(info.field_capabilities().is_empty());
 }
 
 #[test]
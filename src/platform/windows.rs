@@ -38,10 +38,17 @@ use std::io;
 use std::os::windows::ffi::OsStringExt;
 
 use winapi::shared::minwindef::*;
+use winapi::shared::winerror::*;
+use winapi::um::handleapi::INVALID_HANDLE_VALUE;
+use winapi::um::setupapi::*;
 use winapi::um::sysinfoapi::*;
 use winapi::um::winnt::*;
+use winapi::um::winreg::*;
 
-use crate::{PlatformInfoAPI, PlatformInfoError, UNameAPI};
+use crate::{
+    ChassisType, MitigationSummary, PlatformInfoAPI, PlatformInfoError, SocketBufferLimits,
+    UNameAPI,
+};
 
 use super::PathStr;
 use super::PathString;
@@ -70,23 +77,50 @@ pub struct PlatformInfo {
     version: OsString,
     machine: OsString,
     osname: OsString,
+    is_wow64: bool,
+    physical_cpu_count: Option<usize>,
 }
 
 impl PlatformInfoAPI for PlatformInfo {
     // * note: due to the method of information retrieval, this *may* fail
     fn new() -> Result<Self, PlatformInfoError> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("PlatformInfo::new").entered();
         let computer_name = WinOsGetComputerName()?;
         let system_info = WinApiSystemInfo(WinAPI_GetNativeSystemInfo());
         let version_info = os_version_info()?;
+        let is_wow64 = KERNEL32_IsWow64Process(WinAPI_GetCurrentProcess()).unwrap_or(false);
+        let physical_cpu_count = WinOsGetProcessorCoreInformation()
+            .ok()
+            .and_then(|info| processor_core_counts(&info))
+            .map(|(cores, _)| cores)
+            .filter(|&cores| cores > 0);
 
+        Ok(Self {
+            physical_cpu_count,
+            ..Self::from_components(computer_name, system_info, version_info, is_wow64)
+        })
+    }
+}
+
+impl PlatformInfo {
+    /// *Returns* a [`PlatformInfo`] built from the (already retrieved) system information components.
+    // * note: all string information is kept as `OsString` (ie, WTF-16 compatible, with no intermediate `String`
+    //   conversion) so that any ill-formed UTF-16 (eg, a lone surrogate within the computer name) is preserved
+    fn from_components(
+        computer_name: OsString,
+        system_info: WinApiSystemInfo,
+        version_info: WinOsVersionInfo,
+        is_wow64: bool,
+    ) -> Self {
         let sysname = determine_sysname();
         let nodename = computer_name.clone();
-        let release = version_info.release.clone();
-        let version = version_info.version.clone();
-        let machine = determine_machine(&system_info);
+        let release = version_info.release().to_os_string();
+        let version = version_info.version().to_os_string();
+        let machine = determine_machine(&system_info, is_wow64);
         let osname = determine_osname(&version_info);
 
-        Ok(Self {
+        Self {
             computer_name,
             system_info,
             version_info,
@@ -97,7 +131,9 @@ impl PlatformInfoAPI for PlatformInfo {
             version,
             machine,
             osname,
-        })
+            is_wow64,
+            physical_cpu_count: None,
+        }
     }
 }
 
@@ -127,6 +163,445 @@ impl UNameAPI for PlatformInfo {
     }
 }
 
+impl PlatformInfo {
+    /// Re-queries volatile information (ie, the computer name and OS version info), updating the cached values in place.
+    ///
+    /// Refreshes [`computer_name`](PlatformInfo::computer_name), [`version_info`](PlatformInfo::version_info),
+    /// [`nodename()`](UNameAPI::nodename), [`release()`](UNameAPI::release), [`version()`](UNameAPI::version), and
+    /// [`osname()`](UNameAPI::osname); all other cached values (eg, [`machine()`](UNameAPI::machine)) are left
+    /// untouched. On failure, all cached values are left unchanged.
+    pub fn refresh(&mut self) -> Result<(), PlatformInfoError> {
+        let computer_name = WinOsGetComputerName()?;
+        let version_info = os_version_info()?;
+
+        self.nodename = computer_name.clone();
+        self.release = version_info.release().to_os_string();
+        self.version = version_info.version().to_os_string();
+        self.osname = determine_osname(&version_info);
+        self.computer_name = computer_name;
+        self.version_info = version_info;
+        Ok(())
+    }
+
+    /// *Returns* the form factor (aka chassis type) of the system, or `None` if it can't be determined.
+    ///
+    /// Queried (via the registry and raw SMBIOS firmware table) on each call.
+    pub fn chassis_type(&self) -> Option<ChassisType> {
+        let bios_key = r"HARDWARE\DESCRIPTION\System\BIOS";
+        let is_virtual_machine =
+            ["SystemManufacturer", "SystemProductName"]
+                .iter()
+                .any(|value_name| {
+                    WinOsRegGetStringValue(HKEY_LOCAL_MACHINE, bios_key, value_name).is_ok_and(
+                        |name| crate::lib_impl::is_virtual_machine_vendor(&name.to_string_lossy()),
+                    )
+                });
+        if is_virtual_machine {
+            return Some(ChassisType::VirtualMachine);
+        }
+        // firmware table provider signature == 'RSMB' (raw SMBIOS data)
+        let provider = DWORD::from_be_bytes(*b"RSMB");
+        let smbios_data = WinOsGetSystemFirmwareTable(provider, 0).ok()?;
+        ChassisType::from_smbios_code(smbios_chassis_code(&smbios_data)?)
+    }
+
+    /// *Returns* the normalized name of the virtual machine environment (eg, "hyper-v", "vmware", "virtualbox", or
+    /// "kvm"), or `None` if running natively (or if undetermined).
+    ///
+    /// Determined from the system manufacturer/product (via the registry) on each call.
+    #[cfg(feature = "virtualization")]
+    pub fn virtualization(&self) -> Option<OsString> {
+        let bios_key = r"HARDWARE\DESCRIPTION\System\BIOS";
+        ["SystemManufacturer", "SystemProductName"]
+            .iter()
+            .find_map(|value_name| {
+                let name = WinOsRegGetStringValue(HKEY_LOCAL_MACHINE, bios_key, value_name).ok()?;
+                crate::lib_impl::virtualization_from_vendor(&name.to_string_lossy())
+            })
+            .map(OsString::from)
+    }
+
+    /// *Returns* the normalized name of the init system; always `None` for this platform.
+    #[cfg(feature = "init-detect")]
+    pub fn init_system(&self) -> Option<OsString> {
+        None
+    }
+
+    /// *Returns* the display (graphics) adapter descriptions and driver versions (eg, "NVIDIA GeForce RTX 3080
+    /// 31.0.15.3598"), or `None` if no display adapter information is available.
+    ///
+    /// Queried (via the registry) on each call.
+    pub fn graphics_drivers(&self) -> Option<Vec<OsString>> {
+        // display adapter device class registry key; ref: <https://learn.microsoft.com/en-us/windows-hardware/drivers/install/system-defined-device-setup-classes-available-to-vendors>
+        let class_key =
+            r"SYSTEM\CurrentControlSet\Control\Class\{4d36e968-e325-11ce-bfc1-08002be10318}";
+        let mut drivers = Vec::new();
+        // * adapter subkeys are numbered ("0000", "0001", ...), but not necessarily contiguously; non-adapter subkeys
+        //   (eg, "Properties") have no driver description and are skipped
+        for name in WinOsRegEnumSubKeyNames(HKEY_LOCAL_MACHINE, class_key).ok()? {
+            let mut sub_key = PathString::from(class_key);
+            sub_key.push(name);
+            let description =
+                match WinOsRegGetStringValue(HKEY_LOCAL_MACHINE, &sub_key, "DriverDesc") {
+                    Ok(description) => description,
+                    Err(_) => continue,
+                };
+            let mut driver = description;
+            if let Ok(version) =
+                WinOsRegGetStringValue(HKEY_LOCAL_MACHINE, &sub_key, "DriverVersion")
+            {
+                driver.push(" ");
+                driver.push(version);
+            }
+            if !drivers.contains(&driver) {
+                drivers.push(driver);
+            }
+        }
+        if drivers.is_empty() {
+            None
+        } else {
+            Some(drivers)
+        }
+    }
+
+    /// *Returns* the ABI (aka toolchain environment) for which this crate was compiled: "msvc" (Microsoft Visual C++) or
+    /// "gnu" (MinGW).
+    ///
+    /// Note: [`sysname()`](UNameAPI::sysname) is always "Windows_NT"; tools emulating a MinGW/MSYS2 `uname` (eg,
+    /// "MINGW64_NT-10.0") may use this to adjust their output.
+    pub fn abi(&self) -> &'static str {
+        if cfg!(target_env = "msvc") {
+            "msvc"
+        } else if cfg!(target_env = "gnu") {
+            "gnu"
+        } else {
+            "unknown"
+        }
+    }
+
+    /// *Returns* an MSYS2/Cygwin-style system name (eg, "MINGW64_NT-10.0-19045"), as reported by `uname -s` within those
+    /// environments.
+    ///
+    /// The environment prefix is taken from `MSYSTEM` (eg, "MINGW64", "UCRT64", or "MSYS"; as set by MSYS2), defaulting
+    /// to "MINGW64" (or "MINGW32" for a 32-bit WinOS). Note: [`sysname()`](UNameAPI::sysname) remains "Windows_NT".
+    pub fn sysname_msys(&self) -> OsString {
+        let environment = std::env::var("MSYSTEM")
+            .ok()
+            .filter(|s| !s.is_empty())
+            .unwrap_or_else(|| {
+                if self.os_bitness() == Some(32) {
+                    "MINGW32".to_string()
+                } else {
+                    "MINGW64".to_string()
+                }
+            });
+        msys_sysname(&environment, &self.version_info)
+    }
+
+    /// *Returns* the fully qualified domain name (FQDN) of this machine (eg, "host.example.com"), or `None` if it can't
+    /// be resolved.
+    ///
+    /// Resolved (via `GetAddrInfoW()`) from [`nodename()`](UNameAPI::nodename) on each call. Note: resolution *may*
+    /// require a DNS query, so this call may block (eg, for several seconds if the DNS server is unreachable).
+    pub fn fqdn(&self) -> Option<OsString> {
+        WinOsGetAddrInfoCanonicalName(&self.nodename)
+            .ok()
+            .filter(|name| !name.is_empty())
+    }
+
+    /// *Returns* the descriptions of any attached FIDO2/U2F security keys (ie, HID devices using the FIDO Alliance usage
+    /// page), or `None` if none are present.
+    ///
+    /// Queried (via `SetupAPI`) on each call.
+    pub fn fido_authenticators(&self) -> Option<Vec<OsString>> {
+        let authenticators = WinOsGetFidoDeviceNames().ok()?;
+        if authenticators.is_empty() {
+            None
+        } else {
+            Some(authenticators)
+        }
+    }
+
+    /// *Returns* the maximum size (in bytes) of a file on the system volume, or `None` if it can't be determined.
+    ///
+    /// Inferred from the file system type of the system volume (eg, "NTFS" => 16 TiB - 64 KiB or "FAT32" => 4 GiB - 1);
+    /// queried on each call.
+    pub fn max_file_size(&self) -> Option<u64> {
+        let system_directory = WinOsGetSystemDirectory().ok()?;
+        // * volume root directory (eg, `C:\`)
+        let root: PathString = system_directory.components().take(2).collect();
+        let file_system_name = WinOsGetVolumeFileSystemName(root).ok()?;
+        max_file_size_for_file_system(&file_system_name.to_string_lossy())
+    }
+
+    /// *Returns* whether the current process is running under WOW64 (ie, a 32-bit process on a 64-bit WinOS).
+    ///
+    /// Determined once, during construction; any failure to determine the value is reported as `false`.
+    pub fn is_wow64(&self) -> bool {
+        self.is_wow64
+    }
+
+    /// *Returns* `true` if the current process is running under emulation (eg, an "x86" or "x86_64" binary on an ARM64
+    /// WinOS) or WOW64 (eg, an "x86" binary on an "x86_64" WinOS).
+    ///
+    /// Determined from [`is_wow64()`](PlatformInfo::is_wow64) and by comparing the native machine name with the compiled
+    /// target architecture ([`BuildTarget::arch`](crate::BuildTarget::arch)).
+    pub fn running_under_emulation(&self) -> bool {
+        is_emulated(
+            self.is_wow64,
+            &self.machine_raw().to_string_lossy(),
+            std::env::consts::ARCH,
+        )
+    }
+
+    /// *Returns* the OS-native name of the machine hardware (eg, "AMD64", "ARM64", or "x86"), as used by WinOS (eg,
+    /// for `PROCESSOR_ARCHITECTURE`, Python's `platform.machine()`, or PowerShell).
+    ///
+    /// [`machine()`](UNameAPI::machine) remains the normalized (GNU-style) name (eg, "x86_64", "aarch64", or "i686").
+    pub fn machine_raw(&self) -> OsString {
+        OsString::from(determine_machine_raw(&self.system_info, self.is_wow64))
+    }
+
+    /// Replaces the cached machine name (see [`with_machine_overrides()`](PlatformInfo::with_machine_overrides)).
+    pub(crate) fn set_machine(&mut self, machine: OsString) {
+        self.machine = machine;
+    }
+
+    /// Converts into the (owned) `uname`-style fields, moving (rather than cloning) the cached values.
+    pub(crate) fn into_uname_data(self) -> crate::UnameData {
+        crate::UnameData {
+            sysname: self.sysname,
+            nodename: self.nodename,
+            release: self.release,
+            version: self.version,
+            machine: self.machine,
+            osname: self.osname,
+        }
+    }
+
+    /// *Returns* the [`WindowsVersion`] classification of the running WinOS (eg, [`WindowsVersion::Windows11`]).
+    ///
+    /// Prefer this to matching on the "friendly" OS name string, which is intended for display.
+    pub fn windows_version(&self) -> WindowsVersion {
+        self.version_info.windows_version
+    }
+
+    /// *Returns* the "friendly" OS product name (eg, "Windows 11"), without the [`osname()`](UNameAPI::osname) host OS
+    /// prefix (ie, the parenthetical of "MS/Windows (Windows 11)"), falling back to `osname()` if it's unavailable.
+    pub fn os_pretty_name(&self) -> OsString {
+        let os_name = self.version_info.os_name();
+        match os_name.to_str().map(str::trim) {
+            Some("") => self.osname.clone(),
+            Some(trimmed) => OsString::from(trimmed),
+            None => os_name.to_os_string(),
+        }
+    }
+
+    /// *Returns* the OS build number, parsed from [`version()`](UNameAPI::version) (eg, "19045" => 19045), or `None`
+    /// if it isn't numeric.
+    pub fn build_number(&self) -> Option<u32> {
+        parse_build_number(&self.version)
+    }
+
+    /// *Returns* the general/main OS version, parsed from [`release()`](UNameAPI::release) as `(major, minor)` (eg,
+    /// "10.0" => `(10, 0)`), or `None` if it isn't of the form "*major*.*minor*".
+    pub fn release_version(&self) -> Option<(u32, u32)> {
+        parse_release_version(&self.release)
+    }
+
+    /// *Returns* `true` if the running WinOS is at least version `major`.`minor` (build `build`; eg, `(10, 0, 22000)`
+    /// for Windows 11), or `false` if it's older or its version can't be determined.
+    ///
+    /// Uses the cached version info (obtained via `RtlGetVersion()`, or the version of the system DLLs), which reports
+    /// the true OS version. In contrast, `VerifyVersionInfoW()` (like `GetVersionEx()`) is subject to application
+    /// manifest compatibility shims; so, for an executable without a (Windows 8.1 or later) `supportedOS` manifest entry,
+    /// it "lies", reporting Windows 8 (6.2) as the maximum version.
+    // ref: <https://learn.microsoft.com/en-us/windows/win32/sysinfo/targeting-your-application-at-windows-8-1>
+    pub fn at_least_windows(&self, major: u32, minor: u32, build: u32) -> bool {
+        is_version_at_least(&self.release, &self.version, (major, minor, build))
+    }
+
+    /// *Returns* the number of physical processor cores (across all processor groups), or `None` if it can't be
+    /// determined.
+    ///
+    /// Queried (via `GetLogicalProcessorInformationEx()`) and cached at construction. In contrast,
+    /// [`system_info`](PlatformInfo::system_info) (`dwNumberOfProcessors`) reports the number of logical processors
+    /// (eg, including SMT/hyper-threading siblings) within the current processor group.
+    pub fn physical_cpu_count(&self) -> Option<usize> {
+        self.physical_cpu_count
+    }
+
+    /// *Returns* the time elapsed since the last system boot.
+    ///
+    /// Queried (via `GetTickCount64()`) on each call. Note: time spent in sleep/hibernation is included.
+    #[cfg(feature = "uptime")]
+    pub fn uptime(&self) -> Option<std::time::Duration> {
+        Some(std::time::Duration::from_millis(WinAPI_GetTickCount64()))
+    }
+
+    /// *Returns* the configured time zone (eg, "W. Europe Standard Time"), or `None` if it can't be determined.
+    ///
+    /// Queried (via `GetDynamicTimeZoneInformation()`) on each call. Note: the WinOS time zone key name is returned
+    /// (falling back to the standard time name), *not* an IANA time zone name.
+    #[cfg(feature = "timezone")]
+    pub fn timezone(&self) -> Option<OsString> {
+        let info = WinAPI_GetDynamicTimeZoneInformation().ok()?;
+        let wide_str = |data: &[WCHAR]| {
+            let length = data.iter().position(|&c| c == 0).unwrap_or(data.len());
+            OsString::from_wide(&data[..length])
+        };
+        [&info.TimeZoneKeyName[..], &info.StandardName[..]]
+            .iter()
+            .map(|name| wide_str(name))
+            .find(|name| !name.is_empty())
+    }
+
+    /// *Returns* the set of natively determined ("real") fields for this platform; ie, all `uname`-style fields (but no
+    /// `DOMAINNAME`, which has no WinOS equivalent).
+    pub fn field_capabilities(&self) -> crate::FieldCapabilities {
+        crate::FieldCapabilities::UNAME
+    }
+
+    /// *Returns* the size (in bytes) of a memory page (ie, `dwPageSize` of the cached
+    /// [`system_info`](PlatformInfo::system_info)).
+    pub fn page_size(&self) -> Option<usize> {
+        usize::try_from(self.system_info.0.dwPageSize)
+            .ok()
+            .filter(|&size| size > 0)
+    }
+
+    /// *Returns* the bitness (ie, 32 or 64) of the operating system (independent of the bitness of the current
+    /// process), or `None` if it can't be determined.
+    ///
+    /// Derived from the native processor architecture (see [`system_info`](PlatformInfo::system_info)) and
+    /// [`is_wow64()`](PlatformInfo::is_wow64).
+    pub fn os_bitness(&self) -> Option<u8> {
+        determine_os_bitness(&self.system_info, self.is_wow64)
+    }
+
+    /// *Returns* whether the hardware (aka real-time) clock is kept in UTC (`true`) or in local time (`false`).
+    ///
+    /// Queried (via the `RealTimeIsUniversal` registry value) on each call; WinOS keeps the hardware clock in local
+    /// time unless that value is set, so this is never `None`.
+    pub fn hardware_clock_is_utc(&self) -> Option<bool> {
+        // ref: <https://wiki.archlinux.org/title/System_time#UTC_in_Microsoft_Windows>
+        let time_zone_key = r"SYSTEM\CurrentControlSet\Control\TimeZoneInformation";
+        Some(
+            WinOsRegGetDwordValue(HKEY_LOCAL_MACHINE, time_zone_key, "RealTimeIsUniversal")
+                .is_ok_and(|value| value != 0),
+        )
+    }
+
+    /// *Returns* the version of the system TPM (Trusted Platform Module) (eg, "2.0"), or `None` if no TPM is present.
+    ///
+    /// Queried (via `TBS/Tbsi_GetDeviceInfo()`) on each call.
+    pub fn tpm_version(&self) -> Option<OsString> {
+        // ref: <https://learn.microsoft.com/en-us/windows/win32/api/tbs/ns-tbs-tpm_device_info>
+        match TBS_Tbsi_GetDeviceInfo().ok()?.tpmVersion {
+            1 /* TPM_VERSION_12 */ => Some(OsString::from("1.2")),
+            2 /* TPM_VERSION_20 */ => Some(OsString::from("2.0")),
+            _ => None,
+        }
+    }
+
+    /// *Returns* the configured maximum number of inotify watches per user; always `None` for this platform.
+    pub fn inotify_max_watches(&self) -> Option<u32> {
+        None
+    }
+
+    /// *Returns* the configured virtual memory overcommit policy; always `None` for this platform.
+    pub fn overcommit_memory(&self) -> Option<u8> {
+        None
+    }
+
+    /// *Returns* the configured percentage of physical RAM which may be committed when using the "never overcommit"
+    /// policy; always `None` for this platform.
+    pub fn overcommit_ratio(&self) -> Option<u32> {
+        None
+    }
+
+    /// *Returns* the configured system-wide maximum number of concurrent asynchronous I/O requests; always `None` for
+    /// this platform.
+    pub fn aio_max_nr(&self) -> Option<u64> {
+        None
+    }
+
+    /// *Returns* the configured dirty-page writeback thresholds; always `None` for this platform.
+    pub fn dirty_ratios(&self) -> Option<(u8, u8)> {
+        None
+    }
+
+    /// *Returns* whether the kernel CRNG (cryptographically secure random number generator) has been initialized; always
+    /// `None` for this platform.
+    pub fn urandom_ready(&self) -> Option<bool> {
+        None
+    }
+
+    /// *Returns* the overall CPU vulnerability mitigation state; always `None` for this platform.
+    pub fn mitigation_summary(&self) -> Option<MitigationSummary> {
+        None
+    }
+
+    /// *Returns* the estimated maximum number of threads which the current process may create; always `None` for this
+    /// platform.
+    pub fn threads_max(&self) -> Option<u64> {
+        None
+    }
+
+    /// *Returns* the names of the capabilities in the effective capability set of the current process; always `None` for
+    /// this platform.
+    pub fn capabilities(&self) -> Option<Vec<OsString>> {
+        None
+    }
+
+    /// *Returns* the application packaging sandbox in which the current process is running; always `None` for this
+    /// platform.
+    pub fn package_sandbox(&self) -> Option<OsString> {
+        None
+    }
+
+    /// *Returns* the configured maximum number of tracked network connections; always `None` for this platform.
+    pub fn nf_conntrack_max(&self) -> Option<u32> {
+        None
+    }
+
+    /// *Returns* the configured TCP connection tracking timeouts; always `None` for this platform.
+    pub fn conntrack_timeouts(&self) -> Option<std::collections::BTreeMap<String, u32>> {
+        None
+    }
+
+    /// *Returns* the system default file-creation mask; always `None` for this platform.
+    pub fn login_defs_umask(&self) -> Option<u32> {
+        None
+    }
+
+    /// *Returns* the configured socket buffer size limits; always `None` for this platform.
+    pub fn socket_buffer_limits(&self) -> Option<SocketBufferLimits> {
+        None
+    }
+
+    /// *Returns* `true` if running under WSL (Windows Subsystem for Linux); always `false` for this platform.
+    pub fn is_wsl(&self) -> bool {
+        false
+    }
+
+    /// *Returns* the major version of WSL (Windows Subsystem for Linux); always `None` for this platform.
+    pub fn wsl_version(&self) -> Option<u8> {
+        None
+    }
+
+    /// *Returns* the machine-readable OS (distribution) identifier; always `None` for this platform.
+    pub fn os_release_id(&self) -> Option<OsString> {
+        None
+    }
+
+    /// *Returns* the identifiers of the OS (distributions) from which this OS is derived; always `None` for this
+    /// platform.
+    pub fn os_release_id_like(&self) -> Option<Vec<OsString>> {
+        None
+    }
+}
+
 //===
 
 // WinApiSystemInfo
@@ -141,7 +616,12 @@ pub struct WinApiSystemInfo(
 
 // WinOsVersionInfo
 /// Contains WinOS version information as [OsString]'s; for more info, see [NT Version Info (detailed)](https://en.wikipedia.org/wiki/Comparison_of_Microsoft_Windows_versions#Windows_NT).
+///
+/// Note: this structure is `#[non_exhaustive]` (allowing additional fields to be added without a breaking change); so,
+/// outside of this crate, construct it with [`WinOsVersionInfo::new()`] and prefer the accessor methods to direct field
+/// access.
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub struct WinOsVersionInfo {
     // ref: [NT Version Info (detailed)](https://en.wikipedia.org/wiki/Comparison_of_Microsoft_Windows_versions#Windows_NT) @@ <https://archive.is/FSkhj>
     /// "Friendly" OS name (eg, "Windows 10")
@@ -150,6 +630,245 @@ pub struct WinOsVersionInfo {
     pub release: OsString,
     /// Specific OS version (eg, "19045")
     pub version: OsString,
+    /// Classified OS version (eg, [`WindowsVersion::Windows10`])
+    pub windows_version: WindowsVersion,
+}
+
+impl WinOsVersionInfo {
+    /// *Returns* a new [`WinOsVersionInfo`] with the given "friendly" OS name (eg, "Windows 10"), general/main OS
+    /// version (eg, "10.0"), and specific OS version (eg, "19045").
+    ///
+    /// The [`windows_version`](WinOsVersionInfo::windows_version) is classified from `release` and `version`, assuming a
+    /// workstation (ie, non-server) edition; any unparsable version component is treated as zero.
+    pub fn new<N, R, V>(os_name: N, release: R, version: V) -> Self
+    where
+        N: Into<OsString>,
+        R: Into<OsString>,
+        V: Into<OsString>,
+    {
+        let release = release.into();
+        let version = version.into();
+        let component =
+            |s: Option<&str>| s.and_then(|s| s.trim().parse::<DWORD>().ok()).unwrap_or(0);
+        let mut release_components = release.to_str().unwrap_or_default().splitn(2, '.');
+        let major = component(release_components.next());
+        let minor = component(release_components.next());
+        let build = component(version.to_str());
+        Self {
+            os_name: os_name.into(),
+            release,
+            version,
+            windows_version: classify_winos_version(major, minor, build, VER_NT_WORKSTATION, 0),
+        }
+    }
+
+    /// *Returns* the "friendly" OS name (eg, "Windows 10").
+    pub fn os_name(&self) -> &OsStr {
+        &self.os_name
+    }
+
+    /// *Returns* the general/main OS version (eg, "10.0").
+    pub fn release(&self) -> &OsStr {
+        &self.release
+    }
+
+    /// *Returns* the specific OS version (eg, "19045").
+    pub fn version(&self) -> &OsStr {
+        &self.version
+    }
+
+    /// *Returns* the classified OS version (eg, [`WindowsVersion::Windows10`]).
+    pub fn windows_version(&self) -> WindowsVersion {
+        self.windows_version
+    }
+}
+
+#[cfg(not(feature = "no-file-fallback"))]
+impl WinOsVersionInfo {
+    /// *Returns* the WinOS version info for which the executable or DLL file at `path` was built, as recorded in the
+    /// file's version resource (eg, `C:\Windows\System32\kernel32.dll` => "10.0" release and "19041" version).
+    ///
+    /// Note: the file version may differ from that of the running WinOS (eg, for files not updated with the OS).
+    ///
+    /// Not available with the `no-file-fallback` feature.
+    pub fn from_file<P: AsRef<std::path::Path>>(path: P) -> Result<Self, PlatformInfoError> {
+        let path = path.as_ref();
+        if path.as_os_str().is_empty() {
+            return Err(Box::new(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "empty file path",
+            )));
+        }
+        version_info_from_file(path)
+    }
+
+    /// *Returns* the WinOS version info recorded in a version-information resource (`file_version_info`; eg, from
+    /// [`WinApiFileVersionInfo::from_bytes()`]), as for [`WinOsVersionInfo::from_file()`].
+    ///
+    /// Not available with the `no-file-fallback` feature.
+    pub fn from_file_version_info(
+        file_version_info: WinApiFileVersionInfo,
+    ) -> Result<Self, PlatformInfoError> {
+        version_info_from_file_version_info(file_version_info)
+    }
+}
+
+// WindowsVersion
+/// A high-level classification of the WinOS version (and edition; ie, workstation _vs_ server); for more info, see
+/// [NT Version Info (detailed)](https://en.wikipedia.org/wiki/Comparison_of_Microsoft_Windows_versions#Windows_NT).
+///
+/// The [`Display`](std::fmt::Display) form is the "friendly" OS name (eg, "Windows Server 2019").
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum WindowsVersion {
+    /// Windows 2000 (5.0)
+    Windows2000,
+    /// Windows XP (5.1)
+    WindowsXp,
+    /// Windows XP Professional x64 Edition (5.2, workstation)
+    WindowsXpProfessionalX64,
+    /// Windows Home Server (5.2, server)
+    WindowsHomeServer,
+    /// Windows Server 2003 (5.2, server)
+    Server2003,
+    /// Windows Vista (6.0, workstation)
+    WindowsVista,
+    /// Windows Server 2008 (6.0, server)
+    Server2008,
+    /// Windows 7 (6.1, workstation)
+    Windows7,
+    /// Windows Server 2008 R2 (6.1, server)
+    Server2008R2,
+    /// Windows 8 (6.2, workstation)
+    Windows8,
+    /// Windows Server 2012 (6.2, server)
+    Server2012,
+    /// Windows 8.1 (6.3, workstation)
+    Windows8_1,
+    /// Windows Server 2012 R2 (6.3, server)
+    Server2012R2,
+    /// Windows 10 (10.0, workstation builds before 22000)
+    Windows10,
+    /// Windows 11 (10.0, workstation builds from 22000)
+    Windows11,
+    /// Windows Server 2016 (10.0, server builds 14000 to 16999)
+    Server2016,
+    /// Windows Server 2019 (10.0, server builds 17000 to 18999)
+    Server2019,
+    /// Windows Server 2022 (10.0, server builds from 20000)
+    Server2022,
+    /// Any other (eg, older or newer) version
+    Other {
+        /// Major version (eg, 6)
+        major: u32,
+        /// Minor version (eg, 4)
+        minor: u32,
+        /// Build number (eg, 9841)
+        build: u32,
+    },
+}
+
+impl std::fmt::Display for WindowsVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Windows2000 => "Windows 2000",
+            Self::WindowsXp => "Windows XP",
+            Self::WindowsXpProfessionalX64 => "Windows XP Professional x64 Edition",
+            Self::WindowsHomeServer => "Windows Home Server",
+            Self::Server2003 => "Windows Server 2003",
+            Self::WindowsVista => "Windows Vista",
+            Self::Server2008 => "Windows Server 2008",
+            Self::Windows7 => "Windows 7",
+            Self::Server2008R2 => "Windows Server 2008 R2",
+            Self::Windows8 => "Windows 8",
+            Self::Server2012 => "Windows Server 2012",
+            Self::Windows8_1 => "Windows 8.1",
+            Self::Server2012R2 => "Windows Server 2012 R2",
+            Self::Windows10 => "Windows 10",
+            Self::Windows11 => "Windows 11",
+            Self::Server2016 => "Windows Server 2016",
+            Self::Server2019 => "Windows Server 2019",
+            Self::Server2022 => "Windows Server 2022",
+            Self::Other { major, minor, .. } => return write!(f, "Windows {}.{}", major, minor),
+        };
+        f.write_str(name)
+    }
+}
+
+// ProcessorArchitecture
+/// The processor architecture of a WinOS system, decoded from the raw `SYSTEM_INFO.wProcessorArchitecture` value (see
+/// [`WinApiSystemInfo::processor_architecture()`]).
+// ref: [SYSTEM_INFO structure](https://learn.microsoft.com/en-us/windows/win32/api/sysinfoapi/ns-sysinfoapi-system_info) @@ <https://archive.is/cqbrj>
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ProcessorArchitecture {
+    /// x64 (AMD or Intel) (`PROCESSOR_ARCHITECTURE_AMD64`)
+    Amd64,
+    /// x86 (`PROCESSOR_ARCHITECTURE_INTEL`)
+    Intel,
+    /// ARM (`PROCESSOR_ARCHITECTURE_ARM`)
+    Arm,
+    /// ARM64 (`PROCESSOR_ARCHITECTURE_ARM64`)
+    Arm64,
+    /// Intel Itanium-based (`PROCESSOR_ARCHITECTURE_IA64`)
+    Ia64,
+    /// MIPS (`PROCESSOR_ARCHITECTURE_MIPS`)
+    Mips,
+    /// PowerPC (`PROCESSOR_ARCHITECTURE_PPC`)
+    Ppc,
+    /// Alpha (`PROCESSOR_ARCHITECTURE_ALPHA`)
+    Alpha,
+    /// Alpha64 (`PROCESSOR_ARCHITECTURE_ALPHA64`)
+    Alpha64,
+    /// SuperH (`PROCESSOR_ARCHITECTURE_SHX`)
+    SuperH,
+    /// Any other (or unknown) architecture, holding the raw value (eg, `PROCESSOR_ARCHITECTURE_UNKNOWN`)
+    Unknown(u16),
+}
+
+impl From<WORD> for ProcessorArchitecture {
+    fn from(arch: WORD) -> Self {
+        match arch {
+            PROCESSOR_ARCHITECTURE_AMD64 => Self::Amd64,
+            PROCESSOR_ARCHITECTURE_INTEL => Self::Intel,
+            PROCESSOR_ARCHITECTURE_ARM => Self::Arm,
+            PROCESSOR_ARCHITECTURE_ARM64 => Self::Arm64,
+            PROCESSOR_ARCHITECTURE_IA64 => Self::Ia64,
+            PROCESSOR_ARCHITECTURE_MIPS => Self::Mips,
+            PROCESSOR_ARCHITECTURE_PPC => Self::Ppc,
+            PROCESSOR_ARCHITECTURE_ALPHA => Self::Alpha,
+            PROCESSOR_ARCHITECTURE_ALPHA64 => Self::Alpha64,
+            PROCESSOR_ARCHITECTURE_SHX => Self::SuperH,
+            _ => Self::Unknown(arch),
+        }
+    }
+}
+
+impl WinApiSystemInfo {
+    /// *Returns* a new [`WinApiSystemInfo`] with the given processor architecture (a raw `PROCESSOR_ARCHITECTURE_*`
+    /// value), processor level, page size, and number of processors; all other `SYSTEM_INFO` members are zeroed.
+    ///
+    /// Useful for exercising architecture-dependent logic with known values (ie, without a live system).
+    pub fn from_parts(
+        processor_architecture: WORD,
+        processor_level: WORD,
+        page_size: DWORD,
+        number_of_processors: DWORD,
+    ) -> Self {
+        // SAFETY: `SYSTEM_INFO` is a plain C structure (integers and raw pointers), for which all-zero is a valid value
+        let mut system_info: SYSTEM_INFO = unsafe { std::mem::zeroed() };
+        // SAFETY: the union is only ever interpreted via its `wProcessorArchitecture`/`wReserved` struct variant
+        unsafe { system_info.u.s_mut().wProcessorArchitecture = processor_architecture };
+        system_info.wProcessorLevel = processor_level;
+        system_info.dwPageSize = page_size;
+        system_info.dwNumberOfProcessors = number_of_processors;
+        Self(system_info)
+    }
+
+    /// *Returns* the processor architecture, decoded from the raw
+    /// [`wProcessorArchitecture()`](WinApiSystemInfo::wProcessorArchitecture) value.
+    pub fn processor_architecture(&self) -> ProcessorArchitecture {
+        ProcessorArchitecture::from(self.wProcessorArchitecture())
+    }
 }
 
 //===
@@ -157,6 +876,7 @@ pub struct WinOsVersionInfo {
 pub mod util {
     use std::ffi::CString;
     use std::ffi::OsStr;
+    use std::io;
     use std::os::windows::ffi::OsStrExt;
 
     use winapi::um::winnt::*;
@@ -174,6 +894,7 @@ pub mod util {
     /// Convert the leading non-NUL content of any string (which is cheaply convertible to an OsStr) into a CString, without error.
     ///
     /// Any non-Unicode sequences are replaced with [U+FFFD (REPLACEMENT CHARACTER)](https://en.wikipedia.org/wiki/Specials_(Unicode_block)).
+    #[allow(dead_code)] // * retained for display contexts; internal callers use `try_to_c_string()`
     pub fn to_c_string<S: AsRef<OsStr>>(os_str: S) -> CString {
         let nul = '\0';
         let s = os_str.as_ref().to_string_lossy();
@@ -196,12 +917,42 @@ pub mod util {
         assert!(index_first_nul < wstring.len()); //* failure here == algorithmic/logic error => panic
         CWSTR::from(&wstring[..(index_first_nul + 1)])
     }
+
+    // try_to_c_string()
+    /// Convert any string (which is cheaply convertible to an OsStr) into a CString.
+    ///
+    /// *Returns* an `InvalidInput` error if the string contains an interior NUL (instead of truncating, as does
+    /// [`to_c_string()`]). Any non-Unicode sequences are replaced with
+    /// [U+FFFD (REPLACEMENT CHARACTER)](https://en.wikipedia.org/wiki/Specials_(Unicode_block)).
+    pub fn try_to_c_string<S: AsRef<OsStr>>(os_str: S) -> Result<CString, io::Error> {
+        CString::new(os_str.as_ref().to_string_lossy().as_bytes())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
+    }
+
+    // try_to_c_wstring()
+    /// Convert any string (which is cheaply convertible to an OsStr) into a CWSTR.
+    ///
+    /// *Returns* an `InvalidInput` error if the string contains an interior NUL (instead of truncating, as does
+    /// [`to_c_wstring()`]).
+    pub fn try_to_c_wstring<S: AsRef<OsStr>>(os_str: S) -> Result<CWSTR, io::Error> {
+        let nul: WCHAR = 0;
+        let mut wstring: WSTR = os_str.as_ref().encode_wide().collect();
+        if wstring.contains(&nul) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "string contains an interior NUL",
+            ));
+        }
+        wstring.push(nul);
+        Ok(wstring)
+    }
 }
 
 //===
 
 // MmbrVersion
 /// Contains a version specification as major, minor, build, and revision DWORDs (ie, from *major*.*minor*.*build*.*release* version style).
+#[cfg(not(feature = "no-file-fallback"))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 struct MmbrVersion {
     major: DWORD,
@@ -214,11 +965,33 @@ struct MmbrVersion {
 /// Contains file version info (`VS_VERSIONINFO`) wrapped as a byte vector (`data`).
 ///
 /// Wraps [VS_VERSIONINFO](https://learn.microsoft.com/en-us/windows/win32/menurc/vs-versioninfo).
+#[cfg(not(feature = "no-file-fallback"))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct WinApiFileVersionInfo {
     data: Vec<BYTE>,
 }
 
+#[cfg(not(feature = "no-file-fallback"))]
+impl WinApiFileVersionInfo {
+    /// *Returns* the file version info contained in `data`, a caller-supplied version-information resource (eg, as read
+    /// from the resources of an executable or DLL file), or an error if `data` doesn't begin with a well-formed
+    /// `VS_VERSIONINFO` header containing a root `VS_FIXEDFILEINFO` structure.
+    ///
+    /// Allows version info which is already in memory to be decoded (see
+    /// [`WinOsVersionInfo::from_file_version_info()`]) without any file access.
+    ///
+    /// Not available with the `no-file-fallback` feature.
+    pub fn from_bytes(data: Vec<u8>) -> Result<Self, PlatformInfoError> {
+        if !is_valid_version_info_root(&data) {
+            return Err(Box::new(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "invalid VS_VERSIONINFO structure",
+            )));
+        }
+        Ok(Self { data })
+    }
+}
+
 //===
 
 impl Debug for WinApiSystemInfo {
@@ -274,100 +1047,355 @@ impl PartialEq for WinApiSystemInfo {
 
 impl Eq for WinApiSystemInfo {}
 
+// SAFETY: the only non-`Send`/non-`Sync` members of `SYSTEM_INFO` are the application address range limits, which are
+// raw pointers used purely as (informational) address values; they are never dereferenced
+unsafe impl Send for WinApiSystemInfo {}
+unsafe impl Sync for WinApiSystemInfo {}
+
 //===
 
 // WinOSGetComputerName
-/// *Returns* a NetBIOS or DNS name associated with the local computer.
+/// *Returns* a NetBIOS or DNS name associated with the local computer (see [`computer_name_with_fallback()`]).
 #[allow(non_snake_case)]
 fn WinOsGetComputerName() -> Result<OsString, WinOSError> {
+    computer_name_with_fallback(WinOsGetComputerNameEx)
+}
+
+// WinOsGetComputerNameEx
+/// *Returns* the name, of type `name_type` (eg, `ComputerNameNetBIOS`), associated with the local computer.
+#[allow(non_snake_case)]
+fn WinOsGetComputerNameEx(name_type: COMPUTER_NAME_FORMAT) -> Result<OsString, WinOSError> {
+    let mut size: DWORD = 0;
+    let _ = WinAPI_GetComputerNameExW(name_type, None, &mut size);
+    let mut data = vec![0; usize::try_from(size)?];
+    let result = WinAPI_GetComputerNameExW(name_type, &mut data, &mut size);
+    if result == FALSE {
+        return Err(Box::new(io::Error::last_os_error()));
+    }
+    Ok(OsString::from_wide(&data[..usize::try_from(size)?]))
+}
+
+// computer_name_with_fallback
+/// *Returns* the first non-empty computer name fetched (via `get_name`) for each name type in turn (the physical DNS host
+/// name, then the NetBIOS name), or "localhost" if no name type yields a non-empty name.
+///
+/// An error is returned only if every fetch fails.
+fn computer_name_with_fallback<F>(get_name: F) -> Result<OsString, WinOSError>
+where
+    F: Fn(COMPUTER_NAME_FORMAT) -> Result<OsString, WinOSError>,
+{
     //## NameType ~ using "ComputerNameDnsHostname" vs "ComputerNamePhysicalDnsHostname"
     // * "ComputerNamePhysicalDnsHostname" *may* have a different (more specific) name when in a DNS cluster
     // * `uname -n` may show the more specific cluster name (see https://clusterlabs.org/pacemaker/doc/deprecated/en-US/Pacemaker/1.1/html/Clusters_from_Scratch/_short_node_names.html)
     // * under Linux/Wine, they are *exactly* the same ([from Wine patches msgs](https://www.winehq.org/pipermail/wine-patches/2002-November/004080.html))
     // * probably want the more specific in-cluster name, but, functionally, any difference will be very rare
+    // * an empty name (eg, within some sandboxes) falls back to the NetBIOS name
     // ref: [COMPUTER_NAME_FORMAT](https://learn.microsoft.com/en-us/windows/win32/api/sysinfoapi/ne-sysinfoapi-computer_name_format) @@ <https://archive.is/s18y0>
-    let name_type = ComputerNamePhysicalDnsHostname; // or ComputerNameDnsHostname
+    let name_types = [ComputerNamePhysicalDnsHostname, ComputerNameNetBIOS];
+
+    let mut error = None;
+    let mut is_any_success = false;
+    for &name_type in &name_types {
+        match get_name(name_type) {
+            Ok(name) if !name.is_empty() => {
+                #[cfg(feature = "tracing")]
+                tracing::trace!(name_type, ?name, "computer name obtained");
+                return Ok(name);
+            }
+            Ok(_) => {
+                #[cfg(feature = "tracing")]
+                tracing::debug!(name_type, "computer name is empty");
+                is_any_success = true;
+            }
+            Err(e) => {
+                #[cfg(feature = "tracing")]
+                tracing::debug!(name_type, error = %e, "computer name retrieval failed");
+                error = Some(e);
+            }
+        }
+    }
+    match error {
+        Some(e) if !is_any_success => Err(e),
+        _ => {
+            #[cfg(feature = "tracing")]
+            tracing::debug!("no computer name available; falling back to \"localhost\"");
+            Ok(OsString::from("localhost"))
+        }
+    }
+}
 
-    let mut size: DWORD = 0;
-    let _ = WinAPI_GetComputerNameExW(name_type, None, &mut size);
-    let mut data = vec![0; usize::try_from(size)?];
-    let result = WinAPI_GetComputerNameExW(name_type, &mut data, &mut size);
+// WinOsGetProcessorCoreInformation
+/// *Returns* the processor core information, as a series of `SYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX` structures (see
+/// [`processor_core_counts()`]).
+#[allow(non_snake_case)]
+fn WinOsGetProcessorCoreInformation() -> Result<Vec<BYTE>, WinOSError> {
+    let mut length: DWORD = 0;
+    let _ = WinAPI_GetLogicalProcessorInformationEx(RelationProcessorCore, None, &mut length);
+    let mut data = vec![0; usize::try_from(length)?];
+    let result =
+        WinAPI_GetLogicalProcessorInformationEx(RelationProcessorCore, &mut data, &mut length);
     if result == FALSE {
         return Err(Box::new(io::Error::last_os_error()));
     }
-    Ok(OsString::from_wide(&data[..usize::try_from(size)?]))
+    data.truncate(usize::try_from(length)?);
+    Ok(data)
 }
 
 // WinOsGetFileVersionInfo
 /// *Returns* the file version information block for the specified file (`file_path`).
+#[cfg(not(feature = "no-file-fallback"))]
 #[allow(non_snake_case)]
 fn WinOsGetFileVersionInfo<P: AsRef<PathStr>>(
     file_path: P,
 ) -> Result<WinApiFileVersionInfo, WinOSError> {
-    let file_version_size = WinAPI_GetFileVersionInfoSizeW(&file_path);
+    let file_version_size = WinAPI_GetFileVersionInfoSizeW(&file_path)?;
     if file_version_size == 0 {
         return Err(Box::new(io::Error::last_os_error()));
     }
     let mut data: Vec<BYTE> = vec![0; usize::try_from(file_version_size)?];
-    let result = WinAPI_GetFileVersionInfoW(&file_path, &mut data);
+    let result = WinAPI_GetFileVersionInfoW(&file_path, &mut data)?;
     if result == FALSE {
         return Err(Box::new(io::Error::last_os_error()));
     }
     Ok(WinApiFileVersionInfo { data })
 }
 
-// WinOSGetSystemDirectory
-/// *Returns* a resolved path to the Windows System Directory (aka `%SystemRoot%`).
+// WinOsGetFidoDeviceNames
+/// *Returns* the descriptions of the present HID devices which use the FIDO Alliance usage page (ie, FIDO2/U2F security
+/// keys).
 #[allow(non_snake_case)]
-fn WinOsGetSystemDirectory() -> Result<PathString, WinOSError> {
-    let required_capacity: UINT = WinAPI_GetSystemDirectoryW(None);
-    let mut data = vec![0; usize::try_from(required_capacity)?];
-    let result = WinAPI_GetSystemDirectoryW(&mut data);
-    if result == 0 {
+fn WinOsGetFidoDeviceNames() -> Result<Vec<OsString>, WinOSError> {
+    let mut device_info_data = create_SP_DEVINFO_DATA()?;
+    let device_info_set = WinAPI_SetupDiGetClassDevsW("HID", DIGCF_ALLCLASSES | DIGCF_PRESENT);
+    if device_info_set == INVALID_HANDLE_VALUE {
         return Err(Box::new(io::Error::last_os_error()));
     }
-    let path = PathString::from(OsString::from_wide(&data[..usize::try_from(result)?]));
-    Ok(path)
-}
-
-//===
 
-// os_version_info
-/// *Returns* OS version info (as [`WinOsVersionInfo`]) using a DLL procedure call, with fallback version info from a
-/// known system file.
-///
-/// This call and fallback recipe is necessary because Microsoft deprecated the previously used `GetVersionEx()`, making
-/// it useless for Windows 8.1 and later windows versions.
-// ref: <https://learn.microsoft.com/en-us/windows/win32/api/sysinfoapi/nf-sysinfoapi-getversionexw> @@ <https://archive.is/bYAwT>
-// ref: <https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-osversioninfoexw> @@ <https://archive.is/n4hBb>
-fn os_version_info() -> Result<WinOsVersionInfo, WinOSError> {
-    match os_version_info_from_dll() {
-        Ok(os_info) => Ok(os_info),
-        Err(_) => {
-            // as a last resort, try to get the relevant info by loading the version info from a system file
-            // Note: this file version may be just the current "base" version and not the actual most up-to-date version info
-            // * eg: kernel32.dll (or ntdll.dll) version => "10.0.19041.2130" _vs_ `cmd /c ver` => "10.0.19044.2364"
-            version_info_from_file("" /* use default file */)
-            // .or. `return version_info_from_file::<_, &str>(None /* use default file */);`
+    let mut names = Vec::new();
+    let mut index = 0;
+    while WinAPI_SetupDiEnumDeviceInfo(device_info_set, index, &mut device_info_data) != FALSE {
+        index += 1;
+        let property = |device_info_data: &mut SP_DEVINFO_DATA, property| {
+            WinOsSetupDiGetDeviceStringProperty(device_info_set, device_info_data, property)
+        };
+        let is_fido = property(&mut device_info_data, SPDRP_HARDWAREID)
+            .is_ok_and(|hardware_ids| is_fido_hid_hardware_ids(&hardware_ids.to_string_lossy()));
+        if !is_fido {
+            continue;
+        }
+        let name = property(&mut device_info_data, SPDRP_FRIENDLYNAME)
+            .or_else(|_| property(&mut device_info_data, SPDRP_DEVICEDESC));
+        if let Ok(name) = name {
+            names.push(name);
         }
     }
+    let _ = WinAPI_SetupDiDestroyDeviceInfoList(device_info_set);
+    Ok(names)
+}
+
+// WinOsSetupDiGetDeviceStringProperty
+/// *Returns* the string (or multi-string, as NUL-separated strings) data of the specified PnP device `property`.
+#[allow(non_snake_case)]
+fn WinOsSetupDiGetDeviceStringProperty(
+    device_info_set: HDEVINFO,
+    device_info_data: &mut SP_DEVINFO_DATA,
+    property: DWORD,
+) -> Result<OsString, WinOSError> {
+    let mut size: DWORD = 0;
+    let _ = WinAPI_SetupDiGetDeviceRegistryPropertyW(
+        device_info_set,
+        device_info_data,
+        property,
+        None,
+        &mut size,
+    );
+    if size == 0 {
+        return Err(Box::new(io::Error::last_os_error()));
+    }
+    let mut data = vec![0; usize::try_from(size)? / std::mem::size_of::<WCHAR>()];
+    let result = WinAPI_SetupDiGetDeviceRegistryPropertyW(
+        device_info_set,
+        device_info_data,
+        property,
+        &mut data,
+        &mut size,
+    );
+    if result == FALSE {
+        return Err(Box::new(io::Error::last_os_error()));
+    }
+    data.truncate(usize::try_from(size)? / std::mem::size_of::<WCHAR>());
+    // * remove the terminating NUL(s)
+    while data.last() == Some(&0) {
+        let _ = data.pop();
+    }
+    Ok(OsString::from_wide(&data))
+}
+
+// WinOsGetSystemFirmwareTable
+/// *Returns* the specified raw firmware table (`provider`/`table_id`).
+#[allow(non_snake_case)]
+fn WinOsGetSystemFirmwareTable(provider: DWORD, table_id: DWORD) -> Result<Vec<BYTE>, WinOSError> {
+    let required_size = WinAPI_GetSystemFirmwareTable(provider, table_id, None);
+    if required_size == 0 {
+        return Err(Box::new(io::Error::last_os_error()));
+    }
+    let mut data = vec![0; usize::try_from(required_size)?];
+    let result = WinAPI_GetSystemFirmwareTable(provider, table_id, &mut data);
+    if result == 0 || result > required_size {
+        return Err(Box::new(io::Error::last_os_error()));
+    }
+    data.truncate(usize::try_from(result)?);
+    Ok(data)
+}
+
+// WinOsGetVolumeFileSystemName
+/// *Returns* the file system name (eg, "NTFS") of the volume with the specified root directory (`root_path_name`).
+#[allow(non_snake_case)]
+fn WinOsGetVolumeFileSystemName<P: AsRef<PathStr>>(
+    root_path_name: P,
+) -> Result<OsString, WinOSError> {
+    let mut data = vec![0; MAX_PATH + 1];
+    if WinAPI_GetVolumeInformationW(root_path_name, &mut data)? == FALSE {
+        return Err(Box::new(io::Error::last_os_error()));
+    }
+    let length = data.iter().position(|&c| c == 0).unwrap_or(data.len());
+    Ok(OsString::from_wide(&data[..length]))
+}
+
+// WinOsRegGetStringValue
+/// *Returns* the string data of the specified registry value (`value_name`) within the registry key `key`/`sub_key`
+/// (eg, `HKEY_LOCAL_MACHINE` and `HARDWARE\DESCRIPTION\System\BIOS`).
+#[allow(non_snake_case)]
+fn WinOsRegGetStringValue<P: AsRef<PathStr>, S: AsRef<PathStr>>(
+    key: HKEY,
+    sub_key: P,
+    value_name: S,
+) -> Result<OsString, WinOSError> {
+    let flags = RRF_RT_REG_SZ;
+
+    let mut size: DWORD = 0;
+    let result = WinAPI_RegGetValueW(key, &sub_key, &value_name, flags, None, &mut size);
+    if result != ERROR_SUCCESS as LSTATUS {
+        return Err(Box::new(io::Error::from_raw_os_error(result)));
+    }
+    let mut data = vec![0; usize::try_from(size)? / std::mem::size_of::<WCHAR>()];
+    let result = WinAPI_RegGetValueW(key, &sub_key, &value_name, flags, &mut data, &mut size);
+    if result != ERROR_SUCCESS as LSTATUS {
+        return Err(Box::new(io::Error::from_raw_os_error(result)));
+    }
+    // * data size (in bytes) includes the terminating NUL
+    let length = (usize::try_from(size)? / std::mem::size_of::<WCHAR>()).saturating_sub(1);
+    Ok(OsString::from_wide(&data[..length]))
+}
+
+// WinOsRegGetDwordValue
+/// *Returns* the DWORD data of the specified registry value (`value_name`) within the registry key `key`/`sub_key`.
+#[allow(non_snake_case)]
+fn WinOsRegGetDwordValue<P: AsRef<PathStr>, S: AsRef<PathStr>>(
+    key: HKEY,
+    sub_key: P,
+    value_name: S,
+) -> Result<DWORD, WinOSError> {
+    // * a DWORD is the size of two WCHARs
+    let mut data: Vec<WCHAR> = vec![0; std::mem::size_of::<DWORD>() / std::mem::size_of::<WCHAR>()];
+    let mut size: DWORD = 0;
+    let result = WinAPI_RegGetValueW(
+        key,
+        &sub_key,
+        &value_name,
+        RRF_RT_REG_DWORD,
+        &mut data,
+        &mut size,
+    );
+    if result != ERROR_SUCCESS as LSTATUS {
+        return Err(Box::new(io::Error::from_raw_os_error(result)));
+    }
+    // * registry DWORD values are stored little-endian
+    Ok(DWORD::from(data[0]) | (DWORD::from(data[1]) << 16))
+}
+
+// WinOSGetSystemDirectory
+/// *Returns* a resolved path to the Windows System Directory (aka `%SystemRoot%`).
+#[allow(non_snake_case)]
+fn WinOsGetSystemDirectory() -> Result<PathString, WinOSError> {
+    let required_capacity: UINT = WinAPI_GetSystemDirectoryW(None);
+    let mut data = vec![0; usize::try_from(required_capacity)?];
+    let result = WinAPI_GetSystemDirectoryW(&mut data);
+    if result == 0 {
+        return Err(Box::new(io::Error::last_os_error()));
+    }
+    let path = PathString::from(OsString::from_wide(&data[..usize::try_from(result)?]));
+    Ok(path)
+}
+
+//===
+
+// os_version_info
+/// *Returns* OS version info (as [`WinOsVersionInfo`]) using a DLL procedure call, with fallback version info from a
+/// known system file.
+///
+/// This call and fallback recipe is necessary because Microsoft deprecated the previously used `GetVersionEx()`, making
+/// it useless for Windows 8.1 and later windows versions.
+// ref: <https://learn.microsoft.com/en-us/windows/win32/api/sysinfoapi/nf-sysinfoapi-getversionexw> @@ <https://archive.is/bYAwT>
+// ref: <https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-osversioninfoexw> @@ <https://archive.is/n4hBb>
+#[cfg(not(feature = "no-file-fallback"))]
+fn os_version_info() -> Result<WinOsVersionInfo, WinOSError> {
+    match os_version_info_from_dll() {
+        Ok(os_info) => {
+            #[cfg(feature = "tracing")]
+            tracing::debug!(?os_info, "version info obtained via RtlGetVersion()");
+            Ok(os_info)
+        }
+        Err(_error) => {
+            #[cfg(feature = "tracing")]
+            tracing::debug!(error = %_error, "RtlGetVersion() failed; falling back to file version info");
+            // as a last resort, try to get the relevant info by loading the version info from a system file
+            // Note: this file version may be just the current "base" version and not the actual most up-to-date version info
+            // * eg: kernel32.dll (or ntdll.dll) version => "10.0.19041.2130" _vs_ `cmd /c ver` => "10.0.19044.2364"
+            let result = version_info_from_file("" /* use default file */);
+            // .or. `return version_info_from_file::<_, &str>(None /* use default file */);`
+            #[cfg(feature = "tracing")]
+            match &result {
+                Ok(os_info) => {
+                    tracing::debug!(?os_info, "version info obtained via file version info")
+                }
+                Err(error) => tracing::debug!(%error, "file version info fallback failed"),
+            }
+            result
+        }
+    }
+}
+
+// os_version_info
+/// *Returns* OS version info (as [`WinOsVersionInfo`]) using a DLL procedure call, *without* any file-based fallback.
+///
+/// Selected by the `no-file-fallback` feature; any failure of the DLL procedure call is returned directly.
+#[cfg(feature = "no-file-fallback")]
+fn os_version_info() -> Result<WinOsVersionInfo, WinOSError> {
+    let result = os_version_info_from_dll();
+    #[cfg(feature = "tracing")]
+    match &result {
+        Ok(os_info) => tracing::debug!(?os_info, "version info obtained via RtlGetVersion()"),
+        Err(error) => tracing::debug!(%error, "RtlGetVersion() failed (no file fallback)"),
+    }
+    result
 }
 
 // os_version_info_from_dll
 /// *Returns* version info (as [`WinOsVersionInfo`]) obtained via `NTDLL/RtlGetVersion()`.
 fn os_version_info_from_dll() -> Result<WinOsVersionInfo, WinOSError> {
     let os_info = NTDLL_RtlGetVersion()?;
+    let (major, minor, build) = (
+        os_info.dwMajorVersion,
+        os_info.dwMinorVersion,
+        os_info.dwBuildNumber,
+    );
+    let (product_type, suite_mask) = (os_info.wProductType, os_info.wSuiteMask.into());
     Ok(WinOsVersionInfo {
-        os_name: winos_name(
-            os_info.dwMajorVersion,
-            os_info.dwMinorVersion,
-            os_info.dwBuildNumber,
-            os_info.wProductType,
-            os_info.wSuiteMask.into(),
-        )
-        .into(),
-        release: format!("{}.{}", os_info.dwMajorVersion, os_info.dwMinorVersion).into(),
-        version: format!("{}", os_info.dwBuildNumber).into(),
+        os_name: winos_name(major, minor, build, product_type, suite_mask).into(),
+        release: format!("{}.{}", major, minor).into(),
+        version: format!("{}", build).into(),
+        windows_version: classify_winos_version(major, minor, build, product_type, suite_mask),
     })
 }
 
@@ -375,6 +1403,7 @@ fn os_version_info_from_dll() -> Result<WinOsVersionInfo, WinOSError> {
 /// *Returns* version info (as [`WinOsVersionInfo`]) obtained from `file_path`.
 ///
 /// `file_path` ~ if empty or `None`, default to the full path of "kernel32.dll" (a known, omnipresent, system file)
+#[cfg(not(feature = "no-file-fallback"))]
 fn version_info_from_file<I, P>(file_path: I) -> Result<WinOsVersionInfo, WinOSError>
 where
     I: Into<Option<P>>,
@@ -384,8 +1413,15 @@ where
         Some(ref p) if !p.as_ref().as_os_str().is_empty() => p.as_ref().into(),
         _ => WinOsGetSystemDirectory()?.join("kernel32.dll"),
     };
-    let file_info = WinOsGetFileVersionInfo(file_path)?;
+    version_info_from_file_version_info(WinOsGetFileVersionInfo(file_path)?)
+}
 
+// version_info_from_file_version_info
+/// *Returns* version info (as [`WinOsVersionInfo`]) obtained from the version-information resource `file_info`.
+#[cfg(not(feature = "no-file-fallback"))]
+fn version_info_from_file_version_info(
+    file_info: WinApiFileVersionInfo,
+) -> Result<WinOsVersionInfo, WinOSError> {
     let v = mmbr_from_file_version(file_info)?;
 
     let mut info = create_OSVERSIONINFOEXW()?;
@@ -410,81 +1446,318 @@ where
         os_name: winos_name(v.major, v.minor, v.build, product_type, suite_mask).into(),
         release: format!("{}.{}", v.major, v.minor).into(),
         version: format!("{}", v.build).into(),
+        windows_version: classify_winos_version(
+            v.major,
+            v.minor,
+            v.build,
+            product_type,
+            suite_mask,
+        ),
     })
 }
 
 // mmbr_from_file_version
 /// *Returns* version (as an [`MmbrVersion`]) copied from a view (aka slice) into the supplied `file_version_info`.
+#[cfg(not(feature = "no-file-fallback"))]
 fn mmbr_from_file_version(
     file_version_info: WinApiFileVersionInfo,
 ) -> Result<MmbrVersion, WinOSError> {
     let info = WinOsFileVersionInfoQuery_root(&file_version_info)?;
-    Ok(MmbrVersion {
-        major: DWORD::from(HIWORD(info.dwProductVersionMS)),
-        minor: DWORD::from(LOWORD(info.dwProductVersionMS)),
-        build: DWORD::from(HIWORD(info.dwProductVersionLS)),
-        release: DWORD::from(LOWORD(info.dwProductVersionLS)),
+    Ok(mmbr_from_fixed_file_info(&info))
+}
+
+// is_valid_version_info_root
+/// *Returns* `true` if `data` begins with a well-formed `VS_VERSIONINFO` header (ie, of a version-information resource)
+/// whose value is a root `VS_FIXEDFILEINFO` structure.
+///
+/// Checks all structure metadata used by the root query of [`WinOsFileVersionInfoQuery_root()`], so that the query
+/// can't reach past the end of `data`.
+// ref: [VS_VERSIONINFO](https://learn.microsoft.com/en-us/windows/win32/menurc/vs-versioninfo)
+#[cfg(not(feature = "no-file-fallback"))]
+fn is_valid_version_info_root(data: &[u8]) -> bool {
+    const KEY_OFFSET: usize = 6; // * following the `wLength`, `wValueLength`, and `wType` WORDs
+    const VALUE_OFFSET: usize = 40; // * following the NUL-terminated `szKey`, padded to a 32-bit boundary
+    const SIGNATURE: DWORD = 0xFEEF_04BD; // `VS_FIXEDFILEINFO.dwSignature`
+    let fixed_file_info_size = std::mem::size_of::<VS_FIXEDFILEINFO>();
+    let key: Vec<u8> = "VS_VERSION_INFO\0"
+        .encode_utf16()
+        .flat_map(|c| c.to_le_bytes())
+        .collect();
+    let word = |offset: usize| {
+        data.get(offset..offset + 2)
+            .map(|b| usize::from(u16::from_le_bytes([b[0], b[1]])))
+    };
+    match (word(0), word(2), word(4)) {
+        (Some(length), Some(value_length), Some(value_type)) => {
+            (VALUE_OFFSET + fixed_file_info_size..=data.len()).contains(&length)
+                && value_length == fixed_file_info_size
+                && value_type == 0 /* binary data */
+                && data.get(KEY_OFFSET..KEY_OFFSET + key.len()) == Some(&key[..])
+                && data.get(VALUE_OFFSET..VALUE_OFFSET + 4) == Some(&SIGNATURE.to_le_bytes()[..])
+        }
+        _ => false,
+    }
+}
+
+// mmbr_from_fixed_file_info
+/// *Returns* the product version (as an [`MmbrVersion`]) contained in `info`.
+///
+/// Each version DWORD holds two 16-bit components, the more significant in the high-order word (eg,
+/// `dwProductVersionMS == 0x000A_0000` => "10.0"); extracted arithmetically, independent of byte order.
+#[cfg(not(feature = "no-file-fallback"))]
+fn mmbr_from_fixed_file_info(info: &VS_FIXEDFILEINFO) -> MmbrVersion {
+    let high_word = |dword: DWORD| dword >> 16;
+    let low_word = |dword: DWORD| dword & 0xffff;
+    MmbrVersion {
+        major: high_word(info.dwProductVersionMS),
+        minor: low_word(info.dwProductVersionMS),
+        build: high_word(info.dwProductVersionLS),
+        release: low_word(info.dwProductVersionLS),
+    }
+}
+
+// winos_proxy
+/// *Returns* the WinINET (ie, "Internet Options") proxy server for `scheme` (eg, "http" or "https"), if a proxy is
+/// enabled.
+pub(crate) fn winos_proxy(scheme: &str) -> Option<OsString> {
+    let settings_key = r"Software\Microsoft\Windows\CurrentVersion\Internet Settings";
+    let enabled = WinOsRegGetDwordValue(HKEY_CURRENT_USER, settings_key, "ProxyEnable").ok()?;
+    if enabled == 0 {
+        return None;
+    }
+    let server = WinOsRegGetStringValue(HKEY_CURRENT_USER, settings_key, "ProxyServer").ok()?;
+    proxy_for_scheme(&server.to_string_lossy(), scheme).map(OsString::from)
+}
+
+// winos_no_proxy
+/// *Returns* the WinINET (ie, "Internet Options") proxy bypass list (in `NO_PROXY` comma-separated form), if a proxy is
+/// enabled.
+pub(crate) fn winos_no_proxy() -> Option<OsString> {
+    let settings_key = r"Software\Microsoft\Windows\CurrentVersion\Internet Settings";
+    let enabled = WinOsRegGetDwordValue(HKEY_CURRENT_USER, settings_key, "ProxyEnable").ok()?;
+    if enabled == 0 {
+        return None;
+    }
+    let bypass = WinOsRegGetStringValue(HKEY_CURRENT_USER, settings_key, "ProxyOverride").ok()?;
+    Some(OsString::from(bypass.to_string_lossy().replace(';', ",")))
+}
+
+// proxy_for_scheme
+/// *Returns* the proxy server for `scheme` from a WinINET `ProxyServer` setting (`server`; eg, "proxy:8080" or
+/// "http=proxy:8080;https=proxy:8443").
+fn proxy_for_scheme(server: &str, scheme: &str) -> Option<String> {
+    // ref: <https://learn.microsoft.com/en-us/troubleshoot/windows-client/networking/configure-client-proxy-server-settings-by-registry-file>
+    if !server.contains('=') {
+        return Some(server.to_string()).filter(|s| !s.is_empty());
+    }
+    server.split(';').find_map(|entry| {
+        let (entry_scheme, proxy) = entry.split_once('=')?;
+        if entry_scheme.trim().eq_ignore_ascii_case(scheme) && !proxy.is_empty() {
+            Some(proxy.trim().to_string())
+        } else {
+            None
+        }
     })
 }
 
-// winos_name
-/// *Returns* "friendly" WinOS name.
-fn winos_name(
+// is_fido_hid_hardware_ids
+/// *Returns* whether a HID device's (NUL-separated) PnP `hardware_ids` identify a FIDO authenticator (ie, a top-level
+/// collection with the FIDO Alliance usage page, 0xF1D0).
+// ref: <https://learn.microsoft.com/en-us/windows-hardware/drivers/hid/top-level-collections>
+// ref: [FIDO CTAP v2.1 ~ 11.2.8.1 HID Report Descriptor and Device Discovery](https://fidoalliance.org/specs/fido-v2.1-ps-20210615/fido-client-to-authenticator-protocol-v2.1-ps-20210615.html#usb-discovery)
+fn is_fido_hid_hardware_ids(hardware_ids: &str) -> bool {
+    // * HID hardware IDs include the usage page and usage of the top-level collection (eg, "HID_DEVICE_UP:F1D0_U:0001")
+    hardware_ids
+        .split('\0')
+        .any(|id| id.to_ascii_uppercase().starts_with("HID_DEVICE_UP:F1D0"))
+}
+
+// max_file_size_for_file_system
+/// *Returns* the maximum file size (in bytes) supported by the named file system (`file_system_name`; eg, "NTFS").
+// ref: <https://learn.microsoft.com/en-us/windows/win32/fileio/filesystem-functionality-comparison#limits>
+fn max_file_size_for_file_system(file_system_name: &str) -> Option<u64> {
+    const KIB: u64 = 1 << 10;
+    const GIB: u64 = 1 << 30;
+    const TIB: u64 = 1 << 40;
+    const PIB: u64 = 1 << 50;
+    match file_system_name.to_ascii_uppercase().as_str() {
+        "FAT" | "FAT32" => Some(4 * GIB - 1),
+        // * conservative implementation limit (the architectural limit is 16 EiB - 1 KiB)
+        "NTFS" => Some(16 * TIB - 64 * KIB),
+        "EXFAT" => Some(u64::MAX),
+        "REFS" => Some(35 * PIB),
+        _ => None,
+    }
+}
+
+// smbios_chassis_code
+/// *Returns* the system enclosure (aka chassis) type code from raw SMBIOS data (`raw_smbios_data`; as returned by
+/// `GetSystemFirmwareTable('RSMB', ...)`).
+// ref: <https://learn.microsoft.com/en-us/windows/win32/api/sysinfoapi/nf-sysinfoapi-getsystemfirmwaretable>
+// ref: [SMBIOS (DSP0134 v3.7.0)](https://www.dmtf.org/sites/default/files/standards/documents/DSP0134_3.7.0.pdf)
+fn smbios_chassis_code(raw_smbios_data: &[BYTE]) -> Option<u8> {
+    // `RawSMBIOSData` header ~ Used20CallingMethod (BYTE), SMBIOSMajorVersion (BYTE), SMBIOSMinorVersion (BYTE), DmiRevision (BYTE), Length (DWORD)
+    let header = raw_smbios_data.get(..8)?;
+    let length = usize::try_from(DWORD::from_le_bytes([
+        header[4], header[5], header[6], header[7],
+    ]))
+    .ok()?;
+    let table = raw_smbios_data.get(8..)?;
+    let table = &table[..length.min(table.len())];
+
+    // SMBIOS structures ~ Type (BYTE), Length (BYTE; of formatted area), Handle (WORD), formatted area, string-set (terminated by a double NUL)
+    let mut offset = 0;
+    while offset + 4 <= table.len() {
+        let structure_type = table[offset];
+        let structure_length = usize::from(table[offset + 1]);
+        if structure_length < 4 || structure_type == 127
+        /* End-of-Table */
+        {
+            break;
+        }
+        if structure_type == 3
+        /* System Enclosure or Chassis */
+        {
+            return table.get(offset + 5).copied();
+        }
+        // skip the formatted area and the string-set
+        let mut next = offset + structure_length;
+        while next + 1 < table.len() && !(table[next] == 0 && table[next + 1] == 0) {
+            next += 1;
+        }
+        offset = next + 2;
+    }
+    None
+}
+
+// processor_core_counts
+/// *Returns* the number of processor cores and the total number of their logical processors, as `(cores, logical)`,
+/// described by `info` (a series of `SYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX` structures, as retrieved for the
+/// `RelationProcessorCore` relationship), or `None` if `info` is malformed.
+// ref: <https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-system_logical_processor_information_ex>
+// ref: <https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-processor_relationship>
+fn processor_core_counts(info: &[u8]) -> Option<(usize, usize)> {
+    // * each structure is `Relationship` and `Size` DWORDs followed by a PROCESSOR_RELATIONSHIP (`Flags` and
+    //   `EfficiencyClass` BYTEs, `Reserved` BYTE[20], `GroupCount` WORD, then the `GroupMask` GROUP_AFFINITY array)
+    const GROUP_COUNT_OFFSET: usize = 30;
+    const GROUP_MASK_OFFSET: usize = 32;
+    let mask_size = std::mem::size_of::<winapi::shared::basetsd::KAFFINITY>(); // `GROUP_AFFINITY.Mask`
+    let group_affinity_size = std::mem::size_of::<GROUP_AFFINITY>();
+    let dword = |bytes: &[u8], offset: usize| {
+        let b = bytes.get(offset..offset + 4)?;
+        Some(DWORD::from_le_bytes([b[0], b[1], b[2], b[3]]))
+    };
+
+    let (mut cores, mut logical) = (0, 0);
+    let mut rest = info;
+    while !rest.is_empty() {
+        let relationship = dword(rest, 0)?;
+        let size = usize::try_from(dword(rest, 4)?).ok()?;
+        if size < GROUP_MASK_OFFSET || size > rest.len() {
+            return None;
+        }
+        let (record, tail) = rest.split_at(size);
+        if relationship == RelationProcessorCore {
+            cores += 1;
+            let group_count = usize::from(u16::from_le_bytes([
+                record[GROUP_COUNT_OFFSET],
+                record[GROUP_COUNT_OFFSET + 1],
+            ]));
+            for group in 0..group_count {
+                let offset = GROUP_MASK_OFFSET + group * group_affinity_size;
+                let mask = record.get(offset..offset + mask_size)?;
+                logical += mask.iter().map(|b| b.count_ones()).sum::<u32>() as usize;
+            }
+        }
+        rest = tail;
+    }
+    Some((cores, logical))
+}
+
+// classify_winos_version
+/// *Returns* the [`WindowsVersion`] classification of a WinOS version.
+fn classify_winos_version(
     major: DWORD,
     minor: DWORD,
     build: DWORD,
     product_type: BYTE,
     suite_mask: DWORD,
-) -> String {
+) -> WindowsVersion {
     // [NT Version Info (detailed)](https://en.wikipedia.org/wiki/Comparison_of_Microsoft_Windows_versions#Windows_NT) @@ <https://archive.is/FSkhj>
-    let default_name = if product_type == VER_NT_WORKSTATION {
-        format!("{} {}.{}", "Windows", major, minor)
-    } else {
-        format!("{} {}.{}", "Windows Server", major, minor)
+    let other = WindowsVersion::Other {
+        major,
+        minor,
+        build,
     };
-
-    let name = match major {
+    match major {
         5 => match minor {
-            0 => "Windows 2000",
-            1 => "Windows XP",
-            2 if product_type == VER_NT_WORKSTATION => "Windows XP Professional x64 Edition",
-            2 if suite_mask == VER_SUITE_WH_SERVER => "Windows Home Server",
-            2 => "Windows Server 2003",
-            _ => &default_name,
+            0 => WindowsVersion::Windows2000,
+            1 => WindowsVersion::WindowsXp,
+            2 if product_type == VER_NT_WORKSTATION => WindowsVersion::WindowsXpProfessionalX64,
+            2 if suite_mask == VER_SUITE_WH_SERVER => WindowsVersion::WindowsHomeServer,
+            2 => WindowsVersion::Server2003,
+            _ => other,
         },
         6 => match minor {
-            0 if product_type == VER_NT_WORKSTATION => "Windows Vista",
-            0 => "Windows Server 2008",
-            1 if product_type != VER_NT_WORKSTATION => "Windows Server 2008 R2",
-            1 => "Windows 7",
-            2 if product_type != VER_NT_WORKSTATION => "Windows Server 2012",
-            2 => "Windows 8",
-            3 if product_type != VER_NT_WORKSTATION => "Windows Server 2012 R2",
-            3 => "Windows 8.1",
-            _ => &default_name,
+            0 if product_type == VER_NT_WORKSTATION => WindowsVersion::WindowsVista,
+            0 => WindowsVersion::Server2008,
+            1 if product_type != VER_NT_WORKSTATION => WindowsVersion::Server2008R2,
+            1 => WindowsVersion::Windows7,
+            2 if product_type != VER_NT_WORKSTATION => WindowsVersion::Server2012,
+            2 => WindowsVersion::Windows8,
+            3 if product_type != VER_NT_WORKSTATION => WindowsVersion::Server2012R2,
+            3 => WindowsVersion::Windows8_1,
+            _ => other,
         },
         10 => match minor {
-            0 if product_type == VER_NT_WORKSTATION && (build >= 22000) => "Windows 11",
+            0 if product_type == VER_NT_WORKSTATION && (build >= 22000) => {
+                WindowsVersion::Windows11
+            }
             0 if product_type != VER_NT_WORKSTATION && (14000..17000).contains(&build) => {
-                "Windows Server 2016"
+                WindowsVersion::Server2016
             }
             0 if product_type != VER_NT_WORKSTATION && (17000..19000).contains(&build) => {
-                "Windows Server 2019"
+                WindowsVersion::Server2019
             }
-            0 if product_type != VER_NT_WORKSTATION && (build >= 20000) => "Windows Server 2022",
-            _ => "Windows 10",
+            0 if product_type != VER_NT_WORKSTATION && (build >= 20000) => {
+                WindowsVersion::Server2022
+            }
+            _ => WindowsVersion::Windows10,
         },
-        _ => &default_name,
-    };
+        _ => other,
+    }
+}
 
-    name.to_string()
+// winos_name
+/// *Returns* "friendly" WinOS name.
+fn winos_name(
+    major: DWORD,
+    minor: DWORD,
+    build: DWORD,
+    product_type: BYTE,
+    suite_mask: DWORD,
+) -> String {
+    match classify_winos_version(major, minor, build, product_type, suite_mask) {
+        WindowsVersion::Other { .. } if product_type != VER_NT_WORKSTATION => {
+            format!("{} {}.{}", "Windows Server", major, minor)
+        }
+        windows_version => windows_version.to_string(),
+    }
 }
 
 //===
 
-fn determine_machine(system_info: &WinApiSystemInfo) -> OsString {
+fn determine_machine(system_info: &WinApiSystemInfo, is_wow64: bool) -> OsString {
     let arch = system_info.wProcessorArchitecture();
 
+    // `GetNativeSystemInfo()` reports the native architecture for WOW64 processes on x64 (and IA64) systems, but, for x86
+    // processes running under emulation on ARM64 systems, it reports an x86 architecture
+    // * so, WOW64 + x86 architecture => ARM64 host (keeping `machine` consistent with `is_wow64`)
+    // ref: <https://learn.microsoft.com/en-us/windows/win32/api/sysinfoapi/nf-sysinfoapi-getnativesysteminfo> @@ <https://archive.is/UV2S2>
+    if is_wow64 && arch == PROCESSOR_ARCHITECTURE_INTEL {
+        return OsString::from("aarch64");
+    }
+
     // ref: [SYSTEM_INFO structure](https://learn.microsoft.com/en-us/windows/win32/api/sysinfoapi/ns-sysinfoapi-system_info) @@ <https://archive.is/cqbrj>
     // ref: [LLVM Triples](https://llvm.org/doxygen/classllvm_1_1Triple.html) @@ <https://archive.is/MwVL8>
     // ref: [SuperH](https://en.wikipedia.org/wiki/SuperH) @@ <https://archive.is/ckr6a>
@@ -510,16 +1783,108 @@ fn determine_machine(system_info: &WinApiSystemInfo) -> OsString {
     OsString::from(arch_str)
 }
 
+// is_emulated
+/// *Returns* `true` if a process, compiled for `target_arch` (eg, "x86"), is emulated on (or running via WOW64 on) a
+/// system with the native machine name `machine` (eg, "AMD64"; see [`determine_machine_raw()`]).
+fn is_emulated(is_wow64: bool, machine: &str, target_arch: &str) -> bool {
+    is_wow64 || crate::lib_impl::is_foreign_machine(machine, target_arch)
+}
+
+// determine_machine_raw
+/// *Returns* the OS-native name of the machine hardware architecture (see [`PlatformInfo::machine_raw()`]).
+fn determine_machine_raw(system_info: &WinApiSystemInfo, is_wow64: bool) -> &'static str {
+    let arch = system_info.wProcessorArchitecture();
+    // * emulated x86 process on an ARM64 host (see `determine_machine()`)
+    if is_wow64 && arch == PROCESSOR_ARCHITECTURE_INTEL {
+        return native_machine_name(PROCESSOR_ARCHITECTURE_ARM64);
+    }
+    native_machine_name(arch)
+}
+
+// native_machine_name
+/// *Returns* the WinOS-native name of the processor architecture `arch` (a `PROCESSOR_ARCHITECTURE_*` value), as used
+/// for the `PROCESSOR_ARCHITECTURE` environment variable.
+// ref: <https://learn.microsoft.com/en-us/windows/win32/winprog64/wow64-implementation-details#environment-variables>
+fn native_machine_name(arch: WORD) -> &'static str {
+    match arch {
+        PROCESSOR_ARCHITECTURE_AMD64 => "AMD64",
+        PROCESSOR_ARCHITECTURE_INTEL => "x86",
+        PROCESSOR_ARCHITECTURE_IA64 => "IA64",
+        PROCESSOR_ARCHITECTURE_ARM => "ARM",
+        PROCESSOR_ARCHITECTURE_ARM64 => "ARM64",
+        PROCESSOR_ARCHITECTURE_MIPS => "MIPS",
+        PROCESSOR_ARCHITECTURE_PPC => "PPC",
+        PROCESSOR_ARCHITECTURE_ALPHA => "ALPHA",
+        PROCESSOR_ARCHITECTURE_ALPHA64 => "ALPHA64",
+        PROCESSOR_ARCHITECTURE_SHX => "SHX",
+        _ => "unknown",
+    }
+}
+
+fn determine_os_bitness(system_info: &WinApiSystemInfo, is_wow64: bool) -> Option<u8> {
+    match system_info.wProcessorArchitecture() {
+        PROCESSOR_ARCHITECTURE_AMD64
+        | PROCESSOR_ARCHITECTURE_ARM64
+        | PROCESSOR_ARCHITECTURE_IA64
+        | PROCESSOR_ARCHITECTURE_ALPHA64 => Some(64),
+        // * a 32-bit architecture is reported for emulated processes on ARM64 (see `determine_machine()`)
+        PROCESSOR_ARCHITECTURE_INTEL | PROCESSOR_ARCHITECTURE_ARM if is_wow64 => Some(64),
+        PROCESSOR_ARCHITECTURE_INTEL
+        | PROCESSOR_ARCHITECTURE_ARM
+        | PROCESSOR_ARCHITECTURE_MIPS
+        | PROCESSOR_ARCHITECTURE_PPC
+        | PROCESSOR_ARCHITECTURE_ALPHA
+        | PROCESSOR_ARCHITECTURE_SHX => Some(32),
+        _ => None,
+    }
+}
+
+// parse_build_number
+/// *Returns* the build number contained in a WinOS `version` string (eg, "19045"), or `None` if it isn't numeric.
+fn parse_build_number(version: &OsStr) -> Option<u32> {
+    version.to_str()?.parse().ok()
+}
+
+// parse_release_version
+/// *Returns* the `(major, minor)` version contained in a WinOS `release` string (eg, "10.0"), or `None` if it isn't of
+/// the form "*major*.*minor*".
+fn parse_release_version(release: &OsStr) -> Option<(u32, u32)> {
+    let (major, minor) = release.to_str()?.split_once('.')?;
+    Some((major.parse().ok()?, minor.parse().ok()?))
+}
+
+// is_version_at_least
+/// *Returns* `true` if the WinOS version, given as `release` (eg, "10.0") and `version` (eg, "19045") strings, is at
+/// least the `(major, minor, build)` version `minimum`; `false` if it's older or isn't parsable.
+fn is_version_at_least(release: &OsStr, version: &OsStr, minimum: (u32, u32, u32)) -> bool {
+    match (parse_release_version(release), parse_build_number(version)) {
+        (Some((major, minor)), Some(build)) => (major, minor, build) >= minimum,
+        _ => false,
+    }
+}
+
 fn determine_osname(version_info: &WinOsVersionInfo) -> OsString {
-    let mut osname = OsString::from(crate::lib_impl::HOST_OS_NAME);
-    osname.extend([
-        OsString::from(" ("),
-        version_info.os_name.clone(),
-        OsString::from(")"),
-    ]);
+    let mut osname = OsString::from(crate::host_os_name());
+    // * omit any empty (or redundant) "friendly" name parenthetical (eg, "MS/Windows ()" from a degraded fallback)
+    let os_name = version_info.os_name();
+    let os_name = os_name.to_str().map_or(os_name, |s| OsStr::new(s.trim()));
+    if !os_name.is_empty() && os_name != crate::host_os_name() {
+        osname.extend([OsStr::new(" ("), os_name, OsStr::new(")")]);
+    }
     osname
 }
 
+fn msys_sysname(environment: &str, version_info: &WinOsVersionInfo) -> OsString {
+    // MSYS2/Cygwin `uname -s` ~ "<ENVIRONMENT>_NT-<major>.<minor>-<build>" (eg, "CYGWIN_NT-10.0-19045")
+    // ref: <https://github.com/msys2/msys2-runtime/blob/msys2-3.4.10/winsup/cygwin/uname.cc>
+    let mut sysname = OsString::from(environment.to_ascii_uppercase());
+    sysname.push("_NT-");
+    sysname.push(version_info.release());
+    sysname.push("-");
+    sysname.push(version_info.version());
+    sysname
+}
+
 fn determine_sysname() -> OsString {
     // As of 2023-02, possible Windows kernels == [ "Windows_9x", "Windows_NT" ]
     // * "Windows_9x" hit end-of-service-life on 2006-07-11 (ref: [Windows_9x](https://en.wikipedia.org/wiki/Windows_9x) @@ <https://archive.is/wip/K6fhN>)
@@ -547,6 +1912,31 @@ fn test_nodename_no_trailing_NUL() {
     assert_eq!(nodename, trimmed);
 }
 
+#[test]
+fn test_nodename_lone_surrogate() {
+    use std::os::windows::ffi::OsStrExt;
+
+    let info = PlatformInfo::new().unwrap();
+    // "A", lone (unpaired) high surrogate, "B"
+    let wide_name: Vec<u16> = vec![0x0041, 0xD800, 0x0042];
+    let computer_name = OsString::from_wide(&wide_name);
+    let info = PlatformInfo::from_components(
+        computer_name.clone(),
+        info.system_info,
+        info.version_info,
+        info.is_wow64,
+    );
+
+    assert_eq!(info.nodename(), computer_name);
+    assert_eq!(info.nodename().encode_wide().collect::<Vec<_>>(), wide_name);
+    assert!(info
+        .format(crate::UnameFlags::ALL)
+        .encode_wide()
+        .collect::<Vec<_>>()
+        .windows(wide_name.len())
+        .any(|w| w == wide_name));
+}
+
 #[test]
 fn test_machine() {
     let is_wow64 = KERNEL32_IsWow64Process(WinAPI_GetCurrentProcess()).unwrap_or_else(|err| {
@@ -554,8 +1944,10 @@ fn test_machine() {
         false
     });
 
-    let target = if cfg!(target_arch = "x86_64") || (cfg!(target_arch = "x86") && is_wow64) {
+    let target = if cfg!(target_arch = "x86_64") {
         vec!["x86_64"]
+    } else if cfg!(target_arch = "x86") && is_wow64 {
+        vec!["x86_64", "aarch64"]
     } else if cfg!(target_arch = "x86") {
         vec!["i386", "i486", "i586", "i686"]
     } else if cfg!(target_arch = "arm") {
@@ -581,15 +1973,142 @@ fn test_machine() {
     assert!(target.contains(&&machine[..]));
 }
 
+#[test]
+fn test_machine_raw() {
+    let info = PlatformInfo::new().unwrap();
+    let machine_raw = info.machine_raw();
+    println!("machine_raw={:?}", machine_raw);
+    if cfg!(target_arch = "x86_64") {
+        assert_eq!(machine_raw, "AMD64");
+    } else if cfg!(target_arch = "aarch64") {
+        assert_eq!(machine_raw, "ARM64");
+    }
+    if let Some(processor_architecture) = std::env::var_os("PROCESSOR_ARCHITEW6432")
+        .or_else(|| std::env::var_os("PROCESSOR_ARCHITECTURE"))
+    {
+        assert_eq!(machine_raw, processor_architecture);
+    }
+
+    assert_eq!(native_machine_name(PROCESSOR_ARCHITECTURE_AMD64), "AMD64");
+    assert_eq!(native_machine_name(PROCESSOR_ARCHITECTURE_INTEL), "x86");
+    assert_eq!(native_machine_name(PROCESSOR_ARCHITECTURE_ARM), "ARM");
+    assert_eq!(native_machine_name(PROCESSOR_ARCHITECTURE_ARM64), "ARM64");
+    assert_eq!(native_machine_name(PROCESSOR_ARCHITECTURE_IA64), "IA64");
+    assert_eq!(
+        native_machine_name(PROCESSOR_ARCHITECTURE_UNKNOWN),
+        "unknown"
+    );
+}
+
+#[test]
+fn test_abi() {
+    let info = PlatformInfo::new().unwrap();
+    let abi = info.abi();
+    println!("abi='{}'", abi);
+    assert_eq!(abi == "msvc", cfg!(target_env = "msvc"));
+    assert_eq!(abi == "gnu", cfg!(target_env = "gnu"));
+    assert_eq!(info.sysname(), "Windows_NT");
+}
+
+#[test]
+fn test_sysname_msys() {
+    let info = PlatformInfo::new().unwrap();
+    let sysname_msys = info.sysname_msys();
+    println!("sysname_msys={:?}", sysname_msys);
+    assert!(sysname_msys.to_string_lossy().contains("_NT-"));
+
+    let version_info =
+        |release: &str, version: &str| WinOsVersionInfo::new("Windows 10", release, version);
+    assert_eq!(
+        msys_sysname("MINGW64", &version_info("10.0", "19045")),
+        "MINGW64_NT-10.0-19045"
+    );
+    assert_eq!(
+        msys_sysname("ucrt64", &version_info("10.0", "22631")),
+        "UCRT64_NT-10.0-22631"
+    );
+    assert_eq!(
+        msys_sysname("CYGWIN", &version_info("6.1", "7601")),
+        "CYGWIN_NT-6.1-7601"
+    );
+}
+
+#[test]
+fn test_arch_triple() {
+    let info = PlatformInfo::new().unwrap();
+    let arch_triple = info.arch_triple();
+    println!("machine={:?}; arch_triple={}", info.machine(), arch_triple);
+    if !info.is_wow64() {
+        assert_eq!(arch_triple, std::env::consts::ARCH);
+    }
+
+    // * all `determine_machine()` results
+    let arch = crate::lib_impl::rust_arch_from_machine;
+    assert_eq!(arch("x86_64"), "x86_64");
+    assert_eq!(arch("i386"), "x86");
+    assert_eq!(arch("i486"), "x86");
+    assert_eq!(arch("i586"), "x86");
+    assert_eq!(arch("i686"), "x86");
+    assert_eq!(arch("aarch64"), "aarch64");
+    assert_eq!(arch("arm"), "arm");
+    assert_eq!(arch("mips"), "mips");
+    assert_eq!(arch("powerpc"), "powerpc");
+    assert_eq!(arch("ia64"), "unknown");
+    assert_eq!(arch("alpha"), "unknown");
+    assert_eq!(arch("superh"), "unknown");
+    assert_eq!(arch("unknown"), "unknown");
+}
+
+#[test]
+fn test_is_wow64() {
+    let info = PlatformInfo::new().unwrap();
+    let is_wow64 = info.is_wow64();
+    let machine = info.machine().to_string_lossy();
+    println!("is_wow64={}; machine='{}'", is_wow64, machine);
+
+    assert_eq!(
+        is_wow64,
+        KERNEL32_IsWow64Process(WinAPI_GetCurrentProcess()).unwrap_or(false)
+    );
+    // WOW64 => 32-bit process on a 64-bit WinOS
+    if cfg!(target_pointer_width = "64") {
+        assert!(!is_wow64);
+    }
+    if is_wow64 {
+        assert!(["x86_64", "aarch64", "ia64"].contains(&&machine[..]));
+    }
+}
+
+#[test]
+fn test_os_bitness() {
+    let info = PlatformInfo::new().unwrap();
+    let os_bitness = info.os_bitness();
+    println!("os_bitness={:?}", os_bitness);
+
+    if cfg!(target_pointer_width = "64") || info.is_wow64() {
+        assert_eq!(os_bitness, Some(64));
+    } else {
+        assert_eq!(os_bitness, Some(32));
+    }
+}
+
 #[test]
 fn test_osname() {
     let info = PlatformInfo::new().unwrap();
     let osname = info.osname().to_string_lossy();
     println!("osname=[{}]'{}'", osname.len(), osname);
-    assert!(osname.starts_with(crate::lib_impl::HOST_OS_NAME));
+    assert!(osname.starts_with(crate::host_os_name()));
+
+    let osname = |os_name: &str| determine_osname(&WinOsVersionInfo::new(os_name, "10.0", "19045"));
+    assert_eq!(osname("Windows 10"), "MS/Windows (Windows 10)");
+    assert_eq!(osname(" Windows 10 "), "MS/Windows (Windows 10)");
+    assert_eq!(osname(""), "MS/Windows");
+    assert_eq!(osname("  "), "MS/Windows");
+    assert_eq!(osname("MS/Windows"), "MS/Windows");
 }
 
 #[test]
+#[cfg(not(feature = "no-file-fallback"))]
 fn test_version_vs_version() {
     let version_via_dll = os_version_info_from_dll().unwrap();
     let version_via_file = version_info_from_file::<_, &str>(None).unwrap();
@@ -603,19 +2122,52 @@ fn test_version_vs_version() {
     // the "version" portions may differ, but should have only slight variation
     // * assume that "version" is convertible to u32 + "version" from file is always earlier/smaller and may differ only below the thousands digit
     // * ref: [NT Version Info (detailed)](https://en.wikipedia.org/wiki/Comparison_of_Microsoft_Windows_versions#Windows_NT) @@ <https://archive.is/FSkhj>
-    let version_via_dll_n = version_via_dll
-        .version
-        .to_string_lossy()
-        .parse::<u32>()
-        .unwrap();
-    let version_via_file_n = version_via_file
-        .version
-        .to_string_lossy()
-        .parse::<u32>()
-        .unwrap();
+    let version_via_dll_n = parse_build_number(version_via_dll.version()).unwrap();
+    let version_via_file_n = parse_build_number(version_via_file.version()).unwrap();
     assert!(version_via_dll_n.checked_sub(version_via_file_n) < Some(1000));
 }
 
+#[test]
+#[cfg(not(feature = "no-file-fallback"))]
+fn test_version_info_from_file() {
+    let system_directory = WinOsGetSystemDirectory().unwrap();
+    let info = WinOsVersionInfo::from_file(system_directory.join("kernel32.dll")).unwrap();
+    println!("info={:#?}", info);
+    assert!(!info.os_name.is_empty());
+    assert!(info.release.to_string_lossy().contains('.'));
+    assert!(info.version.to_string_lossy().parse::<u32>().is_ok());
+
+    assert_eq!(
+        info,
+        version_info_from_file("" /* use default file */).unwrap()
+    );
+
+    assert!(WinOsVersionInfo::from_file("").is_err());
+    // * an interior NUL is an error (not a truncation to the valid leading path)
+    let mut path_with_nul = system_directory.join("kernel32.dll").into_os_string();
+    path_with_nul.push("\0.bak");
+    assert!(WinOsVersionInfo::from_file(path_with_nul).is_err());
+    assert!(WinOsVersionInfo::from_file(system_directory.join("nonexistent-file.dll")).is_err());
+}
+
+#[test]
+fn test_c_string_interior_nul() {
+    use util::*;
+
+    assert_eq!(to_c_string("abc\0def").as_bytes(), b"abc");
+    assert_eq!(to_c_wstring("abc\0def"), vec![97, 98, 99, 0]);
+
+    assert_eq!(try_to_c_string("abc").unwrap().as_bytes(), b"abc");
+    assert_eq!(try_to_c_wstring("abc").unwrap(), vec![97, 98, 99, 0]);
+    assert_eq!(try_to_c_wstring("").unwrap(), vec![0]);
+
+    let err = try_to_c_string("abc\0def").unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    let err = try_to_c_wstring("abc\0def").unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    assert!(try_to_c_wstring("\0").is_err());
+}
+
 #[test]
 fn test_known_winos_names() {
     // ref: [NT Version Info (detailed)](https://en.wikipedia.org/wiki/Comparison_of_Microsoft_Windows_versions#Windows_NT) @@ <https://archive.is/FSkhj>
@@ -746,6 +2298,777 @@ fn test_known_winos_names() {
     );
 }
 
+#[test]
+fn test_known_windows_versions() {
+    use WindowsVersion::*;
+    let other = |major, minor, build| Other {
+        major,
+        minor,
+        build,
+    };
+    // * same cases as `test_known_winos_names()`
+    for &(major, minor, build, product_type, suite_mask, expected) in &[
+        (
+            3,
+            1,
+            528,
+            VER_NT_WORKSTATION,
+            VER_SUITE_PERSONAL,
+            other(3, 1, 528),
+        ),
+        (
+            3,
+            5,
+            807,
+            VER_NT_WORKSTATION,
+            VER_SUITE_PERSONAL,
+            other(3, 5, 807),
+        ),
+        (
+            3,
+            51,
+            1057,
+            VER_NT_WORKSTATION,
+            VER_SUITE_PERSONAL,
+            other(3, 51, 1057),
+        ),
+        (
+            4,
+            0,
+            1381,
+            VER_NT_WORKSTATION,
+            VER_SUITE_PERSONAL,
+            other(4, 0, 1381),
+        ),
+        (
+            5,
+            0,
+            2195,
+            VER_NT_WORKSTATION,
+            VER_SUITE_PERSONAL,
+            Windows2000,
+        ),
+        (
+            5,
+            1,
+            2600,
+            VER_NT_WORKSTATION,
+            VER_SUITE_PERSONAL,
+            WindowsXp,
+        ),
+        (
+            5,
+            2,
+            3790,
+            VER_NT_WORKSTATION,
+            VER_SUITE_PERSONAL,
+            WindowsXpProfessionalX64,
+        ),
+        (
+            5,
+            2,
+            3790,
+            VER_NT_SERVER,
+            VER_SUITE_WH_SERVER,
+            WindowsHomeServer,
+        ),
+        (
+            5,
+            2,
+            3790,
+            VER_NT_SERVER,
+            VER_SUITE_SMALLBUSINESS,
+            Server2003,
+        ),
+        (
+            6,
+            0,
+            6000,
+            VER_NT_WORKSTATION,
+            VER_SUITE_PERSONAL,
+            WindowsVista,
+        ),
+        (
+            6,
+            0,
+            6001,
+            VER_NT_SERVER,
+            VER_SUITE_SMALLBUSINESS,
+            Server2008,
+        ),
+        (6, 1, 7600, VER_NT_WORKSTATION, VER_SUITE_PERSONAL, Windows7),
+        (
+            6,
+            1,
+            7600,
+            VER_NT_SERVER,
+            VER_SUITE_SMALLBUSINESS,
+            Server2008R2,
+        ),
+        (
+            6,
+            2,
+            9200,
+            VER_NT_SERVER,
+            VER_SUITE_SMALLBUSINESS,
+            Server2012,
+        ),
+        (6, 2, 9200, VER_NT_WORKSTATION, VER_SUITE_PERSONAL, Windows8),
+        (
+            6,
+            3,
+            9600,
+            VER_NT_WORKSTATION,
+            VER_SUITE_PERSONAL,
+            Windows8_1,
+        ),
+        (
+            6,
+            3,
+            9600,
+            VER_NT_SERVER,
+            VER_SUITE_SMALLBUSINESS,
+            Server2012R2,
+        ),
+        (
+            10,
+            0,
+            10240,
+            VER_NT_WORKSTATION,
+            VER_SUITE_PERSONAL,
+            Windows10,
+        ),
+        (
+            10,
+            0,
+            19145,
+            VER_NT_WORKSTATION,
+            VER_SUITE_PERSONAL,
+            Windows10,
+        ),
+        (
+            10,
+            0,
+            14393,
+            VER_NT_SERVER,
+            VER_SUITE_SMALLBUSINESS,
+            Server2016,
+        ),
+        (
+            10,
+            0,
+            17763,
+            VER_NT_SERVER,
+            VER_SUITE_SMALLBUSINESS,
+            Server2019,
+        ),
+        (
+            10,
+            0,
+            20348,
+            VER_NT_SERVER,
+            VER_SUITE_SMALLBUSINESS,
+            Server2022,
+        ),
+        (
+            10,
+            0,
+            22000,
+            VER_NT_WORKSTATION,
+            VER_SUITE_PERSONAL,
+            Windows11,
+        ),
+        (
+            10,
+            0,
+            22621,
+            VER_NT_WORKSTATION,
+            VER_SUITE_PERSONAL,
+            Windows11,
+        ),
+        (
+            5,
+            9,
+            3790,
+            VER_NT_WORKSTATION,
+            VER_SUITE_PERSONAL,
+            other(5, 9, 3790),
+        ),
+        (
+            6,
+            9,
+            9600,
+            VER_NT_SERVER,
+            VER_SUITE_SMALLBUSINESS,
+            other(6, 9, 9600),
+        ),
+    ] {
+        let version = classify_winos_version(major, minor, build, product_type, suite_mask);
+        assert_eq!(version, expected);
+        if product_type == VER_NT_WORKSTATION || version != other(major, minor, build) {
+            assert_eq!(
+                version.to_string(),
+                winos_name(major, minor, build, product_type, suite_mask)
+            );
+        }
+    }
+
+    assert_eq!(
+        WinOsVersionInfo::new("Windows 11", "10.0", "22631").windows_version(),
+        Windows11
+    );
+    assert_eq!(
+        WinOsVersionInfo::new("Windows", "", "bad").windows_version(),
+        other(0, 0, 0)
+    );
+
+    let info = PlatformInfo::new().unwrap();
+    let windows_version = info.windows_version();
+    println!(
+        "windows_version={:?} ({})",
+        windows_version, windows_version
+    );
+    if !matches!(windows_version, Other { .. }) {
+        assert_eq!(
+            info.version_info.os_name(),
+            windows_version.to_string().as_str()
+        );
+    }
+}
+
+#[test]
+#[cfg(feature = "virtualization")]
+fn test_virtualization() {
+    let info = PlatformInfo::new().unwrap();
+    let virtualization = info.virtualization();
+    println!("virtualization={:?}", virtualization);
+    if let Some(v) = virtualization {
+        assert!(!v.is_empty());
+    }
+}
+
+#[test]
+fn test_graphics_drivers() {
+    let info = PlatformInfo::new().unwrap();
+    let graphics_drivers = info.graphics_drivers();
+    println!("graphics_drivers={:#?}", graphics_drivers);
+    if let Some(drivers) = graphics_drivers {
+        assert!(!drivers.is_empty());
+        assert!(drivers.iter().all(|d| !d.is_empty()));
+    }
+}
+
+#[test]
+fn test_processor_architecture() {
+    let info = PlatformInfo::new().unwrap();
+    let processor_architecture = info.system_info.processor_architecture();
+    println!("processor_architecture={:?}", processor_architecture);
+    if cfg!(target_arch = "x86_64") {
+        assert_eq!(processor_architecture, ProcessorArchitecture::Amd64);
+    } else if cfg!(target_arch = "aarch64") {
+        assert_eq!(processor_architecture, ProcessorArchitecture::Arm64);
+    }
+
+    use ProcessorArchitecture::*;
+    for &(arch, expected) in &[
+        (PROCESSOR_ARCHITECTURE_AMD64, Amd64),
+        (PROCESSOR_ARCHITECTURE_INTEL, Intel),
+        (PROCESSOR_ARCHITECTURE_ARM, Arm),
+        (PROCESSOR_ARCHITECTURE_ARM64, Arm64),
+        (PROCESSOR_ARCHITECTURE_IA64, Ia64),
+        (PROCESSOR_ARCHITECTURE_MIPS, Mips),
+        (PROCESSOR_ARCHITECTURE_PPC, Ppc),
+        (PROCESSOR_ARCHITECTURE_ALPHA, Alpha),
+        (PROCESSOR_ARCHITECTURE_ALPHA64, Alpha64),
+        (PROCESSOR_ARCHITECTURE_SHX, SuperH),
+        (PROCESSOR_ARCHITECTURE_MSIL, Unknown(8)),
+        (PROCESSOR_ARCHITECTURE_UNKNOWN, Unknown(0xFFFF)),
+    ] {
+        assert_eq!(ProcessorArchitecture::from(arch), expected);
+    }
+}
+
+#[test]
+fn test_computer_name_with_fallback() {
+    let error = || -> WinOSError { Box::from("failure") };
+
+    // * primary (physical DNS host name) name
+    let name = computer_name_with_fallback(|name_type| {
+        assert_eq!(name_type, ComputerNamePhysicalDnsHostname);
+        Ok(OsString::from("host"))
+    });
+    assert_eq!(name.unwrap(), "host");
+
+    // * empty primary name => NetBIOS name
+    let name = computer_name_with_fallback(|name_type| {
+        Ok(OsString::from(if name_type == ComputerNameNetBIOS {
+            "NETBIOS-HOST"
+        } else {
+            ""
+        }))
+    });
+    assert_eq!(name.unwrap(), "NETBIOS-HOST");
+
+    // * failed primary name => NetBIOS name
+    let name = computer_name_with_fallback(|name_type| {
+        if name_type == ComputerNameNetBIOS {
+            Ok(OsString::from("NETBIOS-HOST"))
+        } else {
+            Err(error())
+        }
+    });
+    assert_eq!(name.unwrap(), "NETBIOS-HOST");
+
+    // * all names empty (or failed) => "localhost"
+    let name = computer_name_with_fallback(|_| Ok(OsString::new()));
+    assert_eq!(name.unwrap(), "localhost");
+    let name = computer_name_with_fallback(|name_type| {
+        if name_type == ComputerNameNetBIOS {
+            Err(error())
+        } else {
+            Ok(OsString::new())
+        }
+    });
+    assert_eq!(name.unwrap(), "localhost");
+
+    // * all failed => error
+    assert!(computer_name_with_fallback(|_| Err(error())).is_err());
+
+    let name = WinOsGetComputerName().unwrap();
+    println!("computer name={:?}", name);
+    assert!(!name.is_empty());
+}
+
+#[test]
+fn test_running_under_emulation() {
+    let info = PlatformInfo::new().unwrap();
+    let running_under_emulation = info.running_under_emulation();
+    println!("running_under_emulation={}", running_under_emulation);
+    if info.is_wow64() {
+        assert!(running_under_emulation);
+    }
+
+    let machine_raw = |arch, is_wow64| {
+        determine_machine_raw(&WinApiSystemInfo::from_parts(arch, 6, 4096, 1), is_wow64)
+    };
+    // * native processes
+    assert!(!is_emulated(
+        false,
+        machine_raw(PROCESSOR_ARCHITECTURE_AMD64, false),
+        "x86_64"
+    ));
+    assert!(!is_emulated(
+        false,
+        machine_raw(PROCESSOR_ARCHITECTURE_ARM64, false),
+        "aarch64"
+    ));
+    assert!(!is_emulated(
+        false,
+        machine_raw(PROCESSOR_ARCHITECTURE_INTEL, false),
+        "x86"
+    ));
+    // * WOW64 ~ "x86" process on an "x86_64" WinOS
+    assert!(is_emulated(
+        true,
+        machine_raw(PROCESSOR_ARCHITECTURE_AMD64, true),
+        "x86"
+    ));
+    // * WOW64 ~ "x86" process on an ARM64 WinOS
+    assert!(is_emulated(
+        true,
+        machine_raw(PROCESSOR_ARCHITECTURE_INTEL, true),
+        "x86"
+    ));
+    // * x64 emulation on an ARM64 WinOS (not WOW64)
+    assert!(is_emulated(
+        false,
+        machine_raw(PROCESSOR_ARCHITECTURE_ARM64, false),
+        "x86_64"
+    ));
+}
+
+#[test]
+fn test_determine_machine() {
+    let system_info = |arch, level| WinApiSystemInfo::from_parts(arch, level, 4096, 1);
+
+    for &(arch, level, machine, machine_raw, os_bitness) in &[
+        (PROCESSOR_ARCHITECTURE_AMD64, 6, "x86_64", "AMD64", Some(64)),
+        (PROCESSOR_ARCHITECTURE_INTEL, 3, "i386", "x86", Some(32)),
+        (PROCESSOR_ARCHITECTURE_INTEL, 4, "i486", "x86", Some(32)),
+        (PROCESSOR_ARCHITECTURE_INTEL, 5, "i586", "x86", Some(32)),
+        (PROCESSOR_ARCHITECTURE_INTEL, 6, "i686", "x86", Some(32)),
+        (PROCESSOR_ARCHITECTURE_IA64, 0, "ia64", "IA64", Some(64)),
+        (PROCESSOR_ARCHITECTURE_ARM, 0, "arm", "ARM", Some(32)),
+        (
+            PROCESSOR_ARCHITECTURE_ARM64,
+            0,
+            "aarch64",
+            "ARM64",
+            Some(64),
+        ),
+        (PROCESSOR_ARCHITECTURE_MIPS, 0, "mips", "MIPS", Some(32)),
+        (PROCESSOR_ARCHITECTURE_PPC, 0, "powerpc", "PPC", Some(32)),
+        (PROCESSOR_ARCHITECTURE_ALPHA, 0, "alpha", "ALPHA", Some(32)),
+        (
+            PROCESSOR_ARCHITECTURE_ALPHA64,
+            0,
+            "alpha",
+            "ALPHA64",
+            Some(64),
+        ),
+        (PROCESSOR_ARCHITECTURE_SHX, 0, "superh", "SHX", Some(32)),
+        (
+            PROCESSOR_ARCHITECTURE_UNKNOWN,
+            0,
+            "unknown",
+            "unknown",
+            None,
+        ),
+    ] {
+        let info = system_info(arch, level);
+        assert_eq!(info.wProcessorArchitecture(), arch);
+        assert_eq!(determine_machine(&info, false), machine);
+        assert_eq!(determine_machine_raw(&info, false), machine_raw);
+        assert_eq!(determine_os_bitness(&info, false), os_bitness);
+    }
+
+    // * emulated x86 process (WOW64) on an ARM64 host
+    let info = system_info(PROCESSOR_ARCHITECTURE_INTEL, 6);
+    assert_eq!(determine_machine(&info, true), "aarch64");
+    assert_eq!(determine_machine_raw(&info, true), "ARM64");
+    assert_eq!(determine_os_bitness(&info, true), Some(64));
+    // * WOW64 process on an x64 host (native architecture reported)
+    let info = system_info(PROCESSOR_ARCHITECTURE_AMD64, 6);
+    assert_eq!(determine_machine(&info, true), "x86_64");
+
+    let info = WinApiSystemInfo::from_parts(PROCESSOR_ARCHITECTURE_ARM64, 0, 16384, 8);
+    assert_eq!(info.processor_architecture(), ProcessorArchitecture::Arm64);
+    assert_eq!(info.0.dwPageSize, 16384);
+    assert_eq!(info.0.dwNumberOfProcessors, 8);
+}
+
+#[test]
+fn test_build_number_and_release_version() {
+    let info = PlatformInfo::new().unwrap();
+    let build_number = info.build_number();
+    let release_version = info.release_version();
+    println!(
+        "build_number={:?}; release_version={:?}",
+        build_number, release_version
+    );
+    assert!(build_number.is_some());
+    assert!(release_version.is_some());
+
+    let build = |s: &str| parse_build_number(OsStr::new(s));
+    assert_eq!(build("19045"), Some(19045));
+    assert_eq!(build("22631"), Some(22631));
+    assert_eq!(build("7601"), Some(7601));
+    assert_eq!(build(""), None);
+    assert_eq!(build("19045.3803"), None);
+    assert_eq!(build("unknown"), None);
+
+    let release = |s: &str| parse_release_version(OsStr::new(s));
+    assert_eq!(release("10.0"), Some((10, 0)));
+    assert_eq!(release("6.3"), Some((6, 3)));
+    assert_eq!(release("3.51"), Some((3, 51)));
+    assert_eq!(release("10"), None);
+    assert_eq!(release("10.0.19045"), None);
+    assert_eq!(release(""), None);
+}
+
+#[test]
+fn test_os_pretty_name() {
+    let info = PlatformInfo::new().unwrap();
+    let pretty_name = info.os_pretty_name();
+    println!(
+        "os_pretty_name={:?}; osname={:?}",
+        pretty_name,
+        info.osname()
+    );
+    assert!(!pretty_name.is_empty());
+    assert!(pretty_name.to_string_lossy().starts_with("Windows"));
+    assert_eq!(
+        info.osname(),
+        OsString::from(format!(
+            "{} ({})",
+            crate::host_os_name(),
+            pretty_name.to_string_lossy()
+        ))
+    );
+}
+
+#[test]
+fn test_at_least_windows() {
+    let info = PlatformInfo::new().unwrap();
+    println!("release={:?}; version={:?}", info.release(), info.version());
+    // * Rust-compiled binaries require (at least) Windows 7 (aka "6.1.7600")
+    assert!(info.at_least_windows(6, 1, 7600));
+    assert!(info.at_least_windows(0, 0, 0));
+    assert!(!info.at_least_windows(u32::MAX, 0, 0));
+
+    let at_least = |release: &str, version: &str, minimum| {
+        is_version_at_least(OsStr::new(release), OsStr::new(version), minimum)
+    };
+    // Windows 11 (23H2) vs Windows 11 (21H2; first release), Windows 10 (22H2), and Windows 8.1
+    assert!(at_least("10.0", "22631", (10, 0, 22000)));
+    assert!(at_least("10.0", "22631", (10, 0, 19045)));
+    assert!(at_least("10.0", "22631", (6, 3, 9600)));
+    // Windows 10 (22H2) vs Windows 11 (21H2) and Windows 10 (1507; first release)
+    assert!(!at_least("10.0", "19045", (10, 0, 22000)));
+    assert!(at_least("10.0", "19045", (10, 0, 10240)));
+    // Windows 8.1 vs Windows 10 (1507) and Windows 7 (SP1); note, build numbers are compared only within a version
+    assert!(!at_least("6.3", "9600", (10, 0, 10240)));
+    assert!(at_least("6.3", "9600", (6, 1, 7601)));
+    assert!(at_least("6.3", "9600", (6, 3, 9600)));
+    assert!(!at_least("6.3", "9600", (6, 3, 9601)));
+    // unparsable version info
+    assert!(!at_least("", "22631", (6, 1, 0)));
+    assert!(!at_least("10.0", "unknown", (6, 1, 0)));
+}
+
+#[test]
+fn test_physical_cpu_count() {
+    let info = PlatformInfo::new().unwrap();
+    let physical_cpu_count = info.physical_cpu_count();
+    let counts = processor_core_counts(&WinOsGetProcessorCoreInformation().unwrap());
+    println!(
+        "physical_cpu_count={:?}; (cores, logical)={:?}",
+        physical_cpu_count, counts
+    );
+    let (cores, logical) = counts.unwrap();
+    assert_eq!(physical_cpu_count, Some(cores));
+    assert!(cores >= 1);
+    assert!(cores <= logical);
+
+    // synthetic info: a 2-thread (SMT) core and a 1-thread core
+    let record = |mask: usize| {
+        let mut record: Vec<u8> = Vec::new();
+        let size = 32 + std::mem::size_of::<GROUP_AFFINITY>();
+        record.extend_from_slice(&RelationProcessorCore.to_le_bytes());
+        record.extend_from_slice(&(size as DWORD).to_le_bytes());
+        record.extend_from_slice(&[0; 22]); // Flags, EfficiencyClass, Reserved
+        record.extend_from_slice(&1u16.to_le_bytes()); // GroupCount
+        record.extend_from_slice(&mask.to_le_bytes()); // GroupMask[0].Mask
+        record.resize(size, 0); // GroupMask[0].Group and .Reserved
+        record
+    };
+    let info = [record(0b0011), record(0b0100)].concat();
+    assert_eq!(processor_core_counts(&info), Some((2, 3)));
+    assert_eq!(processor_core_counts(&[]), Some((0, 0)));
+    assert_eq!(processor_core_counts(&info[..info.len() - 1]), None); // truncated
+    assert_eq!(processor_core_counts(&[0; 8]), None); // zero `Size`
+}
+
+#[test]
+fn test_same_platform() {
+    let info = PlatformInfo::new().unwrap();
+    let mut renamed = info.clone();
+    renamed.nodename = OsString::from("renamed-nodename");
+    assert!(info.same_platform(&renamed));
+    assert_ne!(info, renamed);
+
+    let mut upgraded = info.clone();
+    upgraded.release = OsString::from("0.0.0");
+    assert!(!info.same_platform(&upgraded));
+}
+
+#[test]
+fn test_refresh() {
+    let mut info = PlatformInfo::new().unwrap();
+    let original = info.clone();
+    info.nodename = OsString::from("stale-nodename");
+    info.version = OsString::from("0");
+    info.refresh().unwrap();
+    assert_eq!(info.nodename(), original.nodename());
+    assert_eq!(info, original);
+}
+
+#[test]
+fn test_reg_get_string_value() {
+    // * the BIOS key (and its `SystemManufacturer` value) exists on every WinOS host
+    let bios_key = r"HARDWARE\DESCRIPTION\System\BIOS";
+    let manufacturer = WinOsRegGetStringValue(HKEY_LOCAL_MACHINE, bios_key, "SystemManufacturer");
+    println!("SystemManufacturer={:?}", manufacturer);
+    assert!(manufacturer.is_ok());
+    assert!(WinOsRegGetStringValue(HKEY_LOCAL_MACHINE, bios_key, "NonexistentValueName").is_err());
+}
+
+#[test]
+fn test_reg_enum_sub_key_names() {
+    let names =
+        WinOsRegEnumSubKeyNames(HKEY_LOCAL_MACHINE, r"HARDWARE\DESCRIPTION\System").unwrap();
+    println!("names={:?}", names);
+    assert!(names.iter().any(|name| name == "BIOS"));
+    assert!(WinOsRegEnumSubKeyNames(HKEY_LOCAL_MACHINE, r"SOFTWARE\NonexistentKeyName").is_err());
+    assert!(WinOsRegEnumSubKeyNames(HKEY_LOCAL_MACHINE, "bad\0name").is_err());
+}
+
+#[test]
+fn test_chassis_type() {
+    let info = PlatformInfo::new().unwrap();
+    let chassis_type = info.chassis_type();
+    println!("chassis_type={:?}", chassis_type);
+
+    let raw_smbios_data: Vec<BYTE> = [
+        &[0, 3, 4, 0][..], // header ~ calling method, major/minor version, DMI revision
+        &[28, 0, 0, 0],    // header ~ table length
+        // type 1 (System Information; truncated), with strings "Vendor" and "Product"
+        &[1, 6, 0x01, 0x00, 1, 2],
+        b"Vendor\0Product\0\0",
+        // type 3 (System Enclosure or Chassis; truncated) ~ Laptop (with chassis lock)
+        &[3, 6, 0x02, 0x00, 0, 0x89],
+        &[0, 0],
+    ]
+    .concat();
+    assert_eq!(smbios_chassis_code(&raw_smbios_data), Some(0x89));
+    assert_eq!(
+        ChassisType::from_smbios_code(smbios_chassis_code(&raw_smbios_data).unwrap()),
+        Some(ChassisType::Laptop)
+    );
+    assert_eq!(smbios_chassis_code(&raw_smbios_data[..14]), None);
+    assert_eq!(smbios_chassis_code(&[]), None);
+}
+
+#[test]
+fn test_hardware_clock_is_utc() {
+    let info = PlatformInfo::new().unwrap();
+    let hardware_clock_is_utc = info.hardware_clock_is_utc();
+    println!("hardware_clock_is_utc={:?}", hardware_clock_is_utc);
+    assert!(hardware_clock_is_utc.is_some());
+}
+
+#[test]
+#[ignore = "performs live DNS lookups (which may block)"]
+fn test_fqdn() {
+    let info = PlatformInfo::new().unwrap();
+    let fqdn = info.fqdn();
+    println!("fqdn={:?}", fqdn);
+    if let Some(fqdn) = fqdn {
+        assert!(!fqdn.is_empty());
+    }
+
+    let localhost = WinOsGetAddrInfoCanonicalName(OsStr::new("localhost"));
+    println!("localhost={:?}", localhost);
+}
+
+#[test]
+fn test_canonical_name_invalid() {
+    // * rejected before any name resolution (ie, no DNS lookup)
+    assert!(WinOsGetAddrInfoCanonicalName(OsStr::new("bad\0name")).is_err());
+}
+
+#[test]
+fn test_winos_proxy() {
+    println!(
+        "winos_proxy(http)={:?}; winos_no_proxy={:?}",
+        winos_proxy("http"),
+        winos_no_proxy()
+    );
+
+    assert_eq!(
+        proxy_for_scheme("proxy:8080", "http"),
+        Some(String::from("proxy:8080"))
+    );
+    let server = "http=proxy:8080;https=secure-proxy:8443;ftp=ftp-proxy:21";
+    assert_eq!(
+        proxy_for_scheme(server, "http"),
+        Some(String::from("proxy:8080"))
+    );
+    assert_eq!(
+        proxy_for_scheme(server, "HTTPS"),
+        Some(String::from("secure-proxy:8443"))
+    );
+    assert_eq!(proxy_for_scheme("ftp=ftp-proxy:21", "http"), None);
+    assert_eq!(proxy_for_scheme("", "http"), None);
+}
+
+#[test]
+fn test_fido_authenticators() {
+    let info = PlatformInfo::new().unwrap();
+    let fido_authenticators = info.fido_authenticators();
+    println!("fido_authenticators={:?}", fido_authenticators);
+    if let Some(authenticators) = fido_authenticators {
+        assert!(!authenticators.is_empty());
+    }
+
+    assert!(is_fido_hid_hardware_ids(
+        "HID\\VID_1050&PID_0407&REV_0526&MI_01\0HID\\VID_1050&PID_0407&MI_01\0HID_DEVICE_UP:F1D0_U:0001\0HID_DEVICE_UP:F1D0\0HID_DEVICE"
+    ));
+    assert!(!is_fido_hid_hardware_ids(
+        "HID\\VID_046D&PID_C52B&MI_00\0HID_DEVICE_SYSTEM_KEYBOARD\0HID_DEVICE_UP:0001_U:0006\0HID_DEVICE"
+    ));
+    assert!(!is_fido_hid_hardware_ids(""));
+}
+
+#[test]
+#[cfg(feature = "uptime")]
+fn test_uptime() {
+    let info = PlatformInfo::new().unwrap();
+    let uptime = info.uptime();
+    let boot_time = info.boot_time();
+    println!("uptime={:?}", uptime);
+    println!("boot_time={:?}", boot_time);
+    assert!(uptime > Some(std::time::Duration::ZERO));
+    assert!(boot_time < Some(std::time::SystemTime::now()));
+}
+
+#[test]
+#[cfg(feature = "timezone")]
+fn test_timezone() {
+    let info = PlatformInfo::new().unwrap();
+    let timezone = info.timezone();
+    println!("timezone={:?}", timezone);
+    assert!(timezone.is_some());
+    assert_ne!(timezone, Some(OsString::new()));
+}
+
+#[test]
+fn test_field_capabilities() {
+    use crate::FieldCapabilities as F;
+    let info = PlatformInfo::new().unwrap();
+    let capabilities = info.field_capabilities();
+    assert!(capabilities.contains(F::UNAME));
+    assert!(!capabilities.contains(F::DOMAINNAME));
+}
+
+#[test]
+fn test_page_size() {
+    let info = PlatformInfo::new().unwrap();
+    let page_size = info.page_size();
+    println!("page_size={:?}", page_size);
+    let page_size = page_size.unwrap();
+    assert!(page_size.is_power_of_two());
+    assert!(page_size >= 4096);
+}
+
+#[test]
+fn test_max_file_size() {
+    let info = PlatformInfo::new().unwrap();
+    let max_file_size = info.max_file_size();
+    println!("max_file_size={:?}", max_file_size);
+    if let Some(size) = max_file_size {
+        assert!(size >= (1 << 32) - 1);
+    }
+
+    assert_eq!(max_file_size_for_file_system("FAT32"), Some((1 << 32) - 1));
+    assert_eq!(
+        max_file_size_for_file_system("NTFS"),
+        Some((1 << 44) - (1 << 16))
+    );
+    assert!(max_file_size_for_file_system("ReFS") > max_file_size_for_file_system("NTFS"));
+    assert_eq!(max_file_size_for_file_system("unknown"), None);
+}
+
+#[test]
+fn test_tpm_version() {
+    let info = PlatformInfo::new().unwrap();
+    let tpm_version = info.tpm_version();
+    println!("tpm_version={:?}", tpm_version);
+    if let Some(v) = tpm_version {
+        assert!(["1.2", "2.0"].contains(&&*v.to_string_lossy()));
+    }
+}
+
 #[test]
 fn structure_clone() {
     let info = PlatformInfo::new().unwrap();
@@ -753,7 +3076,126 @@ fn structure_clone() {
     #[allow(clippy::redundant_clone)] // ignore `clippy::redundant_clone` warning for direct testing
     let info_copy = info.clone();
     assert_eq!(info_copy, info);
+}
 
+#[test]
+#[cfg(not(feature = "no-file-fallback"))]
+fn test_file_version_info_from_bytes() {
+    // minimal VS_VERSIONINFO (as stored, little-endian; without any `Children`) for product version 10.0.19041.2130
+    let fixed_file_info: [DWORD; 13] = [
+        0xFEEF_04BD, // dwSignature
+        0x0001_0000, // dwStrucVersion
+        0x000A_0000, // dwFileVersionMS
+        0x4A61_0852, // dwFileVersionLS
+        0x000A_0000, // dwProductVersionMS
+        0x4A61_0852, // dwProductVersionLS
+        0x0000_003F, // dwFileFlagsMask
+        0,           // dwFileFlags
+        0x0004_0004, // dwFileOS (VOS_NT_WINDOWS32)
+        2,           // dwFileType (VFT_DLL)
+        0,           // dwFileSubtype
+        0,           // dwFileDateMS
+        0,           // dwFileDateLS
+    ];
+    let mut data: Vec<u8> = Vec::new();
+    data.extend_from_slice(&92u16.to_le_bytes()); // wLength
+    data.extend_from_slice(&52u16.to_le_bytes()); // wValueLength
+    data.extend_from_slice(&0u16.to_le_bytes()); // wType (binary)
+    data.extend(
+        "VS_VERSION_INFO\0"
+            .encode_utf16()
+            .flat_map(|c| c.to_le_bytes()),
+    ); // szKey
+    data.extend_from_slice(&[0, 0]); // Padding1
+    data.extend(fixed_file_info.iter().flat_map(|d| d.to_le_bytes())); // Value
+    assert_eq!(data.len(), 92);
+
+    let file_version_info = WinApiFileVersionInfo::from_bytes(data.clone()).unwrap();
+    let root = WinOsFileVersionInfoQuery_root(&file_version_info).unwrap();
+    assert_eq!(root.dwSignature, 0xFEEF_04BD);
+    assert_eq!(root.dwFileType, 2);
+    assert_eq!(
+        mmbr_from_file_version(file_version_info.clone()).unwrap(),
+        MmbrVersion {
+            major: 10,
+            minor: 0,
+            build: 19041,
+            release: 2130,
+        }
+    );
+    let info = WinOsVersionInfo::from_file_version_info(file_version_info).unwrap();
+    println!("info={:#?}", info);
+    assert_eq!(info.release(), "10.0");
+    assert_eq!(info.version(), "19041");
+
+    // malformed buffers are rejected
+    assert!(WinApiFileVersionInfo::from_bytes(Vec::new()).is_err());
+    assert!(WinApiFileVersionInfo::from_bytes(data[..91].to_vec()).is_err()); // truncated
+    let mut bad_length = data.clone();
+    bad_length[0] = 200; // wLength > buffer length
+    assert!(WinApiFileVersionInfo::from_bytes(bad_length).is_err());
+    let mut bad_key = data.clone();
+    bad_key[6] = b'X';
+    assert!(WinApiFileVersionInfo::from_bytes(bad_key).is_err());
+    let mut bad_signature = data;
+    bad_signature[40] = 0;
+    assert!(WinApiFileVersionInfo::from_bytes(bad_signature).is_err());
+}
+
+#[test]
+#[cfg(not(feature = "no-file-fallback"))]
+fn test_mmbr_from_fixed_file_info() {
+    // VS_FIXEDFILEINFO (as stored, little-endian) for product version 10.0.19041.2130
+    let dwords: [DWORD; 13] = [
+        0xFEEF_04BD, // dwSignature
+        0x0001_0000, // dwStrucVersion
+        0x000A_0000, // dwFileVersionMS
+        0x4A61_0852, // dwFileVersionLS
+        0x000A_0000, // dwProductVersionMS
+        0x4A61_0852, // dwProductVersionLS
+        0x0000_003F, // dwFileFlagsMask
+        0,           // dwFileFlags
+        0x0004_0004, // dwFileOS (VOS_NT_WINDOWS32)
+        2,           // dwFileType (VFT_DLL)
+        0,           // dwFileSubtype
+        0,           // dwFileDateMS
+        0,           // dwFileDateLS
+    ];
+    let bytes: Vec<u8> = dwords.iter().flat_map(|d| d.to_le_bytes()).collect();
+    assert_eq!(bytes[..4], [0xBD, 0x04, 0xEF, 0xFE]);
+
+    let info = VS_FIXEDFILEINFO::from_le_bytes(&bytes).unwrap();
+    assert_eq!(info.dwSignature, 0xFEEF_04BD);
+    assert_eq!(info.dwFileOS, 0x0004_0004);
+    assert_eq!(
+        mmbr_from_fixed_file_info(&info),
+        MmbrVersion {
+            major: 10,
+            minor: 0,
+            build: 19041,
+            release: 2130,
+        }
+    );
+
+    // unaligned view
+    let unaligned = [&[0u8][..], &bytes].concat();
+    assert_eq!(VS_FIXEDFILEINFO::from_le_bytes(&unaligned[1..]), Some(info));
+    // truncated
+    assert_eq!(
+        VS_FIXEDFILEINFO::from_le_bytes(&bytes[..bytes.len() - 1]),
+        None
+    );
+
+    // system file
+    let file_path = WinOsGetSystemDirectory().unwrap().join("kernel32.dll");
+    let mmbr = mmbr_from_file_version(WinOsGetFileVersionInfo(file_path).unwrap()).unwrap();
+    println!("mmbr={:?}", mmbr);
+    assert!(mmbr.major >= 5);
+}
+
+#[test]
+#[cfg(not(feature = "no-file-fallback"))]
+fn structure_clone_file_version() {
     let mmbr = MmbrVersion {
         major: 1,
         minor: 2,
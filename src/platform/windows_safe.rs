@@ -5,29 +5,46 @@
 // spell-checker:ignore (uutils) coreutils uutils
 // spell-checker:ignore (vars) mmbr mmrb
 // spell-checker:ignore (VSCode) endregion
-// spell-checker:ignore (WinAPI) ctypes CWSTR DWORDLONG dwStrucVersion FARPROC FIXEDFILEINFO HIWORD HMODULE libloaderapi LOWORD LPCSTR LPCVOID LPCWSTR lpdw LPDWORD lplp LPOSVERSIONINFOEXW LPSYSTEM lptstr LPVOID LPWSTR minwindef ntdef ntstatus OSVERSIONINFOEXW processthreadsapi PUINT SMALLBUSINESS SUITENAME sysinfo sysinfoapi sysinfoapi TCHAR TCHARs ULONGLONG WCHAR WCHARs winapi winbase winver WSTR wstring
+// spell-checker:ignore (WinAPI) basetsd ctypes CWSTR DWORDLONG dwStrucVersion FARPROC FIXEDFILEINFO HIWORD HMODULE libloaderapi LOWORD LPCSTR LPCVOID LPCWSTR lpdw LPDWORD lplp LPOSVERSIONINFOEXW LPSYSTEM lptstr LPVOID LPWSTR minwindef ntdef ntstatus OSVERSIONINFOEXW processthreadsapi PUINT SMALLBUSINESS SUITENAME sysinfo sysinfoapi sysinfoapi TCHAR TCHARs ULONGLONG WCHAR WCHARs winapi winbase winver WSTR wstring
 // spell-checker:ignore (WinOS) ntdll
 
 #![warn(unused_results)] // enable warnings for unused results
 
 use std::convert::TryFrom;
+use std::ffi::{OsStr, OsString};
 use std::io;
 use std::mem::{self, MaybeUninit};
+use std::os::windows::ffi::OsStringExt;
 use std::ptr;
 
+use winapi::shared::basetsd::UINT32;
 use winapi::shared::minwindef::*;
 use winapi::shared::ntdef::NTSTATUS;
 use winapi::shared::ntstatus::*;
+use winapi::shared::winerror::{ERROR_NO_MORE_ITEMS, ERROR_SUCCESS};
+use winapi::shared::ws2def::*;
+use winapi::um::fileapi::GetVolumeInformationW;
 use winapi::um::libloaderapi::*;
 use winapi::um::processthreadsapi::GetCurrentProcess;
+use winapi::um::setupapi::*;
+#[cfg(not(feature = "no-file-fallback"))]
 use winapi::um::sysinfoapi;
 use winapi::um::sysinfoapi::*;
+#[cfg(feature = "timezone")]
+use winapi::um::timezoneapi::*;
+#[cfg(not(feature = "no-file-fallback"))]
 use winapi::um::winbase::*;
 use winapi::um::winnt::*;
+use winapi::um::winreg::*;
+use winapi::um::winsock2::*;
+#[cfg(not(feature = "no-file-fallback"))]
 use winapi::um::winver::*;
+use winapi::um::ws2tcpip::*;
 
-use super::util::{to_c_string, to_c_wstring, CWSTR};
-use super::{WinApiFileVersionInfo, WinApiSystemInfo};
+use super::util::{to_c_wstring, try_to_c_string, try_to_c_wstring, CWSTR};
+#[cfg(not(feature = "no-file-fallback"))]
+use super::WinApiFileVersionInfo;
+use super::WinApiSystemInfo;
 
 use super::PathStr;
 use super::WinOSError;
@@ -39,6 +56,7 @@ use super::WinOSError;
 ///
 /// Implements [`VS_FIXEDFILEINFO`].
 // ref: [`VS_FIXEDFILEINFO`](https://learn.microsoft.com/en-us/windows/win32/api/verrsrc/ns-verrsrc-vs_fixedfileinfo) @@ <https://archive.is/1sJgX>
+#[cfg(not(feature = "no-file-fallback"))]
 #[allow(non_snake_case)]
 #[allow(unused_variables)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -59,6 +77,50 @@ pub struct VS_FIXEDFILEINFO {
     pub dwFileDateLS: DWORD,
 }
 
+#[cfg(not(feature = "no-file-fallback"))]
+impl VS_FIXEDFILEINFO {
+    /// *Returns* the structure decoded from its binary layout (`bytes`) within a version-information resource, or
+    /// `None` if `bytes` is too short.
+    ///
+    /// Resource data is always little-endian (as is all PE file content), so fields are decoded explicitly as
+    /// little-endian DWORDs, independent of the host byte order (and alignment).
+    pub fn from_le_bytes(bytes: &[u8]) -> Option<Self> {
+        let mut dwords = bytes
+            .chunks_exact(mem::size_of::<DWORD>())
+            .map(|b| DWORD::from_le_bytes([b[0], b[1], b[2], b[3]]));
+        Some(Self {
+            dwSignature: dwords.next()?,
+            dwStrucVersion: dwords.next()?,
+            dwFileVersionMS: dwords.next()?,
+            dwFileVersionLS: dwords.next()?,
+            dwProductVersionMS: dwords.next()?,
+            dwProductVersionLS: dwords.next()?,
+            dwFileFlagsMask: dwords.next()?,
+            dwFileFlags: dwords.next()?,
+            dwFileOS: dwords.next()?,
+            dwFileType: dwords.next()?,
+            dwFileSubtype: dwords.next()?,
+            dwFileDateMS: dwords.next()?,
+            dwFileDateLS: dwords.next()?,
+        })
+    }
+}
+
+// TPM_DEVICE_INFO
+/// WinAPI structure which contains version information about the TPM (Trusted Platform Module).
+///
+/// Implements [`TPM_DEVICE_INFO`].
+// ref: [`TPM_DEVICE_INFO`](https://learn.microsoft.com/en-us/windows/win32/api/tbs/ns-tbs-tpm_device_info)
+#[allow(non_snake_case)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(C)]
+pub struct TPM_DEVICE_INFO {
+    pub structVersion: UINT32,
+    pub tpmVersion: UINT32,
+    pub tpmInterfaceType: UINT32,
+    pub tpmImpRevision: UINT32,
+}
+
 //===
 
 //#region unsafe code
@@ -84,6 +146,18 @@ pub fn create_OSVERSIONINFOEXW(
     Ok(os_info)
 }
 
+// create_SP_DEVINFO_DATA
+/// *Returns* an owned, mutable [`SP_DEVINFO_DATA`] structure (fully initialized).
+// ref: [`SP_DEVINFO_DATA`](https://learn.microsoft.com/en-us/windows/win32/api/setupapi/ns-setupapi-sp_devinfo_data)
+#[allow(non_snake_case)]
+pub fn create_SP_DEVINFO_DATA() -> Result<SP_DEVINFO_DATA, crate::lib_impl::BoxedThreadSafeStdError>
+{
+    let device_info_data_size = DWORD::try_from(mem::size_of::<SP_DEVINFO_DATA>())?;
+    let mut device_info_data: SP_DEVINFO_DATA = unsafe { mem::zeroed() };
+    device_info_data.cbSize = device_info_data_size;
+    Ok(device_info_data)
+}
+
 // NOTE: WinAPI_... functions are thin-wrapper translations of the underlying WinOS API functions into safe functions
 
 // WinAPI_FreeLibrary
@@ -157,7 +231,6 @@ where
 /// *Returns* a pseudo handle for the current process.
 ///
 /// Wraps WinOS [`Kernel32/GetCurrentProcess()`](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-getcurrentprocess).
-#[allow(dead_code)] // * fn is used by test(s)
 #[allow(non_snake_case)]
 pub fn WinAPI_GetCurrentProcess() -> HANDLE {
     // GetCurrentProcess
@@ -171,51 +244,94 @@ pub fn WinAPI_GetCurrentProcess() -> HANDLE {
 /// If version information is available, GetFileVersionInfoSize returns the size, in bytes, of that information.
 ///
 /// *Returns* DWORD ~ zero for fn *failure*; o/w size of the file version information, in *bytes*, for fn *success*.
+/// <br> An `InvalidInput` error is returned (without calling the WinOS API) if `file_path` contains an interior NUL.
 ///
 /// Wraps WinOS [`Version/GetFileVersionInfoSizeW(...)`](https://learn.microsoft.com/en-us/windows/win32/api/winver/nf-winver-getfileversioninfosizew).
+#[cfg(not(feature = "no-file-fallback"))]
 #[allow(non_snake_case)]
 pub fn WinAPI_GetFileVersionInfoSizeW<P: AsRef<PathStr>>(
     file_path: P, /* used to generate `lptstrFilename: LPCWSTR` */ // lpdwHandle: *mut DWORD, /* ignored/not-needed */
-) -> DWORD {
+) -> Result<DWORD, WinOSError> {
     // GetFileVersionInfoSizeW
     // pub unsafe fn GetFileVersionInfoSizeW(lptstrFilename: LPCWSTR, lpdwHandle: *mut DWORD) -> DWORD
     // ref: <https://learn.microsoft.com/en-us/windows/win32/api/winver/nf-winver-getfileversioninfosizew> @@ <https://archive.is/AdMHL>
     // * returns DWORD ~ on *failure*, 0
     // * returns DWORD ~ on *success*, size of the file version information, in *bytes*
-    let file_path_cws: CWSTR = to_c_wstring(file_path.as_ref());
-    unsafe {
+    let file_path_cws: CWSTR = try_to_c_wstring(file_path.as_ref())?;
+    Ok(unsafe {
         GetFileVersionInfoSizeW(file_path_cws.as_ptr(), ptr::null_mut() /* ignored */)
-    }
+    })
 }
 
 // WinAPI_GetFileVersionInfoW
 /// Retrieves version information for the specified file (`file_path`); stored into BYTE vector (`data`).
 ///
 /// *Returns* BOOL ~ `FALSE` (aka zero) for fn *failure*; o/w non-`FALSE` (aka non-zero) for fn *success*.
+/// <br> An `InvalidInput` error is returned (without calling the WinOS API) if `file_path` contains an interior NUL.
 ///
 /// Wraps WinOS [`Version/GetFileVersionInfoW(...)`](https://learn.microsoft.com/en-us/windows/win32/api/winver/nf-winver-getfileversioninfow).
+#[cfg(not(feature = "no-file-fallback"))]
 #[allow(non_snake_case)]
 pub fn WinAPI_GetFileVersionInfoW<P: AsRef<PathStr>>(
     file_path: P, /* used to generate `lptstrFilename: LPCWSTR` */
     // dwHandle: DWORD, /* ignored/not-needed */
     // dwLen: DWORD,  /* not-needed */
     data: &mut Vec<BYTE>, /* from `lpData: *mut winapi::ctypes::c_void` */
-) -> BOOL {
+) -> Result<BOOL, WinOSError> {
     // GetFileVersionInfoW
     // pub unsafe fn GetFileVersionInfoW(lptstrFilename: LPCWSTR, dwHandle: DWORD, dwLen: DWORD, lpData: *mut c_void) -> BOOL
     // ref: <https://learn.microsoft.com/en-us/windows/win32/api/winver/nf-winver-getfileversioninfow> @@ <https://archive.is/4rx6D>
     // * handle/dwHandle == *ignored*
     // * length/dwLen == maximum size (in bytes) of buffer at data_ptr/lpData
     // * *returns* BOOL ~ `FALSE` (aka zero) for fn *failure*, o/w non-`FALSE` (aka non-zero) for fn *success*
-    let file_path_cws: CWSTR = to_c_wstring(file_path.as_ref());
-    unsafe {
+    let file_path_cws: CWSTR = try_to_c_wstring(file_path.as_ref())?;
+    Ok(unsafe {
         GetFileVersionInfoW(
             file_path_cws.as_ptr(),
             0, /* ignored */
             DWORD::try_from(data.capacity()).unwrap(),
             data.as_mut_ptr() as *mut _,
         )
-    }
+    })
+}
+
+// WinAPI_GetLogicalProcessorInformationEx
+/// Retrieves information about the relationships (of type `relationship`) of logical processors and related hardware;
+/// stored into a BYTE vector (`buffer`) as a series of variable-sized `SYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX`
+/// structures.
+///
+/// * `length` ~ (out)
+///   - for non-`FALSE` return, contains the number of bytes written to the destination buffer
+///   - for `FALSE` return with an inadequate buffer size (including a zero-length or `None` `buffer`), contains the
+///     required buffer size, in bytes
+///
+/// *Returns* BOOL ~ `FALSE` (aka zero) for fn *failure*; o/w non-`FALSE` (aka non-zero) for fn *success*.
+///
+/// Wraps WinOS [`Kernel32/GetLogicalProcessorInformationEx(...)`](https://learn.microsoft.com/en-us/windows/win32/api/sysinfoapi/nf-sysinfoapi-getlogicalprocessorinformationex).
+#[allow(non_snake_case)]
+pub fn WinAPI_GetLogicalProcessorInformationEx<'a, T>(
+    relationship: LOGICAL_PROCESSOR_RELATIONSHIP, /* from `RelationshipType: LOGICAL_PROCESSOR_RELATIONSHIP` */
+    buffer: T,          /* from `Buffer: PSYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX` */
+    length: &mut DWORD, /* from `ReturnedLength: PDWORD` */
+) -> BOOL
+where
+    T: Into<Option<&'a mut Vec<BYTE>>>,
+{
+    // GetLogicalProcessorInformationEx
+    // pub unsafe fn GetLogicalProcessorInformationEx(RelationshipType: LOGICAL_PROCESSOR_RELATIONSHIP, Buffer: PSYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX, ReturnedLength: PDWORD) -> BOOL
+    // ref: <https://learn.microsoft.com/en-us/windows/win32/api/sysinfoapi/nf-sysinfoapi-getlogicalprocessorinformationex>
+    // * `ReturnedLength` ~ (in) specifies the size of the destination buffer (*Buffer) in bytes
+    // * `ReturnedLength` ~ (out) on *fn success*, receives the number of bytes written to the buffer; on *fn failure* (with `ERROR_INSUFFICIENT_BUFFER`), receives the required buffer size
+    // * *returns* BOOL ~ `FALSE` (aka zero) for fn *failure*; o/w non-`FALSE` (aka non-zero) for fn *success*
+    let (buffer_ptr, buffer_length) = match buffer.into() {
+        Some(buf) => (buf.as_mut_ptr(), DWORD::try_from(buf.len()).unwrap_or(0)),
+        None => (ptr::null_mut(), 0),
+    };
+    *length = buffer_length;
+    let result =
+        unsafe { GetLogicalProcessorInformationEx(relationship, buffer_ptr as *mut _, length) };
+    assert!((result == FALSE) || (*length <= buffer_length)); // safety sanity check; panics on out-of-bounds memory writes (buffer overrun)
+    result
 }
 
 // WinAPI_GetNativeSystemInfo
@@ -240,8 +356,39 @@ pub fn WinAPI_GetNativeSystemInfo() -> SYSTEM_INFO {
     }
 }
 
+// WinAPI_GetTickCount64
+/// *Returns* the number of milliseconds elapsed since the system was started.
+///
+/// Wraps WinOS [`Kernel32/GetTickCount64()`](https://learn.microsoft.com/en-us/windows/win32/api/sysinfoapi/nf-sysinfoapi-gettickcount64).
+#[cfg(feature = "uptime")]
+#[allow(non_snake_case)]
+pub fn WinAPI_GetTickCount64() -> u64 {
+    // GetTickCount64
+    // pub unsafe fn GetTickCount64() -> ULONGLONG
+    unsafe { GetTickCount64() }
+}
+
+// WinAPI_GetDynamicTimeZoneInformation
+/// *Returns* the current time zone and dynamic daylight saving time settings.
+///
+/// Wraps WinOS [`Kernel32/GetDynamicTimeZoneInformation(...)`](https://learn.microsoft.com/en-us/windows/win32/api/timezoneapi/nf-timezoneapi-getdynamictimezoneinformation).
+#[cfg(feature = "timezone")]
+#[allow(non_snake_case)]
+pub fn WinAPI_GetDynamicTimeZoneInformation() -> Result<DYNAMIC_TIME_ZONE_INFORMATION, WinOSError> {
+    // GetDynamicTimeZoneInformation
+    // pub unsafe fn GetDynamicTimeZoneInformation(pTimeZoneInformation: PDYNAMIC_TIME_ZONE_INFORMATION) -> DWORD
+    let mut info = MaybeUninit::<DYNAMIC_TIME_ZONE_INFORMATION>::zeroed();
+    let result = unsafe { GetDynamicTimeZoneInformation(info.as_mut_ptr()) };
+    if result == TIME_ZONE_ID_INVALID {
+        return Err(Box::new(io::Error::last_os_error()));
+    }
+    // SAFETY: `GetDynamicTimeZoneInformation()` succeeded => `info` was initialized (and was zero-filled beforehand)
+    Ok(unsafe { info.assume_init() })
+}
+
 // WinAPI_GetProcAddress
 /// *Returns* the address of an exported function/procedure or variable (`symbol_name`) from the specified library (`module`).
+/// <br> A null address is returned (without calling the WinOS API) if `symbol_name` contains an interior NUL.
 ///
 /// Wraps WinOS [`Kernel32/GetProcAddress(...)`](https://learn.microsoft.com/en-us/windows/win32/api/libloaderapi/nf-libloaderapi-getprocaddress).
 #[allow(non_snake_case)]
@@ -252,8 +399,42 @@ pub fn WinAPI_GetProcAddress<P: AsRef<PathStr>>(
     // GetProcAddress
     // pub unsafe fn GetProcAddress(hModule: HMODULE, lpProcName: LPCSTR) -> FARPROC
     // ref: <https://learn.microsoft.com/en-us/windows/win32/api/libloaderapi/nf-libloaderapi-getprocaddress> @@ <https://archive.is/ZPVMr>
-    let symbol_name_cs = to_c_string(symbol_name.as_ref());
-    unsafe { GetProcAddress(module, symbol_name_cs.as_ptr()) }
+    match try_to_c_string(symbol_name.as_ref()) {
+        Ok(symbol_name_cs) => unsafe { GetProcAddress(module, symbol_name_cs.as_ptr()) },
+        Err(_) => ptr::null_mut(), // * a truncated name might find a different symbol
+    }
+}
+
+// WinAPI_GetSystemFirmwareTable
+/// Retrieves the specified firmware table (`provider`/`table_id`) from the firmware table provider; stored into a BYTE
+/// vector (`buffer`).
+///
+/// *Returns* UINT
+///   - zero for fn *failure*
+///   - fn *success* with adequate buffer size, contains the number of bytes copied to the destination buffer
+///   - fn *success* with inadequate buffer size (including a zero-length or `None` `buffer`), contains the required buffer size, in bytes
+///
+/// Wraps WinOS [`Kernel32/GetSystemFirmwareTable(...)`](https://learn.microsoft.com/en-us/windows/win32/api/sysinfoapi/nf-sysinfoapi-getsystemfirmwaretable).
+#[allow(non_snake_case)]
+pub fn WinAPI_GetSystemFirmwareTable<'a, T>(
+    provider: DWORD, /* from `FirmwareTableProviderSignature: DWORD` */
+    table_id: DWORD, /* from `FirmwareTableID: DWORD` */
+    buffer: T,       /* from `pFirmwareTableBuffer: PVOID` */
+) -> UINT
+where
+    T: Into<Option<&'a mut Vec<BYTE>>>,
+{
+    // GetSystemFirmwareTable
+    // pub unsafe fn GetSystemFirmwareTable(FirmwareTableProviderSignature: DWORD, FirmwareTableID: DWORD, pFirmwareTableBuffer: PVOID, BufferSize: DWORD) -> UINT
+    // ref: <https://learn.microsoft.com/en-us/windows/win32/api/sysinfoapi/nf-sysinfoapi-getsystemfirmwaretable>
+    // * `BufferSize` ~ (in) specifies the size of the destination buffer (*pFirmwareTableBuffer) in bytes
+    // * returns UINT ~ on fn *failure*, 0
+    // * returns UINT ~ on fn *success*, the number of bytes written to the buffer (or, if the buffer is too small, the required buffer size)
+    let (buffer_ptr, length) = match buffer.into() {
+        Some(buf) => (buf.as_mut_ptr(), DWORD::try_from(buf.len()).unwrap_or(0)),
+        None => (ptr::null_mut(), 0),
+    };
+    unsafe { GetSystemFirmwareTable(provider, table_id, buffer_ptr as *mut _, length) }
 }
 
 // WinAPI_GetSystemDirectoryW
@@ -299,6 +480,44 @@ where
     unsafe { GetSystemDirectoryW(buffer_ptr, length) }
 }
 
+// WinAPI_GetVolumeInformationW
+/// Retrieves the name of the file system of the volume with the specified root directory (`root_path_name`; eg,
+/// `C:\`); stored into a WCHAR vector (`file_system_name`).
+///
+/// * `file_system_name` ~ (out)
+///   - for non-`FALSE` return, contains the file system name (eg, "NTFS") as a NUL-terminated WSTR
+///   - for `FALSE` return, undefined
+///
+/// *Returns* BOOL ~ `FALSE` (aka zero) for fn *failure*; o/w non-`FALSE` (aka non-zero) for fn *success*.
+/// <br> An `InvalidInput` error is returned (without calling the WinOS API) if `root_path_name` contains an interior
+/// NUL.
+///
+/// Wraps WinOS [`Kernel32/GetVolumeInformationW(...)`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-getvolumeinformationw).
+#[allow(non_snake_case)]
+pub fn WinAPI_GetVolumeInformationW<P: AsRef<PathStr>>(
+    root_path_name: P,                 /* used to generate `lpRootPathName: LPCWSTR` */
+    file_system_name: &mut Vec<WCHAR>, /* from `lpFileSystemNameBuffer: LPWSTR` */
+) -> Result<BOOL, WinOSError> {
+    // GetVolumeInformationW
+    // pub unsafe fn GetVolumeInformationW(lpRootPathName: LPCWSTR, lpVolumeNameBuffer: LPWSTR, nVolumeNameSize: DWORD, lpVolumeSerialNumber: LPDWORD, lpMaximumComponentLength: LPDWORD, lpFileSystemFlags: LPDWORD, lpFileSystemNameBuffer: LPWSTR, nFileSystemNameSize: DWORD) -> BOOL
+    // ref: <https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-getvolumeinformationw>
+    // * `nFileSystemNameSize` ~ (in) specifies the size of the file system name buffer in WCHARs (maximum length is `MAX_PATH + 1`)
+    let root_path_name_cws: CWSTR = try_to_c_wstring(root_path_name.as_ref())?;
+    let length = DWORD::try_from(file_system_name.len()).unwrap_or(0);
+    Ok(unsafe {
+        GetVolumeInformationW(
+            root_path_name_cws.as_ptr(),
+            ptr::null_mut(), /* `lpVolumeNameBuffer` ~ not needed */
+            0,
+            ptr::null_mut(), /* `lpVolumeSerialNumber` ~ not needed */
+            ptr::null_mut(), /* `lpMaximumComponentLength` ~ not needed */
+            ptr::null_mut(), /* `lpFileSystemFlags` ~ not needed */
+            file_system_name.as_mut_ptr(),
+            length,
+        )
+    })
+}
+
 // WinAPI_LoadLibrary
 /// *Returns* a module handle for the specified module (`module_name`), loading the library, if needed, and increasing
 /// the per-process reference count.
@@ -325,6 +544,238 @@ pub fn WinAPI_LoadLibrary<P: AsRef<PathStr>>(
     unsafe { LoadLibraryW(module_name_cws.as_ptr()) }
 }
 
+// WinAPI_RegGetValueW
+/// Retrieves the data for the specified registry value (`value_name`) within the registry key (`key`/`sub_key`);
+/// stored into a WCHAR vector (`buffer`).
+///
+/// * `flags` ~ (in) restricts the data type of the value to be queried (eg, `RRF_RT_REG_SZ`)
+/// * `buffer` ~ (out)
+///   - for `ERROR_SUCCESS` return, contains the requested registry value data (of length `size`, in *bytes*)
+///   - for other returns, unchanged
+/// * `size` ~ (out)
+///   - for `ERROR_SUCCESS` return, contains the size of the data copied to the destination buffer, in *bytes* (including any terminating null character)
+///   - for `ERROR_MORE_DATA` return (or for a zero-length or `None` `buffer`), contains the required buffer size, in *bytes*
+///
+/// *Returns* LSTATUS ~ `ERROR_SUCCESS` (aka zero) for fn *success*; o/w a non-zero error code.
+///
+/// Wraps WinOS [`Advapi32/RegGetValueW(...)`](https://learn.microsoft.com/en-us/windows/win32/api/winreg/nf-winreg-reggetvaluew).
+#[allow(non_snake_case)]
+pub fn WinAPI_RegGetValueW<'a, P, S, T>(
+    key: HKEY,        /* from `hkey: HKEY` */
+    sub_key: P,       /* used to generate `lpSubKey: LPCWSTR` */
+    value_name: S,    /* used to generate `lpValue: LPCWSTR` */
+    flags: DWORD,     /* from `dwFlags: DWORD` */
+    buffer: T,        /* from `pvData: PVOID` */
+    size: &mut DWORD, /* from `pcbData: LPDWORD` */
+) -> LSTATUS
+where
+    P: AsRef<PathStr>,
+    S: AsRef<PathStr>,
+    T: Into<Option<&'a mut Vec<WCHAR>>>,
+{
+    // RegGetValueW
+    // pub unsafe fn RegGetValueW(hkey: HKEY, lpSubKey: LPCWSTR, lpValue: LPCWSTR, dwFlags: DWORD, pdwType: LPDWORD, pvData: PVOID, pcbData: LPDWORD) -> LSTATUS
+    // ref: <https://learn.microsoft.com/en-us/windows/win32/api/winreg/nf-winreg-reggetvaluew>
+    // * `pcbData` ~ (in) specifies the size of the destination buffer (*pvData) in *bytes*
+    // * `pcbData` ~ (out) size of the data (or required buffer size), in *bytes*
+    // * returns LSTATUS ~ `ERROR_SUCCESS` (aka zero) for fn *success*; o/w a non-zero error code (eg, `ERROR_MORE_DATA` or `ERROR_FILE_NOT_FOUND`)
+    let sub_key_cws: CWSTR = to_c_wstring(sub_key.as_ref());
+    let value_name_cws: CWSTR = to_c_wstring(value_name.as_ref());
+    let (buffer_ptr, length) = match buffer.into() {
+        Some(buf) => (
+            buf.as_mut_ptr(),
+            DWORD::try_from(buf.len() * mem::size_of::<WCHAR>()).unwrap_or(0),
+        ),
+        None => (ptr::null_mut(), 0),
+    };
+    *size = length;
+    let result = unsafe {
+        RegGetValueW(
+            key,
+            sub_key_cws.as_ptr(),
+            value_name_cws.as_ptr(),
+            flags,
+            ptr::null_mut(), /* `pdwType` ~ not needed */
+            buffer_ptr as *mut _,
+            size,
+        )
+    };
+    // * a null buffer is a size query (`*size` is set to the required size; nothing is written)
+    assert!((result != 0/* ERROR_SUCCESS */) || buffer_ptr.is_null() || (*size <= length)); // safety sanity check; panics on out-of-bounds memory writes (buffer overrun)
+    result
+}
+
+// WinOsRegEnumSubKeyNames
+/// *Returns* the names of the subkeys of the registry key `key`/`sub_key` (eg, `HKEY_LOCAL_MACHINE` and
+/// `SYSTEM\CurrentControlSet\Control\Class\{...}`), in enumeration order.
+///
+/// Wraps WinOS [`Advapi32/RegOpenKeyExW(...)`](https://learn.microsoft.com/en-us/windows/win32/api/winreg/nf-winreg-regopenkeyexw)
+/// and [`Advapi32/RegEnumKeyExW(...)`](https://learn.microsoft.com/en-us/windows/win32/api/winreg/nf-winreg-regenumkeyexw).
+#[allow(non_snake_case)]
+pub fn WinOsRegEnumSubKeyNames<P: AsRef<PathStr>>(
+    key: HKEY,
+    sub_key: P,
+) -> Result<Vec<OsString>, WinOSError> {
+    // ref: <https://learn.microsoft.com/en-us/windows/win32/sysinfo/registry-element-size-limits>
+    // * key names are limited to 255 characters
+    const MAX_KEY_NAME_LENGTH: usize = 255;
+
+    let sub_key_cws: CWSTR = try_to_c_wstring(sub_key.as_ref())?;
+    let mut opened_key: HKEY = ptr::null_mut();
+    let result = unsafe {
+        RegOpenKeyExW(
+            key,
+            sub_key_cws.as_ptr(),
+            0,
+            KEY_ENUMERATE_SUB_KEYS,
+            &mut opened_key,
+        )
+    };
+    if result != ERROR_SUCCESS as LSTATUS {
+        return Err(Box::new(io::Error::from_raw_os_error(result)));
+    }
+
+    let mut names = Vec::new();
+    let mut name = vec![0 as WCHAR; MAX_KEY_NAME_LENGTH + 1];
+    let mut status = ERROR_SUCCESS as LSTATUS;
+    for index in 0.. {
+        // * `lpcchName` ~ (in) buffer size, in characters (including the terminating NUL); (out) name length
+        let mut length = DWORD::try_from(name.len()).unwrap_or(0);
+        status = unsafe {
+            RegEnumKeyExW(
+                opened_key,
+                index,
+                name.as_mut_ptr(),
+                &mut length,
+                ptr::null_mut(),
+                ptr::null_mut(),
+                ptr::null_mut(),
+                ptr::null_mut(),
+            )
+        };
+        if status != ERROR_SUCCESS as LSTATUS {
+            break;
+        }
+        let length = usize::try_from(length).unwrap_or(0).min(name.len());
+        names.push(OsString::from_wide(&name[..length]));
+    }
+    let _ = unsafe { RegCloseKey(opened_key) }; // RegCloseKey() failure/success can be safely ignored
+
+    if status == ERROR_NO_MORE_ITEMS as LSTATUS {
+        Ok(names)
+    } else {
+        Err(Box::new(io::Error::from_raw_os_error(status)))
+    }
+}
+
+// WinAPI_SetupDiDestroyDeviceInfoList
+/// Deletes a device information set (`device_info_set`) and frees all associated memory.
+///
+/// *Returns* BOOL ~ `FALSE` (aka zero) for fn *failure*; o/w non-`FALSE` (aka non-zero) for fn *success*.
+///
+/// Wraps WinOS [`SetupAPI/SetupDiDestroyDeviceInfoList(...)`](https://learn.microsoft.com/en-us/windows/win32/api/setupapi/nf-setupapi-setupdidestroydeviceinfolist).
+#[allow(non_snake_case)]
+pub fn WinAPI_SetupDiDestroyDeviceInfoList(
+    device_info_set: HDEVINFO, /* from `DeviceInfoSet: HDEVINFO` */
+) -> BOOL {
+    // SetupDiDestroyDeviceInfoList
+    // pub unsafe fn SetupDiDestroyDeviceInfoList(DeviceInfoSet: HDEVINFO) -> BOOL
+    // ref: <https://learn.microsoft.com/en-us/windows/win32/api/setupapi/nf-setupapi-setupdidestroydeviceinfolist>
+    unsafe { SetupDiDestroyDeviceInfoList(device_info_set) }
+}
+
+// WinAPI_SetupDiEnumDeviceInfo
+/// Retrieves information about the device at position `index` within a device information set (`device_info_set`);
+/// stored into `device_info_data`.
+///
+/// *Returns* BOOL ~ `FALSE` (aka zero) for fn *failure* (including `ERROR_NO_MORE_ITEMS` for an `index` beyond the end
+/// of the set); o/w non-`FALSE` (aka non-zero) for fn *success*.
+///
+/// Wraps WinOS [`SetupAPI/SetupDiEnumDeviceInfo(...)`](https://learn.microsoft.com/en-us/windows/win32/api/setupapi/nf-setupapi-setupdienumdeviceinfo).
+#[allow(non_snake_case)]
+pub fn WinAPI_SetupDiEnumDeviceInfo(
+    device_info_set: HDEVINFO,              /* from `DeviceInfoSet: HDEVINFO` */
+    index: DWORD,                           /* from `MemberIndex: DWORD` */
+    device_info_data: &mut SP_DEVINFO_DATA, /* from `DeviceInfoData: PSP_DEVINFO_DATA` */
+) -> BOOL {
+    // SetupDiEnumDeviceInfo
+    // pub unsafe fn SetupDiEnumDeviceInfo(DeviceInfoSet: HDEVINFO, MemberIndex: DWORD, DeviceInfoData: PSP_DEVINFO_DATA) -> BOOL
+    // ref: <https://learn.microsoft.com/en-us/windows/win32/api/setupapi/nf-setupapi-setupdienumdeviceinfo>
+    // * `DeviceInfoData.cbSize` must be initialized (see `create_SP_DEVINFO_DATA()`)
+    unsafe { SetupDiEnumDeviceInfo(device_info_set, index, device_info_data) }
+}
+
+// WinAPI_SetupDiGetClassDevsW
+/// Retrieves a device information set containing the devices of the PnP `enumerator` (eg, "HID") which match `flags`.
+///
+/// *Returns* HDEVINFO ~ a handle to the device information set for fn *success*; o/w `INVALID_HANDLE_VALUE`.
+///
+/// Wraps WinOS [`SetupAPI/SetupDiGetClassDevsW(...)`](https://learn.microsoft.com/en-us/windows/win32/api/setupapi/nf-setupapi-setupdigetclassdevsw).
+#[allow(non_snake_case)]
+pub fn WinAPI_SetupDiGetClassDevsW<P: AsRef<PathStr>>(
+    enumerator: P, /* used to generate `Enumerator: PCWSTR` */
+    flags: DWORD,  /* from `Flags: DWORD` */
+) -> HDEVINFO {
+    // SetupDiGetClassDevsW
+    // pub unsafe fn SetupDiGetClassDevsW(ClassGuid: *const GUID, Enumerator: PCWSTR, hwndParent: HWND, Flags: DWORD) -> HDEVINFO
+    // ref: <https://learn.microsoft.com/en-us/windows/win32/api/setupapi/nf-setupapi-setupdigetclassdevsw>
+    // * `ClassGuid` ~ `NULL` (with `DIGCF_ALLCLASSES`) selects devices of all setup classes
+    let enumerator_cws: CWSTR = to_c_wstring(enumerator.as_ref());
+    unsafe { SetupDiGetClassDevsW(ptr::null(), enumerator_cws.as_ptr(), ptr::null_mut(), flags) }
+}
+
+// WinAPI_SetupDiGetDeviceRegistryPropertyW
+/// Retrieves the specified PnP device `property` (eg, `SPDRP_HARDWAREID`) of a device (`device_info_set`/
+/// `device_info_data`); stored into a WCHAR vector (`buffer`).
+///
+/// * `buffer` ~ (out)
+///   - for non-`FALSE` return, contains the requested (string or multi-string) property data (of length `size`, in *bytes*)
+///   - for `FALSE` return, unchanged
+/// * `size` ~ (out)
+///   - for non-`FALSE` return, contains the size of the data copied to the destination buffer, in *bytes*
+///   - for `FALSE` return with `ERROR_INSUFFICIENT_BUFFER` (or for a zero-length or `None` `buffer`), contains the required buffer size, in *bytes*
+///
+/// *Returns* BOOL ~ `FALSE` (aka zero) for fn *failure*; o/w non-`FALSE` (aka non-zero) for fn *success*.
+///
+/// Wraps WinOS [`SetupAPI/SetupDiGetDeviceRegistryPropertyW(...)`](https://learn.microsoft.com/en-us/windows/win32/api/setupapi/nf-setupapi-setupdigetdeviceregistrypropertyw).
+#[allow(non_snake_case)]
+pub fn WinAPI_SetupDiGetDeviceRegistryPropertyW<'a, T>(
+    device_info_set: HDEVINFO,              /* from `DeviceInfoSet: HDEVINFO` */
+    device_info_data: &mut SP_DEVINFO_DATA, /* from `DeviceInfoData: PSP_DEVINFO_DATA` */
+    property: DWORD,                        /* from `Property: DWORD` */
+    buffer: T,                              /* from `PropertyBuffer: PBYTE` */
+    size: &mut DWORD,                       /* from `RequiredSize: PDWORD` */
+) -> BOOL
+where
+    T: Into<Option<&'a mut Vec<WCHAR>>>,
+{
+    // SetupDiGetDeviceRegistryPropertyW
+    // pub unsafe fn SetupDiGetDeviceRegistryPropertyW(DeviceInfoSet: HDEVINFO, DeviceInfoData: PSP_DEVINFO_DATA, Property: DWORD, PropertyRegDataType: PDWORD, PropertyBuffer: PBYTE, PropertyBufferSize: DWORD, RequiredSize: PDWORD) -> BOOL
+    // ref: <https://learn.microsoft.com/en-us/windows/win32/api/setupapi/nf-setupapi-setupdigetdeviceregistrypropertyw>
+    // * `PropertyBufferSize` ~ (in) specifies the size of the destination buffer (*PropertyBuffer) in *bytes*
+    // * `RequiredSize` ~ (out) size of the data (or required buffer size), in *bytes*
+    let (buffer_ptr, length) = match buffer.into() {
+        Some(buf) => (
+            buf.as_mut_ptr(),
+            DWORD::try_from(buf.len() * mem::size_of::<WCHAR>()).unwrap_or(0),
+        ),
+        None => (ptr::null_mut(), 0),
+    };
+    let result = unsafe {
+        SetupDiGetDeviceRegistryPropertyW(
+            device_info_set,
+            device_info_data,
+            property,
+            ptr::null_mut(), /* `PropertyRegDataType` ~ not needed */
+            buffer_ptr as *mut _,
+            length,
+            size,
+        )
+    };
+    assert!((result == FALSE) || (*size <= length)); // safety sanity check; panics on out-of-bounds memory writes (buffer overrun)
+    result
+}
+
 // WinAPI_VerifyVersionInfoW
 /// Compares a set of operating system version requirements (`version_info`, `type_mask`, and `condition_mask`) to the
 /// corresponding values for the currently running version of the system.
@@ -332,6 +783,7 @@ pub fn WinAPI_LoadLibrary<P: AsRef<PathStr>>(
 /// *Returns* BOOL ~ `FALSE` (aka zero) if resource is non-existent or requirements are not met; o/w non-`FALSE` (aka non-zero)
 ///
 /// Wraps WinOS [`Kernel32/VerifyVersionInfoW(...)`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-verifyversioninfow).
+#[cfg(not(feature = "no-file-fallback"))]
 #[allow(non_snake_case)]
 pub fn WinAPI_VerifyVersionInfoW(
     version_info: &OSVERSIONINFOEXW, /* from `lpVersionInformation: LPOSVERSIONINFOEXW` */
@@ -362,6 +814,7 @@ pub fn WinAPI_VerifyVersionInfoW(
 /// *Returns* BOOL ~ `FALSE` (aka zero) for fn *failure*; o/w non-zero for fn *success*.
 ///
 /// Wraps WinOS [`Version/VerQueryValueW(...)`](https://learn.microsoft.com/en-us/windows/win32/api/winver/nf-winver-verqueryvaluew).
+#[cfg(not(feature = "no-file-fallback"))]
 #[allow(non_snake_case)]
 pub fn WinAPI_VerQueryValueW<'a, S: AsRef<str>>(
     version_info: &'a [BYTE],       /* from `pBlock: LPCVOID` */
@@ -399,6 +852,7 @@ pub fn WinAPI_VerQueryValueW<'a, S: AsRef<str>>(
 /// *Returns* ULONGLONG ~ updated condition_mask
 ///
 /// Wraps WinOS [`Kernel32/VerSetConditionMask(...)`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/nf-winnt-versetconditionmask).
+#[cfg(not(feature = "no-file-fallback"))]
 #[allow(non_snake_case)]
 pub fn WinAPI_VerSetConditionMask(
     condition_mask: ULONGLONG,
@@ -415,15 +869,62 @@ pub fn WinAPI_VerSetConditionMask(
     unsafe { sysinfoapi::VerSetConditionMask(condition_mask, type_mask, condition) }
 }
 
+// WinOsGetAddrInfoCanonicalName
+/// *Returns* the canonical (ie, fully qualified) DNS name of `node_name`, as resolved by `GetAddrInfoW()` (using
+/// `AI_CANONNAME`).
+///
+/// Note: name resolution *may* block (eg, while awaiting a DNS server response).
+#[allow(non_snake_case)]
+pub fn WinOsGetAddrInfoCanonicalName(node_name: &OsStr) -> Result<OsString, WinOSError> {
+    // ref: <https://learn.microsoft.com/en-us/windows/win32/api/winsock2/nf-winsock2-wsastartup>
+    // ref: <https://learn.microsoft.com/en-us/windows/win32/api/ws2tcpip/nf-ws2tcpip-getaddrinfow>
+    // * a name with an interior NUL is rejected (rather than truncated and resolved)
+    let node_name_cws: CWSTR = try_to_c_wstring(node_name)?;
+
+    // * `WSAStartup()` must succeed before any other Winsock function call; each success requires a `WSACleanup()`
+    let mut wsa_data: WSADATA = unsafe { mem::zeroed() };
+    let result = unsafe { WSAStartup(MAKEWORD(2, 2), &mut wsa_data) };
+    if result != 0 {
+        return Err(Box::new(io::Error::from_raw_os_error(result)));
+    }
+
+    let mut hints: ADDRINFOW = unsafe { mem::zeroed() };
+    hints.ai_flags = AI_CANONNAME;
+    hints.ai_family = AF_UNSPEC;
+    let mut info: PADDRINFOW = ptr::null_mut();
+    let result = unsafe { GetAddrInfoW(node_name_cws.as_ptr(), ptr::null(), &hints, &mut info) };
+    let canonical_name = if result != 0 {
+        Err(Box::new(io::Error::from_raw_os_error(result)) as WinOSError)
+    } else if info.is_null() || unsafe { (*info).ai_canonname }.is_null() {
+        Err("no canonical name".into())
+    } else {
+        // SAFETY: `ai_canonname` is a NUL-terminated WSTR (valid until `FreeAddrInfoW()`)
+        let name_ptr = unsafe { (*info).ai_canonname };
+        let length = (0..)
+            .take_while(|&i| unsafe { *name_ptr.add(i) } != 0)
+            .count();
+        Ok(OsString::from_wide(unsafe {
+            std::slice::from_raw_parts(name_ptr, length)
+        }))
+    };
+
+    if !info.is_null() {
+        unsafe { FreeAddrInfoW(info) };
+    }
+    let _ = unsafe { WSACleanup() };
+    canonical_name
+}
+
 // WinOsFileVersionInfoQuery_root
-/// *Returns* the "root" version information *as view of the internal [`VS_FIXEDFILEINFO`] structure* within the
+/// *Returns* the "root" version information *as a copy of the internal [`VS_FIXEDFILEINFO`] structure* within the
 /// specified version-information resource (`version_info`).
 ///
 /// Uses WinOS [`Version/WinAPI_VerQueryValueW(...)`](https://learn.microsoft.com/en-us/windows/win32/api/winver/nf-winver-verqueryvaluew).
+#[cfg(not(feature = "no-file-fallback"))]
 #[allow(non_snake_case)]
 pub fn WinOsFileVersionInfoQuery_root(
     version_info: &WinApiFileVersionInfo,
-) -> Result<&VS_FIXEDFILEINFO, WinOSError> {
+) -> Result<VS_FIXEDFILEINFO, WinOSError> {
     // NOTE: this function could be expanded to cover root, translation, and information queries by using an enum for a return value
 
     // VerQueryValueW
@@ -455,15 +956,19 @@ pub fn WinOsFileVersionInfoQuery_root(
     assert!(version_info_data.len() >= usize::try_from(data_view_size)?);
     assert!(data_view_size == fixed_file_info_size);
     assert!(!data_view.is_null());
-    // * lifetime of block/info is the same as input argument version_info
-    Ok(unsafe { &*(data_view as *const VS_FIXEDFILEINFO) })
+    // * view is within `version_info_data` (and valid for the lifetime of `version_info`)
+    // * decode (as little-endian) instead of casting, which would assume host byte order and alignment
+    let data = unsafe {
+        std::slice::from_raw_parts(data_view as *const BYTE, usize::try_from(data_view_size)?)
+    };
+    VS_FIXEDFILEINFO::from_le_bytes(data)
+        .ok_or_else(|| Box::from("Invalid VS_FIXEDFILEINFO structure"))
 }
 
 // KERNEL32_IsWow64Process
 /// *Returns* an assertion of whether the specified `process` is running under WOW64 on an Intel64 or x64 processor.
 ///
 /// Wraps [`Kernel32/IsWow64Process`](https://learn.microsoft.com/en-us/windows/win32/api/wow64apiset/nf-wow64apiset-iswow64process).
-#[allow(dead_code)] // * fn is used by test(s)
 #[allow(non_snake_case)]
 pub fn KERNEL32_IsWow64Process(process: HANDLE) -> Result<bool, WinOSError> {
     // kernel32.dll/IsWow64Process
@@ -493,6 +998,44 @@ pub fn KERNEL32_IsWow64Process(process: HANDLE) -> Result<bool, WinOSError> {
     Ok((result != FALSE/* func() succeeded` */) && (is_wow64 != FALSE))
 }
 
+// LOAD_LIBRARY_ATTEMPTS
+/// Maximum number of attempts to load a system DLL before failing.
+/// <br> Transient `LoadLibraryW()` failures have been seen on hardened (or AV-heavy) systems.
+const LOAD_LIBRARY_ATTEMPTS: usize = 3;
+
+// LOAD_LIBRARY_RETRY_DELAY
+/// Delay between attempts to load a system DLL.
+const LOAD_LIBRARY_RETRY_DELAY: std::time::Duration = std::time::Duration::from_millis(10);
+
+// load_library_with_retry
+/// *Returns* a (non-null) module handle for `module_path`, using `load` (eg, [`WinAPI_LoadLibrary`]) for up to
+/// `attempts` tries; o/w an error if all attempts fail.
+///
+/// A failed attempt returns a null handle, so no library handles are held (or leaked) between attempts.
+fn load_library_with_retry<F>(
+    module_path: &PathStr,
+    attempts: usize,
+    mut load: F,
+) -> Result<HMODULE, WinOSError>
+where
+    F: FnMut(&PathStr) -> HMODULE,
+{
+    for attempt in 1..=attempts {
+        let module = load(module_path);
+        if !module.is_null() {
+            return Ok(module);
+        }
+        if attempt < attempts {
+            std::thread::sleep(LOAD_LIBRARY_RETRY_DELAY);
+        }
+    }
+    Err(Box::from(format!(
+        "Unable to load DLL '{}' (after {} attempts)",
+        module_path.display(),
+        attempts
+    )))
+}
+
 // NTDLL_RtlGetVersion
 /// *Returns* version information about the currently running operating system.
 ///
@@ -508,9 +1051,12 @@ pub fn NTDLL_RtlGetVersion() -> Result<OSVERSIONINFOEXW, WinOSError> {
     let symbol_name = "RtlGetVersion";
     let module_path = super::WinOsGetSystemDirectory()?.join(module_file);
     // let func = super::WinOsGetModuleProcAddress(module_path, procedure); // loads module "permanently" (for the life of current process)
-    let module = WinAPI_LoadLibrary(module_path);
+    let module = load_library_with_retry(&module_path, LOAD_LIBRARY_ATTEMPTS, |path| {
+        WinAPI_LoadLibrary(path)
+    })?;
     let func = WinAPI_GetProcAddress(module, symbol_name);
     if func.is_null() {
+        let _ = WinAPI_FreeLibrary(module);
         return Err(Box::from(format!(
             "Unable to find DLL procedure '{}' within '{}'",
             symbol_name, module_file
@@ -538,11 +1084,56 @@ pub fn NTDLL_RtlGetVersion() -> Result<OSVERSIONINFOEXW, WinOSError> {
     }
 }
 
+// TBS_Tbsi_GetDeviceInfo
+/// *Returns* information about the version of the system TPM (Trusted Platform Module).
+///
+/// Wraps [`TBS/Tbsi_GetDeviceInfo`](https://learn.microsoft.com/en-us/windows/win32/api/tbs/nf-tbs-tbsi_getdeviceinfo).
+#[allow(non_snake_case)]
+pub fn TBS_Tbsi_GetDeviceInfo() -> Result<TPM_DEVICE_INFO, WinOSError> {
+    // tbs.dll/Tbsi_GetDeviceInfo
+    // extern "system" fn(UINT32, PVOID) -> TBS_RESULT
+    // ref: <https://learn.microsoft.com/en-us/windows/win32/api/tbs/nf-tbs-tbsi_getdeviceinfo>
+    // * returns TBS_RESULT (aka UINT32) ~ `TBS_SUCCESS` (aka zero) for fn *success*; o/w an error code (eg, `TBS_E_TPM_NOT_FOUND`)
+    let module_file = "tbs.dll";
+    let symbol_name = "Tbsi_GetDeviceInfo";
+    let module_path = super::WinOsGetSystemDirectory()?.join(module_file);
+    let module = load_library_with_retry(&module_path, LOAD_LIBRARY_ATTEMPTS, |path| {
+        WinAPI_LoadLibrary(path)
+    })?;
+    let func = WinAPI_GetProcAddress(module, symbol_name);
+    if func.is_null() {
+        let _ = WinAPI_FreeLibrary(module);
+        return Err(Box::from(format!(
+            "Unable to find DLL procedure '{}' within '{}'",
+            symbol_name, module_file
+        )));
+    }
+    let func: extern "system" fn(UINT32, LPVOID) -> UINT32 =
+        unsafe { mem::transmute(func as *const ()) };
+
+    let mut device_info: TPM_DEVICE_INFO = unsafe { mem::zeroed() };
+    let device_info_size = UINT32::try_from(mem::size_of::<TPM_DEVICE_INFO>())?;
+    let device_info_ptr: *mut TPM_DEVICE_INFO = &mut device_info;
+    let result = func(device_info_size, device_info_ptr as LPVOID);
+
+    let _ = WinAPI_FreeLibrary(module); // FreeLibrary() failure/success can be safely ignored
+
+    if result == 0 {
+        Ok(device_info)
+    } else {
+        Err(Box::from(format!(
+            "Tbsi_GetDeviceInfo() failed (result/status: {:#x})",
+            result
+        )))
+    }
+}
+
 //#endregion (unsafe code)
 
 //=== Tests
 
 #[test]
+#[cfg(not(feature = "no-file-fallback"))]
 fn structure_clone() {
     let ffi = VS_FIXEDFILEINFO {
         dwSignature: 0,
@@ -564,3 +1155,34 @@ fn structure_clone() {
     let ffi_clone = ffi.clone();
     assert_eq!(ffi_clone, ffi);
 }
+
+#[test]
+fn test_load_library_with_retry() {
+    let module_path = super::WinOsGetSystemDirectory().unwrap().join("ntdll.dll");
+
+    // mock loader which fails twice, then succeeds
+    let mut calls = 0;
+    let module = load_library_with_retry(&module_path, LOAD_LIBRARY_ATTEMPTS, |path| {
+        calls += 1;
+        if calls <= 2 {
+            ptr::null_mut()
+        } else {
+            WinAPI_LoadLibrary(path)
+        }
+    });
+    assert_eq!(calls, 3);
+    let module = module.unwrap();
+    assert!(!module.is_null());
+    assert_ne!(WinAPI_FreeLibrary(module), FALSE);
+
+    // mock loader which always fails
+    let mut calls = 0;
+    let result = load_library_with_retry(&module_path, LOAD_LIBRARY_ATTEMPTS, |_| {
+        calls += 1;
+        ptr::null_mut()
+    });
+    assert_eq!(calls, LOAD_LIBRARY_ATTEMPTS);
+    let err = result.unwrap_err();
+    println!("err={}", err);
+    assert!(err.to_string().contains("ntdll.dll"));
+}
@@ -1,5 +1,9 @@
 // spell-checker:ignore (API) nodename osname sysname
 
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::ffi::{OsStr, OsString};
+
 use platform_info::*;
 
 #[test]
@@ -38,10 +42,367 @@ fn platform_no_invisible_contents() -> Result<(), String> {
     let s = format!("sysname='{sysname}';nodename='{nodename}';release='{release}';version='{version}';machine='{machine}';osname='{osname}'");
     println!("s = [{}]\"{}\"", s.len(), s);
 
-    // let re = regex::Regex::new("[^[[:print:]]]").unwrap(); // matches invisible (and emojis)
-    let re = regex::Regex::new("[^[[:print:]]\\p{Other_Symbol}]").unwrap(); // matches invisible only (not emojis)
-    assert_eq!(re.find(&s), None);
+    // * `sanitized()` escapes any control (or invisible formatting) characters, so clean fields are left unchanged
+    let sanitized = info.sanitized();
+    for ((name, field), (_, sanitized_field)) in info.fields().iter().zip(sanitized.fields().iter())
+    {
+        assert_eq!(
+            field.to_string_lossy(),
+            sanitized_field.to_string_lossy(),
+            "`{}` contains control characters",
+            name
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn platform_sanitized() -> Result<(), String> {
+    let data = UnameData {
+        sysname: OsString::from("Linux"),
+        nodename: OsString::from("evil\x1b[2J\x1b]0;pwned\x07host"),
+        release: OsString::from("6.1.0\u{202E}"),
+        machine: OsString::from("x86_64"),
+        ..UnameData::default()
+    };
+    let sanitized = data.sanitized();
+    println!("sanitized = {}", sanitized);
+    assert_eq!(sanitized.sanitized(), sanitized);
+    assert_eq!(
+        sanitized.nodename(),
+        OsStr::new("evil\\u{1b}[2J\\u{1b}]0;pwned\\u{7}host")
+    );
+    assert_eq!(sanitized.release(), OsStr::new("6.1.0\\u{202e}"));
+    assert_eq!(sanitized.sysname(), data.sysname());
+    assert_eq!(sanitized.machine(), data.machine());
+    // raw accessors are unchanged
+    assert_eq!(
+        data.nodename(),
+        OsStr::new("evil\x1b[2J\x1b]0;pwned\x07host")
+    );
+
+    let info = PlatformInfo::new().unwrap();
+    assert_eq!(info.sanitized().nodename(), info.nodename());
+
+    Ok(())
+}
+
+#[test]
+fn platform_lossy() -> Result<(), String> {
+    let info = PlatformInfo::new().unwrap();
+
+    assert_eq!(info.sysname_lossy(), info.sysname().to_string_lossy());
+    assert_eq!(info.nodename_lossy(), info.nodename().to_string_lossy());
+    assert_eq!(info.release_lossy(), info.release().to_string_lossy());
+    assert_eq!(info.version_lossy(), info.version().to_string_lossy());
+    assert_eq!(info.machine_lossy(), info.machine().to_string_lossy());
+    assert_eq!(info.osname_lossy(), info.osname().to_string_lossy());
+
+    let s = format!("{} {}", info.sysname_lossy(), info.machine_lossy());
+    println!("s='{s}'");
+    assert!(!s.trim().is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn platform_str() -> Result<(), String> {
+    let data = UnameData {
+        sysname: OsString::from("Linux"),
+        nodename: OsString::from("host"),
+        release: OsString::from("6.1.0"),
+        version: OsString::from("#1 SMP"),
+        machine: OsString::from("x86_64"),
+        osname: OsString::from("GNU/Linux"),
+    };
+    assert_eq!(data.sysname_str(), Some("Linux"));
+    assert_eq!(data.nodename_str(), Some("host"));
+    assert_eq!(data.release_str(), Some("6.1.0"));
+    assert_eq!(data.version_str(), Some("#1 SMP"));
+    assert_eq!(data.machine_str(), Some("x86_64"));
+    assert_eq!(data.osname_str(), Some("GNU/Linux"));
+
+    #[cfg(unix)]
+    let invalid = {
+        use std::os::unix::ffi::OsStringExt;
+        OsString::from_vec(vec![b'h', 0xff, b't'])
+    };
+    #[cfg(windows)]
+    let invalid = {
+        use std::os::windows::ffi::OsStringExt;
+        OsString::from_wide(&[u16::from(b'h'), 0xd800, u16::from(b't')])
+    };
+    #[cfg(any(unix, windows))]
+    {
+        let data = UnameData {
+            nodename: invalid,
+            ..data
+        };
+        assert_eq!(data.nodename_str(), None);
+        assert_eq!(data.sysname_str(), Some("Linux"));
+    }
+
+    let info = PlatformInfo::new().unwrap();
+    assert_eq!(info.sysname_str(), info.sysname().to_str());
+    assert_eq!(info.machine_str(), info.machine().to_str());
+
+    Ok(())
+}
+
+#[test]
+fn platform_bytes_round_trip() -> Result<(), String> {
+    let info = PlatformInfo::new().unwrap();
+    let bytes = info.to_bytes();
+    println!("bytes=[{}]{:?}", bytes.len(), bytes);
+    let data = UnameData::from_bytes(&bytes).map_err(|e| e.to_string())?;
+    assert_eq!(data.sysname(), info.sysname());
+    assert_eq!(data.nodename(), info.nodename());
+    assert_eq!(data.release(), info.release());
+    assert_eq!(data.version(), info.version());
+    assert_eq!(data.machine(), info.machine());
+    assert_eq!(data.osname(), info.osname());
+    assert_eq!(data.to_bytes(), bytes);
+
+    // non-Unicode field content
+    #[cfg(unix)]
+    let non_unicode = {
+        use std::os::unix::ffi::OsStringExt;
+        OsString::from_vec(vec![b'h', 0xff, b'o', b's', b't'])
+    };
+    #[cfg(windows)]
+    let non_unicode = {
+        use std::os::windows::ffi::OsStringExt;
+        OsString::from_wide(&[0x0068, 0xD800, 0x006F, 0x0073, 0x0074])
+    };
+    #[cfg(not(any(unix, windows)))]
+    let non_unicode = OsString::from("host");
+    let data = UnameData {
+        sysname: OsString::from("Linux"),
+        nodename: non_unicode,
+        machine: OsString::from("x86_64"),
+        ..UnameData::default()
+    };
+    let bytes = data.to_bytes();
+    assert_eq!(
+        UnameData::from_bytes(&bytes).map_err(|e| e.to_string())?,
+        data
+    );
+
+    // invalid encodings
+    assert!(UnameData::from_bytes(&[]).is_err());
+    assert!(UnameData::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+    assert!(UnameData::from_bytes(&[bytes.as_slice(), &[0]].concat()).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn platform_to_json() -> Result<(), String> {
+    let info = PlatformInfo::new().unwrap();
+    let json = info.to_json();
+    println!("json={json}");
+    assert!(json.starts_with("{\"sysname\":\""));
+    assert!(json.ends_with("\"}"));
+
+    let data = UnameData {
+        sysname: OsString::from("Linux"),
+        nodename: OsString::from("my \"quoted\" host"),
+        release: OsString::from(r"C:\path\to"),
+        version: OsString::from("#1 SMP\tPREEMPT\n\u{1}"),
+        machine: OsString::from("x86_64"),
+        osname: OsString::from("GNU/Linux ✓"),
+    };
+    assert_eq!(
+        data.to_json(),
+        concat!(
+            r#"{"sysname":"Linux","#,
+            r#""nodename":"my \"quoted\" host","#,
+            r#""release":"C:\\path\\to","#,
+            r##""version":"#1 SMP\tPREEMPT\n\u0001","##,
+            r#""machine":"x86_64","#,
+            r#""osname":"GNU/Linux ✓"}"#,
+        )
+    );
+    assert_eq!(
+        UnameData::default().to_json(),
+        r#"{"sysname":"","nodename":"","release":"","version":"","machine":"","osname":""}"#
+    );
+    Ok(())
+}
+
+#[test]
+fn platform_bug_report() -> Result<(), String> {
+    let info = PlatformInfo::new().unwrap();
+    let report = info.bug_report();
+    println!("{report}");
+    assert!(report.starts_with("### Platform\n\n```text\n"));
+    assert!(report.ends_with("\n```\n"));
+    assert_eq!(report.matches("```").count(), 2);
+    for key in [
+        "osname",
+        "release",
+        "version",
+        "machine",
+        "rustc",
+        "build target",
+    ]
+    .iter()
+    {
+        assert!(report.contains(&format!("\n{key}: ")), "missing '{}'", key);
+    }
+    let osname = format!("\nosname: {}\n", info.osname().to_string_lossy());
+    assert!(report.contains(&osname));
+    assert!(report.contains(std::env::consts::OS));
+    match info.os_release_id() {
+        Some(id) => {
+            let distro_id = format!("\ndistro id: {}\n", id.to_string_lossy());
+            assert!(report.contains(&distro_id));
+            assert!(report.contains("\ndistro: "));
+        }
+        None => assert!(!report.contains("\ndistro")),
+    }
+    Ok(())
+}
+
+#[test]
+fn platform_new_strict() -> Result<(), String> {
+    let result = PlatformInfo::new_strict();
+    if cfg!(any(unix, windows)) {
+        assert_eq!(
+            result.map_err(|e| e.to_string())?,
+            PlatformInfo::new().unwrap()
+        );
+    } else {
+        assert!(result.unwrap_err().is_unsupported());
+    }
+
+    let err: PlatformInfoError = Box::new(UnsupportedPlatformError);
+    println!("err={err}");
+    assert!(err.is_unsupported());
+    let err: PlatformInfoError = Box::new(std::io::Error::other("failure"));
+    assert!(!err.is_unsupported());
+    let err: PlatformInfoError = Box::from("failure");
+    assert!(!err.is_unsupported());
+    Ok(())
+}
+
+#[test]
+fn platform_error_conversions() -> Result<(), String> {
+    fn syscall() -> Result<(), PlatformInfoError> {
+        let result: Result<(), std::io::Error> = Err(std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            "no such file",
+        ));
+        result?;
+        Ok(())
+    }
+    fn conversion() -> Result<u8, PlatformInfoError> {
+        Ok(u8::try_from(256_u32)?)
+    }
+
+    let err = syscall().unwrap_err();
+    println!("err={err}");
+    assert!(err.is_syscall());
+    assert!(!err.is_conversion());
+    assert!(!err.is_unsupported());
+    assert_eq!(err.to_string(), "no such file");
+
+    let err = conversion().unwrap_err();
+    println!("err={err}");
+    assert!(err.is_conversion());
+    assert!(!err.is_syscall());
+    assert!(!err.is_unsupported());
+    assert_eq!(
+        err.to_string(),
+        u8::try_from(256_u32).unwrap_err().to_string()
+    );
+
+    let err: PlatformInfoError = Box::new(UnsupportedPlatformError);
+    assert!(!err.is_syscall());
+    assert!(!err.is_conversion());
+
+    Ok(())
+}
+
+#[test]
+fn platform_parse_uname_a() -> Result<(), String> {
+    use std::convert::TryFrom;
+    use std::ffi::OsStr;
+
+    let data = |fields: [&str; 6]| UnameData {
+        sysname: OsString::from(fields[0]),
+        nodename: OsString::from(fields[1]),
+        release: OsString::from(fields[2]),
+        version: OsString::from(fields[3]),
+        machine: OsString::from(fields[4]),
+        osname: OsString::from(fields[5]),
+    };
+    let samples = [
+        data([
+            "Linux",
+            "host",
+            "6.1.0-18-amd64",
+            "#1 SMP PREEMPT_DYNAMIC Debian 6.1.76-1 (2024-02-01)",
+            "x86_64",
+            "GNU/Linux",
+        ]),
+        data([
+            "Darwin",
+            "mac.local",
+            "23.4.0",
+            "Darwin Kernel Version 23.4.0: Fri Mar 15 00:12:49 PDT 2024; root:xnu-10063.101.17~1/RELEASE_ARM64_T6020",
+            "arm64",
+            "Darwin",
+        ]),
+        data([
+            "Windows_NT",
+            "WORKSTATION",
+            "10.0",
+            "19045",
+            "x86_64",
+            "MS/Windows (Windows 10)",
+        ]),
+    ];
+    for sample in samples.iter() {
+        let s = sample.to_string();
+        println!("{s}");
+        let parsed = UnameData::try_from(OsStr::new(&s)).map_err(|e| e.to_string())?;
+        assert_eq!(&parsed, sample);
+    }
+
+    let info = PlatformInfo::new().unwrap();
+    let s = info.format(UnameFlags::ALL);
+    let parsed = UnameData::parse_uname_a(&s).ok_or("parse failure")?;
+    assert_eq!(parsed.format(UnameFlags::ALL), s);
+
+    // GNU `uname -a` (with known processor and hardware platform)
+    let s = "Linux host 6.8.5-301.fc40.x86_64 #1 SMP PREEMPT_DYNAMIC Thu Apr 11 20:00:10 UTC 2024 x86_64 x86_64 x86_64 GNU/Linux";
+    let parsed = UnameData::parse_uname_a(OsStr::new(s)).ok_or("parse failure")?;
+    assert_eq!(
+        parsed.version,
+        "#1 SMP PREEMPT_DYNAMIC Thu Apr 11 20:00:10 UTC 2024"
+    );
+    assert_eq!(parsed.machine, "x86_64");
+    assert_eq!(parsed.osname, "GNU/Linux");
+
+    assert!(UnameData::parse_uname_a(OsStr::new("Linux host 6.1.0 GNU/Linux")).is_none());
+    assert!(UnameData::try_from(OsStr::new("")).is_err());
+    Ok(())
+}
+
+#[test]
+fn platform_host_os_name() -> Result<(), String> {
+    const HOST_OS_NAME: &str = host_os_name();
+    println!("host_os_name={HOST_OS_NAME:?}");
+    assert!(!HOST_OS_NAME.is_empty());
 
+    let info = PlatformInfo::new().unwrap();
+    let osname = info.osname().to_string_lossy();
+    assert!(osname.starts_with(HOST_OS_NAME));
+    if cfg!(any(target_os = "linux", target_os = "macos", windows)) {
+        assert_ne!(HOST_OS_NAME, "unknown");
+    }
     Ok(())
 }
 
@@ -54,3 +415,815 @@ fn platform_clone() -> Result<(), String> {
     assert_eq!(info_copy, info);
     Ok(())
 }
+
+#[test]
+fn platform_thread_safe() -> Result<(), String> {
+    let info = std::sync::Arc::new(PlatformInfo::new().unwrap());
+    let shared = std::sync::Arc::clone(&info);
+    let sysname = std::thread::spawn(move || shared.sysname().to_os_string())
+        .join()
+        .map_err(|_| "thread panicked")?;
+    assert_eq!(sysname, info.sysname());
+    Ok(())
+}
+
+#[test]
+fn platform_format() -> Result<(), String> {
+    let info = PlatformInfo::new().unwrap();
+
+    let sysname = info.sysname().to_string_lossy();
+    let nodename = info.nodename().to_string_lossy();
+    let release = info.release().to_string_lossy();
+    let version = info.version().to_string_lossy();
+    let machine = info.machine().to_string_lossy();
+    let osname = info.osname().to_string_lossy();
+
+    let all = info.format(UnameFlags::ALL);
+    println!("uname -a => '{}'", all.to_string_lossy());
+    assert_eq!(
+        all.to_string_lossy(),
+        format!("{sysname} {nodename} {release} {version} {machine} {osname}")
+    );
+
+    assert_eq!(info.format(UnameFlags::empty()), info.sysname());
+    assert_eq!(info.format(UnameFlags::SYSNAME), info.sysname());
+    assert_eq!(
+        info.format(UnameFlags::MACHINE | UnameFlags::SYSNAME)
+            .to_string_lossy(),
+        format!("{sysname} {machine}")
+    );
+    assert_eq!(
+        info.format(UnameFlags::OSNAME | UnameFlags::PROCESSOR | UnameFlags::RELEASE)
+            .to_string_lossy(),
+        format!("{release} unknown {osname}")
+    );
+    let mut flags = UnameFlags::ALL;
+    flags |= UnameFlags::PROCESSOR | UnameFlags::HARDWARE_PLATFORM;
+    assert_eq!(
+        info.format(flags).to_string_lossy(),
+        format!("{sysname} {nodename} {release} {version} {machine} unknown unknown {osname}")
+    );
+
+    Ok(())
+}
+
+#[test]
+fn platform_architecture() -> Result<(), String> {
+    let arch = |s: &str| s.parse::<Architecture>().unwrap();
+
+    // x86 family
+    assert_eq!(arch("x86_64"), Architecture::X86_64);
+    assert_eq!(arch("amd64"), Architecture::X86_64); // FreeBSD/OpenBSD
+    assert_eq!(arch("i386"), Architecture::X86);
+    assert_eq!(arch("i486"), Architecture::X86);
+    assert_eq!(arch("i586"), Architecture::X86);
+    assert_eq!(arch("i686"), Architecture::X86);
+    assert_eq!(arch("i86pc"), Architecture::Unknown(String::from("i86pc"))); // illumos/Solaris hardware platform
+
+    // ARM family
+    assert_eq!(arch("aarch64"), Architecture::Aarch64);
+    assert_eq!(arch("arm64"), Architecture::Aarch64); // MacOS/FreeBSD
+    assert_eq!(arch("arm64e"), Architecture::Aarch64); // MacOS (pointer authentication)
+    assert_eq!(arch("armv7l"), Architecture::Arm);
+    assert_eq!(arch("armv6l"), Architecture::Arm);
+    assert_eq!(arch("armv8l"), Architecture::Arm); // 32-bit userland on a 64-bit kernel
+
+    // Windows spellings (eg, `PROCESSOR_ARCHITECTURE`)
+    assert_eq!(arch("AMD64"), Architecture::X86_64);
+    assert_eq!(arch("x64"), Architecture::X86_64);
+    assert_eq!(arch("X86"), Architecture::X86);
+    assert_eq!(arch("ARM64"), Architecture::Aarch64);
+    assert_eq!(arch("ARM64EC"), Architecture::Aarch64);
+    assert_eq!(arch("ARM"), Architecture::Arm);
+    assert_eq!(arch("PPC"), Architecture::PowerPc);
+    assert_eq!(arch("MIPS"), Architecture::Mips);
+
+    // others
+    assert_eq!(arch("ppc64le"), Architecture::PowerPc64);
+    assert_eq!(arch("mips64el"), Architecture::Mips64);
+    assert_eq!(arch("riscv32"), Architecture::RiscV32);
+    assert_eq!(arch("riscv64"), Architecture::RiscV64);
+    assert_eq!(arch("s390x"), Architecture::S390x);
+    assert_eq!(arch("sun4v"), Architecture::Sparc64);
+    assert_eq!(arch("loongarch64"), Architecture::LoongArch64);
+    assert_eq!(arch("m68k"), Architecture::M68k);
+
+    // unknown names are preserved
+    assert_eq!(arch("IA64"), Architecture::Unknown(String::from("IA64")));
+    assert_eq!(arch(""), Architecture::Unknown(String::new()));
+
+    // `Display` uses the Rust architecture name
+    assert_eq!(arch("AMD64").to_string(), "x86_64");
+    assert_eq!(arch("armv7l").to_string(), "arm");
+    assert_eq!(arch("alpha").to_string(), "alpha");
+    for name in ["x86", "x86_64", "arm", "aarch64", "powerpc64", "riscv64"] {
+        assert_eq!(arch(name).to_string(), name);
+    }
+
+    let info = PlatformInfo::new().unwrap();
+    let architecture = info.architecture();
+    println!("architecture={architecture}");
+    if info.arch_triple() != "unknown" {
+        assert_eq!(architecture.as_str(), info.arch_triple());
+    }
+    if cfg!(any(target_arch = "x86_64", target_arch = "aarch64")) {
+        assert_eq!(architecture.as_str(), std::env::consts::ARCH);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn platform_os_kind() -> Result<(), String> {
+    // * coreutils `uname -o` expectations, per target
+    let expected = [
+        (
+            cfg!(all(
+                target_os = "linux",
+                any(target_env = "gnu", target_env = "")
+            )),
+            "GNU/Linux",
+        ),
+        (cfg!(all(target_os = "linux", target_env = "musl")), "Linux"),
+        (cfg!(target_os = "android"), "Android"),
+        (cfg!(target_os = "windows"), "MS/Windows"),
+        (cfg!(target_os = "cygwin"), "Cygwin"),
+        (
+            cfg!(all(target_os = "freebsd", target_env = "gnu")),
+            "GNU/kFreeBSD",
+        ),
+        (
+            cfg!(all(target_os = "freebsd", not(target_env = "gnu"))),
+            "FreeBSD",
+        ),
+        (cfg!(target_os = "netbsd"), "NetBSD"),
+        (cfg!(target_os = "openbsd"), "OpenBSD"),
+        (cfg!(target_vendor = "apple"), "Darwin"),
+        (cfg!(target_os = "hurd"), "GNU"),
+        (cfg!(target_os = "fuchsia"), "Fuchsia"),
+        (cfg!(target_os = "redox"), "Redox"),
+    ];
+
+    let info = PlatformInfo::new().unwrap();
+    let os_kind = info.os_kind();
+    println!("os_kind={:?}; osname={:?}", os_kind, info.osname());
+    assert_eq!(os_kind, host_os_name());
+    assert!(info.osname().to_string_lossy().starts_with(host_os_name()));
+    if let Some((_, expected)) = expected.iter().find(|(is_target, _)| *is_target) {
+        assert_eq!(os_kind, *expected);
+    }
+    if cfg!(not(windows)) {
+        assert_eq!(os_kind, info.osname());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn platform_kernel_kind() -> Result<(), String> {
+    let kind = |sysname: &str| KernelKind::from_sysname(OsStr::new(sysname));
+    assert_eq!(kind("Linux"), KernelKind::Linux);
+    assert_eq!(kind("Darwin"), KernelKind::Darwin);
+    assert_eq!(kind("Windows_NT"), KernelKind::WindowsNt);
+    assert_eq!(kind("MINGW64_NT-10.0-19045"), KernelKind::WindowsNt);
+    assert_eq!(kind("CYGWIN_NT-6.1-7601"), KernelKind::WindowsNt);
+    assert_eq!(kind("MSYS_NT-10.0-22631"), KernelKind::WindowsNt);
+    assert_eq!(kind("FreeBSD"), KernelKind::FreeBsd);
+    assert_eq!(kind("GNU/kFreeBSD"), KernelKind::FreeBsd);
+    assert_eq!(kind("NetBSD"), KernelKind::NetBsd);
+    assert_eq!(kind("OpenBSD"), KernelKind::OpenBsd);
+    assert_eq!(kind("DragonFly"), KernelKind::DragonFly);
+    assert_eq!(kind("SunOS"), KernelKind::SunOs);
+    assert_eq!(kind("AIX"), KernelKind::Aix);
+    assert_eq!(kind("Haiku"), KernelKind::Haiku);
+    assert_eq!(kind("GNU"), KernelKind::Hurd);
+    assert_eq!(kind("Fuchsia"), KernelKind::Fuchsia);
+    assert_eq!(kind("Redox"), KernelKind::Redox);
+    assert_eq!(kind("linux"), KernelKind::Linux); // case-insensitive
+    assert_eq!(kind("Plan9"), KernelKind::Other(OsString::from("Plan9")));
+    assert_eq!(kind(""), KernelKind::Other(OsString::new()));
+
+    let info = PlatformInfo::new().unwrap();
+    let kernel_kind = info.kernel_kind();
+    println!("sysname={:?}; kernel_kind={kernel_kind:?}", info.sysname());
+    if cfg!(any(target_os = "linux", target_os = "android")) {
+        assert_eq!(kernel_kind, KernelKind::Linux);
+    } else if cfg!(target_os = "macos") {
+        assert_eq!(kernel_kind, KernelKind::Darwin);
+    } else if cfg!(windows) {
+        assert_eq!(kernel_kind, KernelKind::WindowsNt);
+    } else if cfg!(target_os = "freebsd") {
+        assert_eq!(kernel_kind, KernelKind::FreeBsd);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn platform_machine_overrides() -> Result<(), String> {
+    let info = PlatformInfo::new().unwrap();
+    let machine = info.machine().to_string_lossy().into_owned();
+
+    // an empty table is a no-op
+    let unchanged = info.clone().with_machine_overrides(HashMap::new());
+    assert_eq!(unchanged, info);
+
+    let mut overrides = HashMap::new();
+    let _ = overrides.insert(String::from("no-such-machine"), String::from("unused"));
+    let _ = overrides.insert(machine, String::from("custom-machine"));
+    let overridden = info.clone().with_machine_overrides(overrides);
+    println!("machine={:?} => {:?}", info.machine(), overridden.machine());
+    assert_eq!(overridden.machine(), "custom-machine");
+    assert_eq!(overridden.machine_raw(), info.machine_raw());
+    assert_eq!(overridden.sysname(), info.sysname());
+
+    Ok(())
+}
+
+#[test]
+fn chassis_type_from_smbios_code() -> Result<(), String> {
+    assert_eq!(ChassisType::from_smbios_code(1), Some(ChassisType::Other));
+    assert_eq!(ChassisType::from_smbios_code(2), None); // "Unknown"
+    assert_eq!(ChassisType::from_smbios_code(3), Some(ChassisType::Desktop));
+    assert_eq!(ChassisType::from_smbios_code(7), Some(ChassisType::Desktop));
+    assert_eq!(ChassisType::from_smbios_code(9), Some(ChassisType::Laptop));
+    assert_eq!(ChassisType::from_smbios_code(10), Some(ChassisType::Laptop));
+    assert_eq!(ChassisType::from_smbios_code(17), Some(ChassisType::Server));
+    assert_eq!(ChassisType::from_smbios_code(23), Some(ChassisType::Server));
+    assert_eq!(ChassisType::from_smbios_code(30), Some(ChassisType::Other));
+    assert_eq!(
+        ChassisType::from_smbios_code(35),
+        Some(ChassisType::Desktop)
+    );
+    // the "chassis lock present" flag is ignored
+    assert_eq!(
+        ChassisType::from_smbios_code(0x80 | 9),
+        Some(ChassisType::Laptop)
+    );
+    assert_eq!(ChassisType::from_smbios_code(0), None);
+    assert_eq!(ChassisType::from_smbios_code(0x7f), None);
+
+    let info = PlatformInfo::new().unwrap();
+    println!("chassis_type={:?}", info.chassis_type());
+
+    Ok(())
+}
+
+#[test]
+fn platform_cpu_signature() -> Result<(), String> {
+    let signature = |family, model, stepping| CpuSignature {
+        family,
+        model,
+        stepping,
+    };
+    // Intel Core i7-8700 (Coffee Lake) ~ extended model applies to family 6
+    assert_eq!(
+        CpuSignature::from_cpuid_eax(0x000906EA),
+        signature(6, 158, 10)
+    );
+    // AMD Ryzen 7 3700X (Zen 2) ~ extended family and model apply to family 15
+    assert_eq!(
+        CpuSignature::from_cpuid_eax(0x00870F10),
+        signature(23, 113, 0)
+    );
+    // Intel Pentium (P5) ~ extended fields don't apply
+    assert_eq!(CpuSignature::from_cpuid_eax(0x00FF0543), signature(5, 4, 3));
+
+    let info = PlatformInfo::new().unwrap();
+    let cpu_signature = info.cpu_signature();
+    println!("cpu_signature={:?}", cpu_signature);
+    if cfg!(any(target_arch = "x86", target_arch = "x86_64")) {
+        let cpu_signature = cpu_signature.unwrap();
+        // * modern x86 processors are family 6 (most Intel) or 15+ (AMD and Intel NetBurst)
+        assert!(cpu_signature.family >= 5);
+        assert!(cpu_signature.stepping <= 0xf);
+    } else {
+        assert_eq!(cpu_signature, None);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn platform_default_editor_and_pager() -> Result<(), String> {
+    // note: the modified environment variables are used only by this test
+    let info = PlatformInfo::new().unwrap();
+    let names = ["VISUAL", "EDITOR", "PAGER"];
+    let saved: Vec<_> = names.iter().map(std::env::var_os).collect();
+
+    std::env::set_var("VISUAL", "vis-editor");
+    std::env::set_var("EDITOR", "editor");
+    std::env::set_var("PAGER", "pager");
+    assert_eq!(info.default_editor(), Some(OsString::from("vis-editor")));
+    assert_eq!(info.default_pager(), Some(OsString::from("pager")));
+
+    std::env::set_var("VISUAL", "");
+    assert_eq!(info.default_editor(), Some(OsString::from("editor")));
+
+    std::env::remove_var("VISUAL");
+    std::env::remove_var("EDITOR");
+    std::env::remove_var("PAGER");
+    let expected_editor = if cfg!(windows) {
+        Some(OsString::from("notepad"))
+    } else {
+        None
+    };
+    assert_eq!(info.default_editor(), expected_editor);
+    assert_eq!(info.default_pager(), None);
+
+    for (name, value) in names.iter().zip(saved) {
+        match value {
+            Some(value) => std::env::set_var(name, value),
+            None => std::env::remove_var(name),
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn platform_proxy() -> Result<(), String> {
+    // * only this test modifies the proxy environment variables
+    let info = PlatformInfo::new().unwrap();
+    let names = [
+        "http_proxy",
+        "HTTP_PROXY",
+        "https_proxy",
+        "HTTPS_PROXY",
+        "no_proxy",
+        "NO_PROXY",
+    ];
+    let saved: Vec<_> = names.iter().map(std::env::var_os).collect();
+    for name in names.iter() {
+        std::env::remove_var(name);
+    }
+    if cfg!(not(windows)) {
+        assert_eq!(info.http_proxy(), None);
+        assert_eq!(info.no_proxy(), None);
+    }
+
+    std::env::set_var("HTTP_PROXY", "http://upper.example.com:8080");
+    std::env::set_var("HTTPS_PROXY", "http://secure.example.com:8443");
+    std::env::set_var("NO_PROXY", "localhost,.example.com");
+    assert_eq!(
+        info.http_proxy(),
+        Some(OsString::from("http://upper.example.com:8080"))
+    );
+    assert_eq!(
+        info.https_proxy(),
+        Some(OsString::from("http://secure.example.com:8443"))
+    );
+    assert_eq!(
+        info.no_proxy(),
+        Some(OsString::from("localhost,.example.com"))
+    );
+
+    // lowercase variables take precedence
+    std::env::set_var("http_proxy", "http://lower.example.com:3128");
+    std::env::set_var("no_proxy", "127.0.0.1");
+    assert_eq!(
+        info.http_proxy(),
+        Some(OsString::from("http://lower.example.com:3128"))
+    );
+    assert_eq!(info.no_proxy(), Some(OsString::from("127.0.0.1")));
+
+    for (name, value) in names.iter().zip(saved) {
+        match value {
+            Some(value) => std::env::set_var(name, value),
+            None => std::env::remove_var(name),
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn platform_has_native_atomic() -> Result<(), String> {
+    let info = PlatformInfo::new().unwrap();
+
+    for width in [8, 16, 32, 64, 128] {
+        println!(
+            "has_native_atomic({width}) = {}",
+            info.has_native_atomic(width)
+        );
+    }
+    if cfg!(target_pointer_width = "64") {
+        assert!(info.has_native_atomic(64));
+        assert!(info.has_native_atomic(32));
+    }
+    #[cfg(target_arch = "x86_64")]
+    assert_eq!(
+        info.has_native_atomic(128),
+        std::is_x86_feature_detected!("cmpxchg16b")
+    );
+    assert!(!info.has_native_atomic(0));
+    assert!(!info.has_native_atomic(24));
+    assert!(!info.has_native_atomic(256));
+
+    Ok(())
+}
+
+#[cfg(feature = "mockable")]
+mod mockable {
+    use std::ffi::{OsStr, OsString};
+
+    use platform_info::*;
+
+    struct FakeInfo {
+        name: OsString,
+    }
+
+    impl UNameAPI for FakeInfo {
+        fn sysname(&self) -> &OsStr {
+            &self.name
+        }
+        fn nodename(&self) -> &OsStr {
+            &self.name
+        }
+        fn release(&self) -> &OsStr {
+            &self.name
+        }
+        fn version(&self) -> &OsStr {
+            &self.name
+        }
+        fn machine(&self) -> &OsStr {
+            &self.name
+        }
+        fn osname(&self) -> &OsStr {
+            &self.name
+        }
+    }
+
+    // * storing the trait object within a (thread-shareable) structure field confirms object-safety
+    struct Reporter {
+        info: BoxedPlatformInfo,
+    }
+
+    impl Reporter {
+        fn report(&self) -> String {
+            format!(
+                "{} {}",
+                self.info.sysname().to_string_lossy(),
+                self.info.machine().to_string_lossy()
+            )
+        }
+    }
+
+    #[test]
+    fn platform_boxed() -> Result<(), String> {
+        let info = PlatformInfo::new().unwrap();
+        let expected = format!(
+            "{} {}",
+            info.sysname().to_string_lossy(),
+            info.machine().to_string_lossy()
+        );
+        let reporter = Reporter { info: info.boxed() };
+        assert_eq!(reporter.report(), expected);
+
+        let fake = Reporter {
+            info: Box::new(FakeInfo {
+                name: OsString::from("fake"),
+            }),
+        };
+        let handle = std::thread::spawn(move || fake.report());
+        assert_eq!(handle.join().unwrap(), "fake fake");
+        Ok(())
+    }
+}
+
+#[test]
+#[cfg(feature = "tokio")]
+fn platform_new_async_tokio() -> Result<(), String> {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    let info = runtime.block_on(PlatformInfo::new_async()).unwrap();
+    println!("{info:?}");
+    assert_eq!(info, PlatformInfo::new().unwrap());
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "async-std", not(feature = "tokio")))]
+fn platform_new_async_async_std() -> Result<(), String> {
+    let info = async_std::task::block_on(PlatformInfo::new_async()).unwrap();
+    println!("{info:?}");
+    assert_eq!(info, PlatformInfo::new().unwrap());
+    Ok(())
+}
+
+#[test]
+fn platform_mitigation_summary() -> Result<(), String> {
+    let summary = MitigationSummary::from_vulnerability_states;
+    assert_eq!(summary(Vec::new()), None);
+    assert_eq!(
+        summary(vec![
+            "Not affected",
+            "Mitigation: PTI",
+            "Mitigation: usercopy/swapgs barriers and __user pointer sanitization",
+        ]),
+        Some(MitigationSummary::FullyMitigated)
+    );
+    assert_eq!(
+        summary(vec![
+            "Mitigation: PTI",
+            "Mitigation: Clear CPU buffers; SMT vulnerable",
+        ]),
+        Some(MitigationSummary::PartiallyMitigated)
+    );
+    assert_eq!(
+        summary(vec!["Mitigation: PTI", "Vulnerable: No microcode"]),
+        Some(MitigationSummary::PartiallyMitigated)
+    );
+    assert_eq!(
+        summary(vec![
+            "Not affected",
+            "Unknown: Dependent on hypervisor status"
+        ]),
+        Some(MitigationSummary::PartiallyMitigated)
+    );
+    assert_eq!(
+        summary(vec![
+            "Vulnerable",
+            "Vulnerable: Clear CPU buffers attempted, no microcode"
+        ]),
+        Some(MitigationSummary::Vulnerable)
+    );
+    Ok(())
+}
+
+#[test]
+fn platform_field_capabilities() -> Result<(), String> {
+    let info = PlatformInfo::new().unwrap();
+    let capabilities = info.field_capabilities();
+    println!("capabilities = {:?}", capabilities);
+
+    if cfg!(any(unix, windows)) {
+        assert!(capabilities.contains(FieldCapabilities::SYSNAME | FieldCapabilities::MACHINE));
+    } else {
+        assert!(capabilities.is_empty());
+    }
+
+    let mut flags = FieldCapabilities::empty();
+    assert!(flags.is_empty());
+    flags |= FieldCapabilities::RELEASE;
+    assert!(flags.contains(FieldCapabilities::RELEASE));
+    assert!(!flags.contains(FieldCapabilities::RELEASE | FieldCapabilities::VERSION));
+    assert!(FieldCapabilities::UNAME.contains(FieldCapabilities::OSNAME));
+    assert!(!FieldCapabilities::UNAME.contains(FieldCapabilities::DOMAINNAME));
+
+    Ok(())
+}
+
+#[test]
+fn platform_info_ref() -> Result<(), String> {
+    let info = PlatformInfo::new().unwrap();
+    let info_ref = PlatformInfoRef::from(&info);
+    let copy = info_ref; // * `Copy`; no field allocations
+
+    for r in [info_ref, copy].iter() {
+        assert_eq!(r.sysname(), info.sysname());
+        assert_eq!(r.nodename(), info.nodename());
+        assert_eq!(r.release(), info.release());
+        assert_eq!(r.version(), info.version());
+        assert_eq!(r.machine(), info.machine());
+        assert_eq!(r.osname(), info.osname());
+    }
+    assert_eq!(
+        info_ref.format(UnameFlags::ALL),
+        info.format(UnameFlags::ALL)
+    );
+    assert_eq!(info_ref.to_bytes(), info.to_bytes());
+
+    let data = UnameData::from_bytes(&info.to_bytes()).map_err(|e| e.to_string())?;
+    assert_eq!(PlatformInfoRef::from(&data), info_ref);
+    assert_eq!(PlatformInfoRef::new(&info_ref), info_ref);
+
+    Ok(())
+}
+
+#[test]
+fn platform_build_target() -> Result<(), String> {
+    const TARGET: BuildTarget = BuildTarget::CURRENT; // * usable in `const` contexts
+    let info = PlatformInfo::new().unwrap();
+    let build_target = info.build_target();
+    println!(
+        "build_target={}; arch_triple={}",
+        build_target,
+        info.arch_triple()
+    );
+    assert_eq!(build_target, TARGET);
+
+    assert_eq!(build_target.arch, std::env::consts::ARCH);
+    assert_eq!(build_target.os, std::env::consts::OS);
+    assert_eq!(
+        build_target.endian,
+        if cfg!(target_endian = "big") {
+            "big"
+        } else {
+            "little"
+        }
+    );
+    assert_eq!(
+        build_target.pointer_width,
+        u32::try_from(std::mem::size_of::<usize>() * 8).unwrap()
+    );
+    if cfg!(target_env = "gnu") {
+        assert_eq!(build_target.env, "gnu");
+    } else if cfg!(target_env = "musl") {
+        assert_eq!(build_target.env, "musl");
+    } else if cfg!(target_env = "msvc") {
+        assert_eq!(build_target.env, "msvc");
+    }
+
+    let target = BuildTarget {
+        arch: "x86",
+        os: "windows",
+        env: "msvc",
+        endian: "little",
+        pointer_width: 32,
+    };
+    assert_eq!(target.to_string(), "x86-windows-msvc");
+    let target = BuildTarget { env: "", ..target };
+    assert_eq!(target.to_string(), "x86-windows");
+
+    Ok(())
+}
+
+#[test]
+fn platform_machine_shell_safe() -> Result<(), String> {
+    let info = PlatformInfo::new().unwrap();
+    let machine = info.machine().to_string_lossy().into_owned();
+    println!("machine_shell_safe={}", info.machine_shell_safe());
+    if ["x86_64", "aarch64", "i686", "armv7l", "arm64", "amd64"].contains(&machine.as_str()) {
+        assert_eq!(info.machine_shell_safe(), machine);
+    }
+
+    let overrides = [(machine, String::from("Power Macintosh"))]
+        .iter()
+        .cloned()
+        .collect();
+    let info = PlatformInfo::new()
+        .unwrap()
+        .with_machine_overrides(overrides);
+    assert_eq!(info.machine_shell_safe(), "Power_Macintosh");
+
+    Ok(())
+}
+
+#[cfg(feature = "tracing")]
+#[test]
+fn platform_tracing() -> Result<(), String> {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    // minimal `tracing` subscriber, counting all events
+    struct EventCounter(Arc<AtomicUsize>);
+    impl tracing::Subscriber for EventCounter {
+        fn enabled(&self, _: &tracing::Metadata<'_>) -> bool {
+            true
+        }
+        fn new_span(&self, _: &tracing::span::Attributes<'_>) -> tracing::span::Id {
+            tracing::span::Id::from_u64(1)
+        }
+        fn record(&self, _: &tracing::span::Id, _: &tracing::span::Record<'_>) {}
+        fn record_follows_from(&self, _: &tracing::span::Id, _: &tracing::span::Id) {}
+        fn event(&self, _: &tracing::Event<'_>) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+        fn enter(&self, _: &tracing::span::Id) {}
+        fn exit(&self, _: &tracing::span::Id) {}
+    }
+
+    let events = Arc::new(AtomicUsize::new(0));
+    let subscriber = EventCounter(Arc::clone(&events));
+    tracing::subscriber::with_default(subscriber, || PlatformInfo::new().unwrap());
+
+    let events = events.load(Ordering::SeqCst);
+    println!("events={events}");
+    if cfg!(any(unix, windows)) {
+        assert!(events > 0);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn platform_fields() -> Result<(), String> {
+    let info = PlatformInfo::new().unwrap();
+    let names = [
+        "sysname", "nodename", "release", "version", "machine", "osname",
+    ];
+
+    let fields = info.fields();
+    assert_eq!(
+        fields.iter().map(|(name, _)| *name).collect::<Vec<_>>(),
+        names
+    );
+    assert_eq!(fields[0].1, info.sysname());
+    assert_eq!(fields[5].1, info.osname());
+    let expected: Vec<(&str, OsString)> = fields
+        .iter()
+        .map(|(name, value)| (*name, value.to_os_string()))
+        .collect();
+
+    let owned: Vec<(&str, OsString)> = info.into_fields().collect();
+    assert_eq!(owned.len(), 6);
+    assert_eq!(owned, expected);
+
+    let map: HashMap<&str, OsString> = PlatformInfo::new().unwrap().into_fields().collect();
+    assert_eq!(map.len(), 6);
+
+    let data = UnameData::from(PlatformInfo::new().unwrap());
+    assert_eq!(data.into_fields().collect::<Vec<_>>(), expected);
+
+    Ok(())
+}
+
+#[test]
+fn platform_dpkg_architecture() -> Result<(), String> {
+    let info = PlatformInfo::new().unwrap();
+    let machine = info.machine().to_string_lossy().into_owned();
+    println!("dpkg_architecture={:?}", info.dpkg_architecture());
+    if machine == "x86_64" {
+        assert_eq!(info.dpkg_architecture(), Some("amd64"));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn platform_rpm_architecture() -> Result<(), String> {
+    let info = PlatformInfo::new().unwrap();
+    let machine = info.machine().to_string_lossy().into_owned();
+    println!("rpm_architecture={:?}", info.rpm_architecture());
+    if ["x86_64", "aarch64", "i686", "ppc64le", "s390x"].contains(&machine.as_str()) {
+        assert_eq!(info.rpm_architecture(), Some(machine.as_str()));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn platform_linux_only_stubs() -> Result<(), String> {
+    // * Linux-only values are available (as `None`/`false` stubs) for all platforms
+    let info = PlatformInfo::new().unwrap();
+    let inotify_max_watches = info.inotify_max_watches();
+    println!("inotify_max_watches={:?}", inotify_max_watches);
+    let overcommit_memory = info.overcommit_memory();
+    println!("overcommit_memory={:?}", overcommit_memory);
+    let overcommit_ratio = info.overcommit_ratio();
+    println!("overcommit_ratio={:?}", overcommit_ratio);
+    let aio_max_nr = info.aio_max_nr();
+    println!("aio_max_nr={:?}", aio_max_nr);
+    let dirty_ratios = info.dirty_ratios();
+    println!("dirty_ratios={:?}", dirty_ratios);
+    let urandom_ready = info.urandom_ready();
+    println!("urandom_ready={:?}", urandom_ready);
+    let mitigation_summary = info.mitigation_summary();
+    println!("mitigation_summary={:?}", mitigation_summary);
+    let threads_max = info.threads_max();
+    println!("threads_max={:?}", threads_max);
+    let capabilities = info.capabilities();
+    println!("capabilities={:?}", capabilities);
+    let package_sandbox = info.package_sandbox();
+    println!("package_sandbox={:?}", package_sandbox);
+    let nf_conntrack_max = info.nf_conntrack_max();
+    println!("nf_conntrack_max={:?}", nf_conntrack_max);
+    let conntrack_timeouts = info.conntrack_timeouts();
+    println!("conntrack_timeouts={:?}", conntrack_timeouts);
+    let login_defs_umask = info.login_defs_umask();
+    println!("login_defs_umask={:?}", login_defs_umask);
+    let socket_buffer_limits = info.socket_buffer_limits();
+    println!("socket_buffer_limits={:?}", socket_buffer_limits);
+    let is_wsl = info.is_wsl();
+    println!("is_wsl={:?}", is_wsl);
+    let wsl_version = info.wsl_version();
+    println!("wsl_version={:?}", wsl_version);
+    let os_release_id = info.os_release_id();
+    println!("os_release_id={:?}", os_release_id);
+    let os_release_id_like = info.os_release_id_like();
+    println!("os_release_id_like={:?}", os_release_id_like);
+    if !cfg!(unix) {
+        assert_eq!(inotify_max_watches, None);
+        assert_eq!(overcommit_memory, None);
+        assert_eq!(overcommit_ratio, None);
+        assert_eq!(aio_max_nr, None);
+        assert_eq!(dirty_ratios, None);
+        assert_eq!(urandom_ready, None);
+        assert_eq!(mitigation_summary, None);
+        assert_eq!(threads_max, None);
+        assert_eq!(capabilities, None);
+        assert_eq!(package_sandbox, None);
+        assert_eq!(nf_conntrack_max, None);
+        assert_eq!(conntrack_timeouts, None);
+        assert_eq!(login_defs_umask, None);
+        assert_eq!(socket_buffer_limits, None);
+        assert!(!is_wsl);
+        assert_eq!(wsl_version, None);
+        assert_eq!(os_release_id, None);
+        assert_eq!(os_release_id_like, None);
+    }
+
+    Ok(())
+}